@@ -12,9 +12,25 @@ import {
   getAssociatedTokenAddress,
 } from "@solana/spl-token";
 import BN from "bn.js";
-import { getProgram, getAuctionPDA, getVaultPDA, getDepositPDA, getMetadataPDA, parseMetadataCreators } from "@/lib/program";
+import {
+  getProgram,
+  getAuctionPDA,
+  getVaultPDA,
+  getUnclaimedFundsPDA,
+  getDepositPDA,
+  getMetadataPDA,
+  getBlocklistPDA,
+  getDefaultRecordPDA,
+  getBidderProfilePDA,
+  getBidderAccountPDA,
+  getSellerProfilePDA,
+  getCreatorEarningsPDA,
+  fetchAuctionSeller,
+  fetchPreviousSaleLot,
+  parseMetadataCreators,
+} from "@/lib/program";
 import { getMagicConnection } from "@/lib/magic-router";
-import { PROGRAM_ID, DELEGATION_PROGRAM_ID, DEVNET_RPC, PROTOCOL_TREASURY } from "@/lib/constants";
+import { PROGRAM_ID, DELEGATION_PROGRAM_ID, DEVNET_RPC, PROTOCOL_TREASURY, MAX_EXTENSION_SECONDS } from "@/lib/constants";
 
 // ---------------------------------------------------------------------------
 // Types
@@ -298,6 +314,7 @@ export function useAuctionActions(): UseAuctionActionsReturn {
 
       const [auctionState] = getAuctionPDA(publicKey, nftMint);
       const [auctionVault] = getVaultPDA(auctionState);
+      const [unclaimedFunds] = getUnclaimedFundsPDA(auctionState);
 
       const sellerNftTokenAccount = await getAssociatedTokenAddress(
         nftMint,
@@ -311,13 +328,29 @@ export function useAuctionActions(): UseAuctionActionsReturn {
       );
 
       const sig = await l1Program.methods
-        .createAuction(
+        .createAuction({
           reservePrice,
+          reserveCommitment: null,
           durationSeconds,
           extensionSeconds,
           extensionWindow,
-          minBidIncrement
-        )
+          minBidIncrement,
+          bidIncrement: { flat: {} },
+          scheduledStart: null,
+          antiSnipePolicy: { fixedAdd: {} },
+          maxExtensionSeconds: MAX_EXTENSION_SECONDS,
+          buyerPremiumBps: 0,
+          payoutSplits: [],
+          escrowTerms: null,
+          allowlistRoot: null,
+          collectionGate: null,
+          banDefaulters: false,
+          minReputation: null,
+          maxDefaults: null,
+          sellerBond: new BN(0),
+          minBidInterval: 0,
+          blockSelfOutbid: false,
+        })
         .accounts({
           seller: publicKey,
           nftMint,
@@ -325,6 +358,9 @@ export function useAuctionActions(): UseAuctionActionsReturn {
           escrowNftTokenAccount,
           auctionState,
           auctionVault,
+          unclaimedFunds,
+          auctionHouse: null,
+          curator: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -347,21 +383,32 @@ export function useAuctionActions(): UseAuctionActionsReturn {
 
       const [auctionVault] = getVaultPDA(auctionStatePubkey);
       const [bidderDeposit] = getDepositPDA(auctionStatePubkey, publicKey);
+      const seller = await fetchAuctionSeller(l1Connection, l1Program, auctionStatePubkey);
+      const [blocklist] = getBlocklistPDA(seller);
+      const [defaultRecord] = getDefaultRecordPDA(publicKey);
+      const [bidderProfile] = getBidderProfilePDA(publicKey);
 
+      // Empty proof / no gate accounts — the app does not list gated auctions
       const sig = await l1Program.methods
-        .deposit(amount)
+        .deposit(amount, [])
         .accounts({
           bidder: publicKey,
           auctionState: auctionStatePubkey,
           bidderDeposit,
           auctionVault,
+          blocklist,
+          defaultRecord,
+          bidderProfile,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc({ skipPreflight: true });
 
       return sig;
     },
-    [l1Program, publicKey]
+    [l1Program, l1Connection, publicKey]
   );
 
   // -----------------------------------------------------------------------
@@ -427,19 +474,32 @@ export function useAuctionActions(): UseAuctionActionsReturn {
         throw new Error("Wallet not connected");
       }
 
+      const seller = await fetchAuctionSeller(l1Connection, erProgram, auctionStatePubkey);
+      const [blocklist] = getBlocklistPDA(seller);
+      const [defaultRecord] = getDefaultRecordPDA(publicKey);
+      const [bidderProfile] = getBidderProfilePDA(publicKey);
+      const previousLot = await fetchPreviousSaleLot(l1Connection, erProgram, auctionStatePubkey);
+
       return sendErTransaction(
         erProgram.methods
-          .placeBid(amount)
+          .placeBid(amount, null)
           .accounts({
             bidder: publicKey,
             auctionState: auctionStatePubkey,
+            bidderDeposit: null,
+            blocklist,
+            defaultRecord,
+            bidderProfile,
+            previousLot,
+            bidderAccount: null,
+            outbidBidderAccount: null,
           }),
         wallet,
         publicKey,
         magicConnection
       );
     },
-    [erProgram, publicKey, wallet, magicConnection]
+    [erProgram, l1Connection, publicKey, wallet, magicConnection]
   );
 
   // -----------------------------------------------------------------------
@@ -510,8 +570,18 @@ export function useAuctionActions(): UseAuctionActionsReturn {
 
       const [auctionVault] = getVaultPDA(auctionStatePubkey);
       const [winnerDeposit] = getDepositPDA(auctionStatePubkey, winner);
+      const [winnerBidderAccountPda] = getBidderAccountPDA(winner);
+      const [winnerProfile] = getBidderProfilePDA(winner);
+      const [sellerProfile] = getSellerProfilePDA(seller);
       const [nftMetadata] = getMetadataPDA(nftMint);
 
+      // The BidderAccount is optional — only pass it when the winner has one
+      // back on L1 (undelegated), where it holds their lock on this auction
+      const winnerBidderAccountInfo = await l1Connection.getAccountInfo(winnerBidderAccountPda);
+      const winnerBidderAccount = winnerBidderAccountInfo?.owner.equals(l1Program.programId)
+        ? winnerBidderAccountPda
+        : null;
+
       const escrowNftTokenAccount = await getAssociatedTokenAddress(
         nftMint,
         auctionStatePubkey,
@@ -523,7 +593,8 @@ export function useAuctionActions(): UseAuctionActionsReturn {
         winner
       );
 
-      // Fetch metadata account to get creator list for remaining_accounts
+      // Fetch metadata account to get creator list for remaining_accounts.
+      // Royalties are credited to each verified creator's earnings PDA.
       const metadataAccountInfo = await l1Connection.getAccountInfo(nftMetadata);
       const remainingAccounts: { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[] = [];
 
@@ -531,9 +602,9 @@ export function useAuctionActions(): UseAuctionActionsReturn {
         const parsed = parseMetadataCreators(metadataAccountInfo.data);
         if (parsed && parsed.sellerFeeBps > 0 && parsed.creators.length > 0) {
           for (const creator of parsed.creators) {
-            if (creator.share > 0) {
+            if (creator.share > 0 && creator.verified) {
               remainingAccounts.push({
-                pubkey: creator.address,
+                pubkey: getCreatorEarningsPDA(creator.address)[0],
                 isSigner: false,
                 isWritable: true,
               });
@@ -567,9 +638,15 @@ export function useAuctionActions(): UseAuctionActionsReturn {
             auctionState: auctionStatePubkey,
             auctionVault,
             winnerDeposit,
+            winnerBidderAccount,
             seller,
             winner,
+            winnerProfile,
+            sellerProfile,
             protocolTreasury: PROTOCOL_TREASURY,
+            proceedsEscrow: null,
+            auctionHouse: null,
+            houseFeeRecipient: null,
             nftMint,
             nftMetadata,
             escrowNftTokenAccount,
@@ -694,6 +771,7 @@ export function useAuctionActions(): UseAuctionActionsReturn {
 
       const [auctionVault] = getVaultPDA(auctionStatePubkey);
       const [bidderDeposit] = getDepositPDA(auctionStatePubkey, publicKey);
+      const [bidderProfile] = getBidderProfilePDA(publicKey);
 
       const sig = await l1Program.methods
         .claimRefund()
@@ -702,6 +780,7 @@ export function useAuctionActions(): UseAuctionActionsReturn {
           auctionState: auctionStatePubkey,
           bidderDeposit,
           auctionVault,
+          bidderProfile,
           systemProgram: SystemProgram.programId,
         })
         .rpc({ skipPreflight: true });
@@ -722,6 +801,7 @@ export function useAuctionActions(): UseAuctionActionsReturn {
 
       const [auctionVault] = getVaultPDA(auctionStatePubkey);
       const [bidderDeposit] = getDepositPDA(auctionStatePubkey, bidderAddress);
+      const [bidderProfile] = getBidderProfilePDA(bidderAddress);
 
       const sig = await l1Program.methods
         .claimRefundFor()
@@ -731,6 +811,7 @@ export function useAuctionActions(): UseAuctionActionsReturn {
           auctionState: auctionStatePubkey,
           bidderDeposit,
           auctionVault,
+          bidderProfile,
           systemProgram: SystemProgram.programId,
         })
         .rpc({ skipPreflight: true });
@@ -756,6 +837,9 @@ export function useAuctionActions(): UseAuctionActionsReturn {
 
       const [auctionVault] = getVaultPDA(auctionStatePubkey);
       const [winnerDeposit] = getDepositPDA(auctionStatePubkey, winner);
+      const [winnerBidderAccount] = getBidderAccountPDA(winner);
+      const [winnerDefaultRecord] = getDefaultRecordPDA(winner);
+      const [winnerProfile] = getBidderProfilePDA(winner);
 
       const escrowNftTokenAccount = await getAssociatedTokenAddress(
         nftMint,
@@ -775,6 +859,9 @@ export function useAuctionActions(): UseAuctionActionsReturn {
           auctionState: auctionStatePubkey,
           auctionVault,
           winnerDeposit,
+          winnerBidderAccount,
+          winnerDefaultRecord,
+          winnerProfile,
           seller,
           nftMint,
           escrowNftTokenAccount,
@@ -810,15 +897,22 @@ export function useAuctionActions(): UseAuctionActionsReturn {
         publicKey
       );
 
+      const [auctionVault] = getVaultPDA(auctionStatePubkey);
+      const [sellerProfile] = getSellerProfilePDA(publicKey);
+
       const sig = await l1Program.methods
         .cancelAuction()
         .accounts({
           seller: publicKey,
           auctionState: auctionStatePubkey,
+          auctionVault,
+          sellerProfile,
+          protocolTreasury: PROTOCOL_TREASURY,
           nftMint,
           escrowNftTokenAccount,
           sellerNftTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .rpc({ skipPreflight: true });
 
@@ -11,7 +11,17 @@ import {
 import { useAnchorWallet, useWallet } from "@solana/wallet-adapter-react";
 import type { AnchorWallet } from "@solana/wallet-adapter-react";
 import BN from "bn.js";
-import { getProgram, getVaultPDA, getDepositPDA, getSessionPDA } from "@/lib/program";
+import {
+  getProgram,
+  getVaultPDA,
+  getDepositPDA,
+  getSessionPDA,
+  getBlocklistPDA,
+  getDefaultRecordPDA,
+  getBidderProfilePDA,
+  fetchAuctionSeller,
+  fetchPreviousSaleLot,
+} from "@/lib/program";
 import { getMagicConnection } from "@/lib/magic-router";
 import {
   PROGRAM_ID,
@@ -61,6 +71,9 @@ async function getMagicBlockhash(
 // Amount of SOL to fund ephemeral key for ER tx fees (0.005 SOL)
 const EPHEMERAL_KEY_FUNDING = 5_000_000;
 
+// How long a session key stays valid (program allows up to 7 days)
+const SESSION_DURATION_SECONDS = 24 * 60 * 60;
+
 export interface UseSessionBiddingReturn {
   /** Whether session bidding is active and ready */
   sessionActive: boolean;
@@ -131,20 +144,40 @@ export function useSessionBidding(): UseSessionBiddingReturn {
 
         // Build combined L1 transaction
         const tx = new Transaction();
+        const [bidderDeposit] = getDepositPDA(auctionPubkey, publicKey);
+
+        // The session key may bid up to what will be deposited after this tx
+        const depositInfo = await l1Connection.getAccountInfo(bidderDeposit);
+        const existingDeposit = depositInfo
+          ? (l1Program.coder.accounts.decode("BidderDeposit", depositInfo.data).amount as BN)
+          : new BN(0);
+        const maxBidAmount = existingDeposit.add(new BN(depositAmount));
+        if (maxBidAmount.isZero()) {
+          throw new Error("Deposit SOL before enabling Quick Bidding");
+        }
 
         // 1. Deposit (if needed)
         if (depositAmount > 0) {
           setActivationProgress("Building deposit...");
           const [auctionVault] = getVaultPDA(auctionPubkey);
-          const [bidderDeposit] = getDepositPDA(auctionPubkey, publicKey);
+          const seller = await fetchAuctionSeller(l1Connection, l1Program, auctionPubkey);
+          const [blocklist] = getBlocklistPDA(seller);
+          const [defaultRecord] = getDefaultRecordPDA(publicKey);
+          const [bidderProfile] = getBidderProfilePDA(publicKey);
 
           const depositIx = await l1Program.methods
-            .deposit(new BN(depositAmount))
+            .deposit(new BN(depositAmount), [])
             .accounts({
               bidder: publicKey,
               auctionState: auctionPubkey,
               bidderDeposit,
               auctionVault,
+              blocklist,
+              defaultRecord,
+              bidderProfile,
+              gateTokenAccount: null,
+              gateMetadata: null,
+              gateClaim: null,
               systemProgram: SystemProgram.programId,
             })
             .instruction();
@@ -165,12 +198,17 @@ export function useSessionBidding(): UseSessionBiddingReturn {
         setActivationProgress("Creating session...");
         const [sessionToken] = getSessionPDA(auctionPubkey, publicKey);
 
+        const expiresAt = new BN(Math.floor(Date.now() / 1000) + SESSION_DURATION_SECONDS);
+
         const createSessionIx = await l1Program.methods
-          .createSession(ephemeral.publicKey)
+          .createSession(ephemeral.publicKey, expiresAt, maxBidAmount, [])
           .accounts({
             bidder: publicKey,
             auctionState: auctionPubkey,
             sessionToken,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
             systemProgram: SystemProgram.programId,
           })
           .instruction();
@@ -240,14 +278,27 @@ export function useSessionBidding(): UseSessionBiddingReturn {
       const erProgram = getProgram(magicConnection, dummyWallet);
 
       const [sessionToken] = getSessionPDA(auctionPubkey, publicKey);
+      const seller = await fetchAuctionSeller(l1Connection, erProgram, auctionPubkey);
+      const [blocklist] = getBlocklistPDA(seller);
+      const [defaultRecord] = getDefaultRecordPDA(publicKey);
+      const [bidderProfile] = getBidderProfilePDA(publicKey);
+      const previousLot = await fetchPreviousSaleLot(l1Connection, erProgram, auctionPubkey);
 
       // Build the place_bid_session transaction
       const tx = await erProgram.methods
-        .placeBidSession(new BN(amount))
+        .placeBidSession(new BN(amount), null)
         .accounts({
           sessionSigner: keypair.publicKey,
           sessionToken,
+          globalSession: null,
           auctionState: auctionPubkey,
+          bidderDeposit: null,
+          blocklist,
+          defaultRecord,
+          bidderProfile,
+          previousLot,
+          bidderAccount: null,
+          outbidBidderAccount: null,
         })
         .transaction();
 
@@ -296,7 +347,7 @@ export function useSessionBidding(): UseSessionBiddingReturn {
 
       return { signature: sig, sendMs };
     },
-    [publicKey, magicConnection]
+    [publicKey, l1Connection, magicConnection]
   );
 
   const disableSession = useCallback(() => {
@@ -29,6 +29,13 @@ export const AUCTION_SEED = Buffer.from("auction");
 export const VAULT_SEED = Buffer.from("vault");
 export const DEPOSIT_SEED = Buffer.from("deposit");
 export const SESSION_SEED = Buffer.from("session");
+export const BLOCKLIST_SEED = Buffer.from("blocklist");
+export const DEFAULT_RECORD_SEED = Buffer.from("default_record");
+export const BIDDER_PROFILE_SEED = Buffer.from("bidder_profile");
+export const BIDDER_ACCOUNT_SEED = Buffer.from("bidder_account");
+export const SELLER_PROFILE_SEED = Buffer.from("seller_profile");
+export const EARNINGS_SEED = Buffer.from("earnings");
+export const UNCLAIMED_SEED = Buffer.from("unclaimed");
 // Protocol Constants
 export const PROTOCOL_FEE_BPS = 250; // 2.5%
 export const DEFAULT_EXTENSION_SECONDS = 300; // 5 min
@@ -36,6 +43,7 @@ export const DEFAULT_EXTENSION_WINDOW = 300; // 5 min
 export const DEFAULT_MIN_BID_INCREMENT = 100_000_000; // 0.1 SOL in lamports
 export const MIN_AUCTION_DURATION = 300; // 5 min
 export const MAX_AUCTION_DURATION = 604_800; // 7 days
+export const MAX_EXTENSION_SECONDS = 86_400; // 24 hours
 
 // Bubblegum / Badge Constants
 export const BUBBLEGUM_PROGRAM_ID_STR =
@@ -2,7 +2,21 @@ import { AnchorProvider, Program } from "@coral-xyz/anchor";
 import type { Idl } from "@coral-xyz/anchor";
 import { Connection, Keypair, PublicKey } from "@solana/web3.js";
 import type { AnchorWallet } from "@solana/wallet-adapter-react";
-import { PROGRAM_ID, AUCTION_SEED, VAULT_SEED, DEPOSIT_SEED, SESSION_SEED, TOKEN_METADATA_PROGRAM_ID } from "./constants";
+import {
+  PROGRAM_ID,
+  AUCTION_SEED,
+  VAULT_SEED,
+  DEPOSIT_SEED,
+  SESSION_SEED,
+  BLOCKLIST_SEED,
+  DEFAULT_RECORD_SEED,
+  BIDDER_PROFILE_SEED,
+  BIDDER_ACCOUNT_SEED,
+  SELLER_PROFILE_SEED,
+  EARNINGS_SEED,
+  UNCLAIMED_SEED,
+  TOKEN_METADATA_PROGRAM_ID,
+} from "./constants";
 import idl from "./idl.json";
 
 /**
@@ -65,6 +79,19 @@ export function getVaultPDA(
   );
 }
 
+/**
+ * Derives the UnclaimedFunds PDA left by a force close (may not exist).
+ * Seeds: ["unclaimed", auction_state_pubkey]
+ */
+export function getUnclaimedFundsPDA(
+  auctionState: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [UNCLAIMED_SEED, auctionState.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 /**
  * Derives the BidderDeposit PDA.
  * Seeds: ["deposit", auction_state_pubkey, bidder_pubkey]
@@ -93,6 +120,114 @@ export function getSessionPDA(
   );
 }
 
+/**
+ * Derives a seller's BlockList PDA (may not exist).
+ * Seeds: ["blocklist", seller_pubkey]
+ */
+export function getBlocklistPDA(seller: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BLOCKLIST_SEED, seller.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a wallet's DefaultRecord PDA (may not exist).
+ * Seeds: ["default_record", bidder_pubkey]
+ */
+export function getDefaultRecordPDA(bidder: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [DEFAULT_RECORD_SEED, bidder.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a wallet's BidderProfile PDA (may not exist).
+ * Seeds: ["bidder_profile", bidder_pubkey]
+ */
+export function getBidderProfilePDA(bidder: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BIDDER_PROFILE_SEED, bidder.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a wallet's BidderAccount PDA (may not exist).
+ * Seeds: ["bidder_account", bidder_pubkey]
+ */
+export function getBidderAccountPDA(bidder: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BIDDER_ACCOUNT_SEED, bidder.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a seller's SellerProfile PDA (may not exist).
+ * Seeds: ["seller_profile", seller_pubkey]
+ */
+export function getSellerProfilePDA(seller: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SELLER_PROFILE_SEED, seller.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derives a creator's CreatorEarnings PDA, where settlement credits royalties.
+ * Seeds: ["earnings", creator_pubkey]
+ */
+export function getCreatorEarningsPDA(creator: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [EARNINGS_SEED, creator.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Reads the seller of an auction from its L1 account. The seller never
+ * changes, so the L1 copy is accurate even while the auction is delegated.
+ */
+export async function fetchAuctionSeller(
+  connection: Connection,
+  program: Program,
+  auctionState: PublicKey
+): Promise<PublicKey> {
+  const info = await connection.getAccountInfo(auctionState);
+  if (!info) {
+    throw new Error("Auction not found");
+  }
+  const decoded = program.coder.accounts.decode("AuctionState", info.data);
+  return decoded.seller as PublicKey;
+}
+
+/**
+ * Previous lot of a sale lot after the first, which its bids must pass so it
+ * can't close ahead of that lot; null for standalone auctions and first lots.
+ */
+export async function fetchPreviousSaleLot(
+  connection: Connection,
+  program: Program,
+  auctionState: PublicKey
+): Promise<PublicKey | null> {
+  const info = await connection.getAccountInfo(auctionState);
+  if (!info) {
+    throw new Error("Auction not found");
+  }
+  const auction = program.coder.accounts.decode("AuctionState", info.data);
+  if (auction.lotIndex === 0) {
+    return null;
+  }
+  const saleInfo = await connection.getAccountInfo(auction.saleEvent as PublicKey);
+  if (!saleInfo) {
+    throw new Error("Sale event not found");
+  }
+  const sale = program.coder.accounts.decode("SaleEvent", saleInfo.data);
+  return sale.lots[auction.lotIndex - 1] as PublicKey;
+}
+
 /**
  * Derives the Metaplex Token Metadata PDA for an NFT mint.
  * Seeds: ["metadata", TOKEN_METADATA_PROGRAM_ID, nft_mint]
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::Pubkey;
 
 pub const PROTOCOL_FEE_BPS: u16 = 250; // 2.5%
+pub const MAX_BUYER_PREMIUM_BPS: u16 = 2_500; // 25%
+pub const MAX_HOUSE_FEE_BPS: u16 = 2_000; // 20%
 
 /// Protocol treasury — receives protocol fees at settlement.
 /// Address: B6MtVeqn7BrJ8HTX6CeP8VugNWyCqqbfcDMxYBknzPt7
@@ -22,9 +24,41 @@ pub const MIN_AUCTION_DURATION: u64 = 300; // 5 minutes
 pub const MAX_AUCTION_DURATION: u64 = 604_800; // 7 days
 
 pub const FORCE_CLOSE_GRACE_PERIOD: i64 = 604_800; // 7 days
-pub const MAX_EXTENSION_SECONDS: i64 = 3_600; // 1 hour — cap on total anti-snipe extension
+pub const RESERVE_REVEAL_PERIOD: i64 = 86_400; // 24 hours after end_time to reveal a hidden reserve
+pub const MAX_EXTENSION_SECONDS: u32 = 86_400; // 24 hours — upper bound on a per-auction extension cap
+pub const MAX_SESSION_DURATION: i64 = 604_800; // 7 days — longest a session key stays valid
+pub const MAX_DISPUTE_WINDOW_SECONDS: u32 = 2_592_000; // 30 days for escrowed physical-item payouts
+pub const MAX_BID_INTERVAL_SECONDS: u32 = 3_600; // 1 hour — longest per-bidder cooldown between bids
+pub const MAX_SALE_STAGGER_SECONDS: u32 = 3_600; // 1 hour — longest gap between consecutive lot closes
+
+pub const MAX_INCREMENT_TIERS: usize = 4;
+pub const MAX_SALE_LOTS: usize = 50;
+pub const MAX_PAYOUT_SPLITS: usize = 5;
+pub const MAX_SESSION_TARGETS: usize = 10;
+pub const MAX_ALLOWLIST_PROOF_LEN: usize = 24; // 2^24 wallets
+pub const MAX_BLOCKED_WALLETS: usize = 64;
+pub const MAX_OPEN_BID_LOCKS: usize = 10; // auctions one BidderAccount can hold bids in at once
+pub const RECENT_BIDDER_SLOTS: usize = 8; // bidders remembered for the per-bidder bid interval
+pub const DEFAULT_BAN_THRESHOLD: u32 = 2; // forfeits before auctions with ban_defaulters reject a wallet
+
+pub const BIDDER_PROFILE_VERSION: u8 = 1;
+pub const REPUTATION_WIN_POINTS: u32 = 10;
+pub const REPUTATION_DEFAULT_PENALTY: u32 = 50;
+pub const SELLER_PROFILE_VERSION: u8 = 1;
 
 pub const AUCTION_SEED: &[u8] = b"auction";
 pub const VAULT_SEED: &[u8] = b"vault";
 pub const DEPOSIT_SEED: &[u8] = b"deposit";
 pub const SESSION_SEED: &[u8] = b"session";
+pub const HOUSE_SEED: &[u8] = b"house";
+pub const SALE_SEED: &[u8] = b"sale";
+pub const EARNINGS_SEED: &[u8] = b"earnings";
+pub const PROCEEDS_SEED: &[u8] = b"proceeds";
+pub const UNCLAIMED_SEED: &[u8] = b"unclaimed";
+pub const BIDDER_ACCOUNT_SEED: &[u8] = b"bidder_account";
+pub const BID_NONCE_SEED: &[u8] = b"bid_nonce";
+pub const BLOCKLIST_SEED: &[u8] = b"blocklist";
+pub const DEFAULT_RECORD_SEED: &[u8] = b"default_record";
+pub const BIDDER_PROFILE_SEED: &[u8] = b"bidder_profile";
+pub const SELLER_PROFILE_SEED: &[u8] = b"seller_profile";
+pub const GATE_CLAIM_SEED: &[u8] = b"gate_claim";
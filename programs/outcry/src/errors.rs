@@ -50,7 +50,7 @@ pub enum OutcryError {
     InvalidTreasury,
     #[msg("Auction has no bids to settle")]
     NoBidsToSettle,
-    #[msg("Minimum bid increment must be greater than zero")]
+    #[msg("Bid increment must be greater than zero and tiers must be ascending")]
     InvalidBidIncrement,
     #[msg("Could not deserialize deposit account data")]
     InvalidDepositAccount,
@@ -62,4 +62,158 @@ pub enum OutcryError {
     SessionSignerMismatch,
     #[msg("Session token bidder does not match expected bidder")]
     SessionBidderMismatch,
+    #[msg("Scheduled start must be in the future")]
+    InvalidScheduledStart,
+    #[msg("Auction has no scheduled start — the seller must start it")]
+    NotScheduled,
+    #[msg("Anti-snipe policy configuration is invalid")]
+    InvalidAntiSnipePolicy,
+    #[msg("Revealed reserve does not match the committed hash")]
+    InvalidReserveReveal,
+    #[msg("Hidden reserve must be revealed before settlement")]
+    ReserveNotRevealed,
+    #[msg("Reserve reveal window has closed")]
+    RevealWindowClosed,
+    #[msg("Buyer's premium exceeds the maximum")]
+    InvalidBuyerPremium,
+    #[msg("House fee exceeds the maximum")]
+    InvalidHouseFee,
+    #[msg("This house requires the curator to co-sign listings")]
+    CuratorApprovalRequired,
+    #[msg("Auction house does not match the auction")]
+    InvalidAuctionHouse,
+    #[msg("Fee recipient does not match the auction house")]
+    InvalidFeeRecipient,
+    #[msg("Sale event has no room for more lots")]
+    SaleEventFull,
+    #[msg("Sale has already started — lots can no longer be added")]
+    SaleAlreadyStarted,
+    #[msg("Auction is not a lot in this sale event")]
+    InvalidSaleLot,
+    #[msg("Sale lots must close in order — advance this lot after the previous one")]
+    SaleLotOutOfOrder,
+    #[msg("Payout split shares must be non-zero and sum to 10,000 bps")]
+    InvalidPayoutSplit,
+    #[msg("Payout recipient account missing or does not match the split table")]
+    MissingPayoutAccount,
+    #[msg("Creator earnings account is invalid")]
+    InvalidCreatorEarnings,
+    #[msg("No creator earnings to withdraw")]
+    NothingToWithdraw,
+    #[msg("Escrow terms are invalid — arbiter required and dispute window out of range")]
+    InvalidEscrowTerms,
+    #[msg("This auction escrows seller proceeds — pass the proceeds escrow account")]
+    ProceedsEscrowRequired,
+    #[msg("Only the buyer can do this")]
+    UnauthorizedBuyer,
+    #[msg("Only the arbiter can resolve disputes")]
+    UnauthorizedArbiter,
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+    #[msg("Proceeds are under dispute")]
+    ProceedsDisputed,
+    #[msg("Proceeds are not under dispute")]
+    NotDisputed,
+    #[msg("Refund exceeds the held proceeds")]
+    InvalidRefundAmount,
+    #[msg("Unclaimed deposits remain — pass the unclaimed funds account")]
+    UnclaimedFundsRequired,
+    #[msg("Batch accounts must be non-empty (bidder, bidder_deposit) pairs")]
+    InvalidBatchAccounts,
+    #[msg("Vault lamports do not cover outstanding deposits")]
+    VaultInsolvent,
+    #[msg("Withdrawal exceeds the deposit above your current commitment")]
+    WithdrawExceedsExcess,
+    #[msg("Bidder account balance too low")]
+    InsufficientBalance,
+    #[msg("Locked funds can't be released while you hold the top bid")]
+    BidLockActive,
+    #[msg("Bidder account is delegated; undelegate it first")]
+    BidderAccountDelegated,
+    #[msg("Bidder account does not match the bidder")]
+    InvalidBidderAccount,
+    #[msg("Session expiry must be in the future and within the maximum session duration")]
+    InvalidSessionExpiry,
+    #[msg("Session spend cap must be greater than zero")]
+    InvalidSessionCap,
+    #[msg("Session has expired")]
+    SessionExpired,
+    #[msg("Session has been revoked")]
+    SessionRevoked,
+    #[msg("Bid exceeds the session's spend cap")]
+    SessionCapExceeded,
+    #[msg("Pass exactly one of session_token or global_session")]
+    InvalidSessionAccounts,
+    #[msg("Too many allowed auctions or houses for a global session")]
+    InvalidSessionTargets,
+    #[msg("Global session is not allowed in this auction")]
+    SessionTargetNotAllowed,
+    #[msg("Bid exceeds the global session's remaining bid-volume cap")]
+    SessionVolumeCapExceeded,
+    #[msg("Signed bid must be preceded by a matching Ed25519 verify instruction")]
+    InvalidBidSignature,
+    #[msg("Signed bid has expired")]
+    SignedBidExpired,
+    #[msg("Signed bid nonce already used")]
+    SignedBidReplayed,
+    #[msg("Bid transaction is past its valid_until")]
+    BidIntentExpired,
+    #[msg("Current bid changed since the bid was built")]
+    BidStateChanged,
+    #[msg("Bid exceeds the bidder's max_amount")]
+    BidAboveMax,
+    #[msg("Next-increment bids require a max_amount guard")]
+    BidGuardRequired,
+    #[msg("Wallet is not on this auction's allowlist")]
+    NotAllowlisted,
+    #[msg("Allowlist proof does not match the auction's root")]
+    InvalidAllowlistProof,
+    #[msg("Bidder has not proven holding a token from the gate collection")]
+    NotCollectionHolder,
+    #[msg("Asset type is not supported for collection gating")]
+    UnsupportedGateAsset,
+    #[msg("Wallet is on the seller's blocklist")]
+    WalletBlocked,
+    #[msg("Wallet is banned for repeated defaults")]
+    WalletBanned,
+    #[msg("Blocklist is full")]
+    BlockListFull,
+    #[msg("Blocklist account does not match the auction's seller")]
+    InvalidBlockList,
+    #[msg("Bidder profile account does not match the bidder")]
+    InvalidBidderProfile,
+    #[msg("Bidder has more defaults than this auction allows")]
+    TooManyDefaults,
+    #[msg("Bidder reputation is below this auction's minimum")]
+    ReputationTooLow,
+    #[msg("Bidder must wait longer between bids on this auction")]
+    BidRateLimited,
+    #[msg("Bidder already holds the top bid")]
+    SelfOutbid,
+    #[msg("Extension cap exceeds the maximum allowed")]
+    InvalidExtensionCap,
+    #[msg("Bid interval exceeds the maximum allowed")]
+    InvalidBidInterval,
+    #[msg("Sale stagger exceeds the maximum allowed")]
+    InvalidSaleStagger,
+    #[msg("Unclaimed deposits from an earlier listing must be redeemed before relisting")]
+    UnclaimedFundsOutstanding,
+    #[msg("The leader of the last committed auction state can't withdraw while it is delegated")]
+    LeaderWithdrawWhileDelegated,
+    #[msg("Gate claim account does not match the auction and gate token")]
+    InvalidGateClaim,
+    #[msg("Gate token already qualified another bidder on this auction")]
+    GateTokenClaimed,
+    #[msg("Default record account does not match the bidder")]
+    InvalidDefaultRecord,
+    #[msg("Bidder account already holds locks in the maximum number of auctions")]
+    BidLocksFull,
+    #[msg("Locked funds can only be released where the auction is live")]
+    BidLockAuctionDelegated,
+    #[msg("Bidder account still has funds locked into auctions")]
+    BidLocksOutstanding,
+    #[msg("No unclaimed deposits — don't pass the unclaimed funds account")]
+    UnclaimedFundsNotNeeded,
 }
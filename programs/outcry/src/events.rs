@@ -5,8 +5,55 @@ pub struct AuctionCreated {
     pub auction: Pubkey,
     pub seller: Pubkey,
     pub nft_mint: Pubkey,
+    pub house: Pubkey,
     pub reserve_price: u64,
     pub duration_seconds: u64,
+    pub scheduled_start: i64,
+    pub hidden_reserve: bool,
+    pub seller_bond: u64,
+}
+
+#[event]
+pub struct AllowlistUpdated {
+    pub auction: Pubkey,
+    /// All zeros = allowlist removed
+    pub root: [u8; 32],
+}
+
+#[event]
+pub struct BidderBlocked {
+    pub seller: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct BidderUnblocked {
+    pub seller: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct WinnerDefaulted {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    /// Total forfeits on this wallet's record
+    pub defaults: u32,
+}
+
+#[event]
+pub struct SellerBondSlashed {
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    /// Winner for an unrevealed reserve, protocol treasury for a cancel
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SellerBondReturned {
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    pub amount: u64,
 }
 
 #[event]
@@ -16,6 +63,60 @@ pub struct AuctionStarted {
     pub end_time: i64,
 }
 
+#[event]
+pub struct DepositWithdrawn {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub remaining_deposit: u64,
+}
+
+#[event]
+pub struct BidderAccountFunded {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct BidderAccountWithdrawn {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct BidderAccountClosed {
+    pub owner: Pubkey,
+    /// Free balance returned along with the rent
+    pub balance: u64,
+}
+
+#[event]
+pub struct BidFundsUnlocked {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SessionCreated {
+    /// Pubkey::default() for a global session
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub session_signer: Pubkey,
+    pub expires_at: i64,
+    pub max_bid_amount: u64,
+}
+
+#[event]
+pub struct SessionRevoked {
+    /// Pubkey::default() for a global session
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub session_signer: Pubkey,
+}
+
 #[event]
 pub struct BidPlaced {
     pub auction: Pubkey,
@@ -24,6 +125,7 @@ pub struct BidPlaced {
     pub previous_bid: u64,
     pub bid_count: u32,
     pub new_end_time: i64,
+    pub extended: bool,
 }
 
 #[event]
@@ -42,6 +144,8 @@ pub struct AuctionSettled {
     pub seller_received: u64,
     pub royalties_paid: u64,
     pub protocol_fee: u64,
+    pub house_fee: u64,
+    pub buyer_premium: u64,
 }
 
 #[event]
@@ -69,5 +173,73 @@ pub struct AuctionCancelled {
 pub struct AuctionForceClosed {
     pub auction: Pubkey,
     pub seller: Pubkey,
+    /// Unclaimed deposits moved into UnclaimedFunds
     pub drained_lamports: u64,
 }
+
+#[event]
+pub struct ReserveRevealed {
+    pub auction: Pubkey,
+    pub reserve_price: u64,
+    pub winning_bid: u64,
+    pub reserve_met: bool,
+}
+
+#[event]
+pub struct AuctionHouseConfigured {
+    pub house: Pubkey,
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub fee_recipient: Pubkey,
+    pub curator: Pubkey,
+    pub buyer_premium_bps: u16,
+}
+
+#[event]
+pub struct SaleEventCreated {
+    pub sale: Pubkey,
+    pub organizer: Pubkey,
+    pub start_time: i64,
+    pub stagger_seconds: u32,
+}
+
+#[event]
+pub struct SaleLotAdded {
+    pub sale: Pubkey,
+    pub auction: Pubkey,
+    pub lot_index: u16,
+}
+
+#[event]
+pub struct SaleLotRescheduled {
+    pub sale: Pubkey,
+    pub auction: Pubkey,
+    pub lot_index: u16,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct CreatorEarningsWithdrawn {
+    pub creator: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProceedsEscrowed {
+    pub auction: Pubkey,
+    pub amount: u64,
+    pub release_after: i64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub auction: Pubkey,
+    pub buyer: Pubkey,
+}
+
+#[event]
+pub struct ProceedsReleased {
+    pub auction: Pubkey,
+    pub seller_amount: u64,
+    pub buyer_refund: u64,
+}
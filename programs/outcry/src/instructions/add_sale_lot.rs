@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::SaleLotAdded,
+    state::{AuctionState, AuctionStatus, SaleEvent},
+};
+
+/// Appends a Created auction to the end of a sale's closing order. The lot
+/// inherits the sale's start time and stagger, and its duration grows by one
+/// stagger per earlier lot so the catalog closes one lot after another.
+#[derive(Accounts)]
+pub struct AddSaleLot<'info> {
+    pub organizer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SALE_SEED, organizer.key().as_ref(), &sale_event.sale_id.to_le_bytes()],
+        bump = sale_event.bump,
+        has_one = organizer @ OutcryError::UnauthorizedSeller,
+    )]
+    pub sale_event: Account<'info, SaleEvent>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, organizer.key().as_ref(), auction_state.nft_mint.as_ref()],
+        bump = auction_state.bump,
+        constraint = auction_state.status == AuctionStatus::Created @ OutcryError::InvalidAuctionStatus,
+        constraint = auction_state.sale_event == Pubkey::default() @ OutcryError::InvalidSaleLot,
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+}
+
+pub fn handle_add_sale_lot(ctx: Context<AddSaleLot>) -> Result<()> {
+    let clock = Clock::get()?;
+    let sale = &mut ctx.accounts.sale_event;
+
+    require!(
+        clock.unix_timestamp < sale.start_time,
+        OutcryError::SaleAlreadyStarted
+    );
+    require!(sale.lots.len() < MAX_SALE_LOTS, OutcryError::SaleEventFull);
+
+    let lot_index = sale.lots.len() as u16;
+    let stagger_offset = (sale.stagger_seconds as u64)
+        .checked_mul(lot_index as u64)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+
+    let auction = &mut ctx.accounts.auction_state;
+    auction.sale_event = sale.key();
+    auction.lot_index = lot_index;
+    auction.sale_stagger_seconds = sale.stagger_seconds;
+    auction.scheduled_start = sale.start_time;
+    auction.duration_seconds = auction
+        .duration_seconds
+        .checked_add(stagger_offset)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+    require!(
+        auction.duration_seconds <= MAX_AUCTION_DURATION,
+        OutcryError::InvalidDuration
+    );
+
+    sale.lots.push(auction.key());
+
+    emit!(SaleLotAdded {
+        sale: sale.key(),
+        auction: auction.key(),
+        lot_index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::OutcryError,
+    events::{AuctionEnded, SaleLotRescheduled},
+    state::{AuctionState, AuctionStatus, SaleEvent},
+};
+
+/// Permissionless crank for lots after the first in a sale (on ER). Keeps the
+/// lot closing at least `stagger_seconds` after the previous lot, and ends it
+/// once the previous lot has closed (ended, settled or otherwise finished —
+/// never just Created or still Active) and its own timer has expired. Bids
+/// apply the same rule, so anti-snipe extensions cascade down the catalog
+/// without waiting for this crank.
+#[derive(Accounts)]
+pub struct AdvanceSaleLot<'info> {
+    /// Anyone can crank this — permissionless
+    pub authority: Signer<'info>,
+
+    pub sale_event: Account<'info, SaleEvent>,
+
+    #[account(
+        constraint = previous_lot.sale_event == sale_event.key() @ OutcryError::InvalidSaleLot,
+    )]
+    pub previous_lot: Account<'info, AuctionState>,
+
+    #[account(
+        mut,
+        constraint = auction_state.status == AuctionStatus::Active @ OutcryError::InvalidAuctionStatus,
+        constraint = auction_state.sale_event == sale_event.key() @ OutcryError::InvalidSaleLot,
+        constraint = auction_state.lot_index > 0 @ OutcryError::InvalidSaleLot,
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+}
+
+pub fn handle_advance_sale_lot(ctx: Context<AdvanceSaleLot>) -> Result<()> {
+    let clock = Clock::get()?;
+    let sale = &ctx.accounts.sale_event;
+    let previous = &ctx.accounts.previous_lot;
+    let auction = &mut ctx.accounts.auction_state;
+
+    let previous_index = (auction.lot_index - 1) as usize;
+    require!(
+        sale.lots.get(previous_index) == Some(&previous.key()),
+        OutcryError::InvalidSaleLot
+    );
+
+    // Push this lot back if the previous one was extended into its slot, or
+    // is still running past it
+    let rescheduled = auction.sync_sale_close(Some(previous), clock.unix_timestamp)?;
+    if rescheduled {
+        emit!(SaleLotRescheduled {
+            sale: sale.key(),
+            auction: auction.key(),
+            lot_index: auction.lot_index,
+            end_time: auction.end_time,
+        });
+    }
+
+    // Only ends once the previous lot has closed and this one's timer expired;
+    // otherwise keep whatever was rescheduled
+    if !previous.sale_lot_closed() {
+        require!(rescheduled, OutcryError::SaleLotOutOfOrder);
+        return Ok(());
+    }
+    if clock.unix_timestamp < auction.end_time {
+        require!(rescheduled, OutcryError::AuctionStillActive);
+        return Ok(());
+    }
+
+    auction.status = AuctionStatus::Ended;
+
+    emit!(AuctionEnded {
+        auction: auction.key(),
+        winner: auction.highest_bidder,
+        winning_bid: auction.current_bid,
+        total_bids: auction.bid_count,
+    });
+
+    Ok(())
+}
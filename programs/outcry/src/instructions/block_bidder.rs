@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::BidderBlocked,
+    state::BlockList,
+};
+
+/// Adds a wallet to the seller's blocklist, creating it on first use. The
+/// blocklist applies to every auction the seller runs, including live ones —
+/// the ER picks up the L1 change on its read-only clone.
+#[derive(Accounts)]
+pub struct BlockBidder<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + BlockList::INIT_SPACE,
+        seeds = [BLOCKLIST_SEED, seller.key().as_ref()],
+        bump,
+    )]
+    pub blocklist: Account<'info, BlockList>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_block_bidder(ctx: Context<BlockBidder>, wallet: Pubkey) -> Result<()> {
+    let seller_key = ctx.accounts.seller.key();
+    let list = &mut ctx.accounts.blocklist;
+
+    // Initialize fields if this is a new blocklist
+    if list.seller == Pubkey::default() {
+        list.seller = seller_key;
+        list.bump = ctx.bumps.blocklist;
+    }
+
+    // Blocking an already-blocked wallet is a no-op
+    if list.contains(&wallet) {
+        return Ok(());
+    }
+    require!(
+        list.blocked.len() < MAX_BLOCKED_WALLETS,
+        OutcryError::BlockListFull
+    );
+    list.blocked.push(wallet);
+
+    emit!(BidderBlocked {
+        seller: seller_key,
+        wallet,
+    });
+
+    Ok(())
+}
@@ -4,8 +4,8 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 use crate::{
     constants::*,
     errors::OutcryError,
-    events::AuctionCancelled,
-    state::{AuctionState, AuctionStatus},
+    events::{AuctionCancelled, SellerBondSlashed},
+    state::{AuctionState, AuctionStatus, AuctionVault, SellerProfile},
 };
 
 #[derive(Accounts)]
@@ -21,6 +21,31 @@ pub struct CancelAuction<'info> {
     )]
     pub auction_state: Account<'info, AuctionState>,
 
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, auction_state.key().as_ref()],
+        bump = auction_vault.bump,
+    )]
+    pub auction_vault: Account<'info, AuctionVault>,
+
+    /// Seller's SellerProfile — the cancellation is recorded here, created
+    /// if needed so sellers can't dodge their history
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + SellerProfile::INIT_SPACE,
+        seeds = [SELLER_PROFILE_SEED, seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_profile: Account<'info, SellerProfile>,
+
+    /// CHECK: Protocol treasury — constrained to the hardcoded PROTOCOL_TREASURY address
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == PROTOCOL_TREASURY @ OutcryError::InvalidTreasury,
+    )]
+    pub protocol_treasury: UncheckedAccount<'info>,
+
     pub nft_mint: Account<'info, Mint>,
 
     #[account(
@@ -38,6 +63,7 @@ pub struct CancelAuction<'info> {
     pub seller_nft_token_account: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handle_cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
@@ -49,6 +75,7 @@ pub fn handle_cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
             || (auction.status == AuctionStatus::Ended && auction.bid_count == 0),
         OutcryError::InvalidAuctionStatus
     );
+    let before_start = auction.status == AuctionStatus::Created;
 
     // Return NFT to seller
     let seller_key = ctx.accounts.seller.key();
@@ -76,6 +103,30 @@ pub fn handle_cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
 
     ctx.accounts.auction_state.status = AuctionStatus::Cancelled;
 
+    let profile = &mut ctx.accounts.seller_profile;
+    if profile.seller == Pubkey::default() {
+        profile.init(seller_key, ctx.bumps.seller_profile);
+    }
+    profile.record_cancellation();
+
+    // Pulling a listing before it opens, once bidders have deposited into
+    // it, costs the bond. An auction that ran and drew no bids doesn't.
+    let vault = &mut ctx.accounts.auction_vault;
+    if before_start && vault.total_deposited > 0 {
+        let vault_info = vault.to_account_info();
+        let slashed =
+            vault.slash_bond(&vault_info, &ctx.accounts.protocol_treasury.to_account_info())?;
+        if slashed > 0 {
+            profile.record_slash(slashed)?;
+            emit!(SellerBondSlashed {
+                auction: ctx.accounts.auction_state.key(),
+                seller: seller_key,
+                recipient: PROTOCOL_TREASURY,
+                amount: slashed,
+            });
+        }
+    }
+
     emit!(AuctionCancelled {
         auction: ctx.accounts.auction_state.key(),
         seller: ctx.accounts.seller.key(),
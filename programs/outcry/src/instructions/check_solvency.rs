@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AuctionVault;
+
+/// Read-only invariant check: fails with VaultInsolvent if the vault's
+/// lamports have dropped below rent plus outstanding deposits.
+#[derive(Accounts)]
+pub struct CheckSolvency<'info> {
+    pub auction_vault: Account<'info, AuctionVault>,
+}
+
+pub fn handle_check_solvency(ctx: Context<CheckSolvency>) -> Result<()> {
+    let vault = &ctx.accounts.auction_vault;
+    vault.assert_solvent(&vault.to_account_info())
+}
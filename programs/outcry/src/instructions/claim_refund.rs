@@ -4,7 +4,7 @@ use crate::{
     constants::*,
     errors::OutcryError,
     events::RefundClaimed,
-    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit},
+    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit, BidderProfile},
 };
 
 #[derive(Accounts)]
@@ -15,6 +15,7 @@ pub struct ClaimRefund<'info> {
     #[account(
         constraint = auction_state.status == AuctionStatus::Settled
             || auction_state.status == AuctionStatus::Cancelled
+            || auction_state.status == AuctionStatus::ReserveNotMet
             @ OutcryError::RefundNotAvailable,
     )]
     pub auction_state: Account<'info, AuctionState>,
@@ -35,6 +36,15 @@ pub struct ClaimRefund<'info> {
     )]
     pub auction_vault: Account<'info, AuctionVault>,
 
+    /// Bidder's BidderProfile — credited with the participation if it exists.
+    /// CHECK: PDA validated via seeds; updated via BidderProfile::update_if_exists
+    #[account(
+        mut,
+        seeds = [BIDDER_PROFILE_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_profile: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -54,6 +64,18 @@ pub fn handle_claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
     **vault_info.try_borrow_mut_lamports()? -= refund_amount;
     **bidder_info.try_borrow_mut_lamports()? += refund_amount;
 
+    let vault = &mut ctx.accounts.auction_vault;
+    vault.record_refund(refund_amount)?;
+    vault.assert_solvent(&vault_info)?;
+
+    // A refund from an auction that actually ran counts as participation
+    if ctx.accounts.auction_state.status != AuctionStatus::Cancelled {
+        BidderProfile::update_if_exists(&ctx.accounts.bidder_profile, |profile| {
+            profile.record_participation();
+            Ok(())
+        })?;
+    }
+
     emit!(RefundClaimed {
         auction: auction_key,
         bidder: bidder_key,
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::RefundClaimed,
+    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit, BidderProfile},
+};
+
+/// Permissionless batch version of claim_refund_for. Takes
+/// (bidder, bidder_deposit, bidder_profile) triples through
+/// remaining_accounts, all writable, and refunds and closes each deposit.
+/// Profiles are credited with the participation like claim_refund_for; pass
+/// the PDA even if it doesn't exist. Pack as many triples as the
+/// transaction's account and compute limits allow.
+#[derive(Accounts)]
+pub struct ClaimRefundsBatch<'info> {
+    /// Anyone can pay the transaction fee (typically the seller).
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = auction_state.status == AuctionStatus::Settled
+            || auction_state.status == AuctionStatus::Cancelled
+            || auction_state.status == AuctionStatus::ReserveNotMet
+            @ OutcryError::RefundNotAvailable,
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, auction_state.key().as_ref()],
+        bump = auction_vault.bump,
+    )]
+    pub auction_vault: Account<'info, AuctionVault>,
+}
+
+pub fn handle_claim_refunds_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimRefundsBatch<'info>>,
+) -> Result<()> {
+    let triples = ctx.remaining_accounts.chunks_exact(3);
+    require!(
+        triples.len() > 0 && triples.remainder().is_empty(),
+        OutcryError::InvalidBatchAccounts
+    );
+
+    let auction_key = ctx.accounts.auction_state.key();
+    let ran = ctx.accounts.auction_state.status != AuctionStatus::Cancelled;
+    let vault_info = ctx.accounts.auction_vault.to_account_info();
+    let mut total_refunded: u64 = 0;
+
+    for triple in triples {
+        let (bidder_info, deposit_info, profile_info) = (&triple[0], &triple[1], &triple[2]);
+
+        // Owner + discriminator checked by Account, address re-derived from
+        // DEPOSIT_SEED with the stored bump
+        let mut deposit = Account::<BidderDeposit>::try_from(deposit_info)
+            .map_err(|_| error!(OutcryError::InvalidDepositAccount))?;
+        let expected = Pubkey::create_program_address(
+            &[
+                DEPOSIT_SEED,
+                auction_key.as_ref(),
+                bidder_info.key.as_ref(),
+                &[deposit.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| error!(OutcryError::InvalidDepositAccount))?;
+        require_keys_eq!(
+            deposit_info.key(),
+            expected,
+            OutcryError::InvalidDepositAccount
+        );
+
+        // Forfeited deposits (amount 0) are just closed to return their rent
+        let refund_amount = deposit.amount;
+        deposit.amount = 0;
+        if refund_amount > 0 {
+            **vault_info.try_borrow_mut_lamports()? -= refund_amount;
+            **bidder_info.try_borrow_mut_lamports()? += refund_amount;
+            total_refunded = total_refunded
+                .checked_add(refund_amount)
+                .ok_or(OutcryError::ArithmeticOverflow)?;
+        }
+        deposit.close(bidder_info.clone())?;
+
+        let (profile_key, _) = Pubkey::find_program_address(
+            &[BIDDER_PROFILE_SEED, bidder_info.key.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            profile_info.key(),
+            profile_key,
+            OutcryError::InvalidBidderProfile
+        );
+        if ran {
+            BidderProfile::update_if_exists(profile_info, |profile| {
+                profile.record_participation();
+                Ok(())
+            })?;
+        }
+
+        emit!(RefundClaimed {
+            auction: auction_key,
+            bidder: bidder_info.key(),
+            amount: refund_amount,
+        });
+    }
+
+    let vault = &mut ctx.accounts.auction_vault;
+    vault.record_refund(total_refunded)?;
+    vault.assert_solvent(&vault_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::RefundClaimed,
+    state::{BidderDeposit, UnclaimedFunds},
+};
+
+/// claim_refund for a force-closed auction: redeems the bidder's deposit
+/// from UnclaimedFunds. No deadline — works for as long as the deposit PDA
+/// exists. The last claim closes UnclaimedFunds and returns its rent.
+#[derive(Accounts)]
+pub struct ClaimUnclaimedRefund<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [UNCLAIMED_SEED, unclaimed_funds.auction.as_ref()],
+        bump = unclaimed_funds.bump,
+        has_one = seller,
+    )]
+    pub unclaimed_funds: Account<'info, UnclaimedFunds>,
+
+    #[account(
+        mut,
+        seeds = [DEPOSIT_SEED, unclaimed_funds.auction.as_ref(), bidder.key().as_ref()],
+        bump = bidder_deposit.bump,
+        constraint = bidder_deposit.amount > 0 @ OutcryError::NothingToRefund,
+        close = bidder,
+    )]
+    pub bidder_deposit: Account<'info, BidderDeposit>,
+
+    /// CHECK: Validated against unclaimed_funds.seller; receives rent on final claim
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+}
+
+pub fn handle_claim_unclaimed_refund(ctx: Context<ClaimUnclaimedRefund>) -> Result<()> {
+    let refund_amount = ctx.accounts.bidder_deposit.amount;
+    ctx.accounts.bidder_deposit.amount = 0;
+
+    let unclaimed = &mut ctx.accounts.unclaimed_funds;
+    unclaimed.remaining = unclaimed
+        .remaining
+        .checked_sub(refund_amount)
+        .ok_or(OutcryError::InsufficientVaultBalance)?;
+
+    let unclaimed_info = unclaimed.to_account_info();
+    let bidder_info = ctx.accounts.bidder.to_account_info();
+    **unclaimed_info.try_borrow_mut_lamports()? -= refund_amount;
+    **bidder_info.try_borrow_mut_lamports()? += refund_amount;
+
+    emit!(RefundClaimed {
+        auction: unclaimed.auction,
+        bidder: ctx.accounts.bidder.key(),
+        amount: refund_amount,
+    });
+
+    // Everything redeemed — return the rent to the seller
+    if unclaimed.remaining == 0 {
+        unclaimed.close(ctx.accounts.seller.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use crate::{
     constants::*,
     errors::OutcryError,
+    events::SellerBondReturned,
     state::{AuctionState, AuctionStatus, AuctionVault},
 };
 
@@ -17,6 +18,7 @@ pub struct CloseAuction<'info> {
         has_one = seller @ OutcryError::UnauthorizedSeller,
         constraint = auction_state.status == AuctionStatus::Settled
             || auction_state.status == AuctionStatus::Cancelled
+            || auction_state.status == AuctionStatus::ReserveNotMet
             @ OutcryError::InvalidAuctionStatus,
         seeds = [AUCTION_SEED, seller.key().as_ref(), nft_mint.key().as_ref()],
         bump = auction_state.bump,
@@ -47,16 +49,11 @@ pub struct CloseAuction<'info> {
 pub fn handle_close_auction(ctx: Context<CloseAuction>) -> Result<()> {
     let auction = &ctx.accounts.auction_state;
 
-    // NOTE: With separate BidderDeposit PDAs, outstanding deposit tracking
-    // is no longer embedded in AuctionState. Bidders close their own deposit
-    // accounts via claim_refund. The vault must be empty to close.
-
-    // Ensure vault has no unclaimed deposits (only rent-exempt lamports remain)
-    let vault_info = ctx.accounts.auction_vault.to_account_info();
-    let rent = Rent::get()?;
-    let vault_rent = rent.minimum_balance(vault_info.data_len());
+    // Every deposit must be refunded or paid out before closing. The ledger
+    // is authoritative — stray lamports sent to the vault don't block close
+    // and are returned to the seller with the rent.
     require!(
-        vault_info.lamports() <= vault_rent,
+        ctx.accounts.auction_vault.liabilities()? == 0,
         OutcryError::OutstandingDeposits
     );
 
@@ -89,7 +86,16 @@ pub fn handle_close_auction(ctx: Context<CloseAuction>) -> Result<()> {
     ))?;
 
     // AuctionState and AuctionVault are closed by Anchor's `close` constraint.
-    // All rent-exempt lamports are returned to seller.
+    // All rent-exempt lamports are returned to seller, along with any
+    // seller bond that wasn't slashed.
+    let seller_bond = ctx.accounts.auction_vault.seller_bond;
+    if seller_bond > 0 {
+        emit!(SellerBondReturned {
+            auction: ctx.accounts.auction_state.key(),
+            seller: seller_key,
+            amount: seller_bond,
+        });
+    }
 
     Ok(())
 }
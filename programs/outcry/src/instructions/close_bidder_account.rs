@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::BidderAccountClosed,
+    state::BidderAccount,
+};
+
+/// Owner closes their BidderAccount once no auction holds a lock on it,
+/// getting the free balance and the rent back. Call on L1 — undelegate the
+/// account first if it was bidding on the ER.
+#[derive(Accounts)]
+pub struct CloseBidderAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = bidder_account.bump,
+        has_one = owner @ OutcryError::InvalidBidderAccount,
+        constraint = bidder_account.locked == 0 @ OutcryError::BidLocksOutstanding,
+        close = owner,
+    )]
+    pub bidder_account: Account<'info, BidderAccount>,
+}
+
+pub fn handle_close_bidder_account(ctx: Context<CloseBidderAccount>) -> Result<()> {
+    emit!(BidderAccountClosed {
+        owner: ctx.accounts.owner.key(),
+        balance: ctx.accounts.bidder_account.balance,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SESSION_SEED,
+    errors::OutcryError,
+    state::GlobalSession,
+};
+
+/// Real wallet closes its global session and reclaims the rent. Call on L1
+/// after undelegate_global_session if it was delegated.
+#[derive(Accounts)]
+pub struct CloseGlobalSession<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SESSION_SEED, bidder.key().as_ref()],
+        bump = global_session.bump,
+        constraint = global_session.bidder == bidder.key() @ OutcryError::SessionBidderMismatch,
+        close = bidder,
+    )]
+    pub global_session: Account<'info, GlobalSession>,
+}
+
+pub fn handle_close_global_session(_ctx: Context<CloseGlobalSession>) -> Result<()> {
+    Ok(())
+}
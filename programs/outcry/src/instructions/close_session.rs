@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SESSION_SEED,
+    errors::OutcryError,
+    state::SessionToken,
+};
+
+/// Real wallet closes its session token and reclaims the rent. Call on L1 at
+/// any time — once the account is gone, the ER clone has nothing to
+/// deserialize and place_bid_session fails for that session.
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SESSION_SEED, session_token.auction.as_ref(), bidder.key().as_ref()],
+        bump = session_token.bump,
+        constraint = session_token.bidder == bidder.key() @ OutcryError::SessionBidderMismatch,
+        close = bidder,
+    )]
+    pub session_token: Account<'info, SessionToken>,
+}
+
+pub fn handle_close_session(_ctx: Context<CloseSession>) -> Result<()> {
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount, Transfer},
@@ -8,7 +9,10 @@ use crate::{
     constants::*,
     errors::OutcryError,
     events::AuctionCreated,
-    state::{AuctionState, AuctionStatus, AuctionVault},
+    state::{
+        AntiSnipePolicy, AuctionHouse, AuctionState, AuctionStatus, AuctionVault, BidIncrement,
+        EscrowTerms, PayoutSplit, RecentBid,
+    },
 };
 
 #[derive(Accounts)]
@@ -55,25 +59,117 @@ pub struct CreateAuction<'info> {
     )]
     pub auction_vault: Account<'info, AuctionVault>,
 
+    /// Leftover deposits from an earlier force-closed listing at this same
+    /// PDA — may not exist. Relisting waits until every one is redeemed, so
+    /// those BidderDeposits can't carry over into the new auction.
+    /// CHECK: PDA validated via seeds; only its owner is read
+    #[account(
+        seeds = [UNCLAIMED_SEED, auction_state.key().as_ref()],
+        bump,
+        constraint = unclaimed_funds.owner != &crate::ID @ OutcryError::UnclaimedFundsOutstanding,
+    )]
+    pub unclaimed_funds: UncheckedAccount<'info>,
+
+    /// Optional house to list under — its fee and premium are snapshotted
+    pub auction_house: Option<Account<'info, AuctionHouse>>,
+
+    /// Curator co-signature, required when the house curates listings
+    pub curator: Option<Signer<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_create_auction(
-    ctx: Context<CreateAuction>,
-    reserve_price: u64,
-    duration_seconds: u64,
-    extension_seconds: u32,
-    extension_window: u32,
-    min_bid_increment: u64,
-) -> Result<()> {
+/// Seller-chosen auction configuration, fixed at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateAuctionParams {
+    /// Minimum acceptable first bid (lamports) — the opening price when a
+    /// hidden reserve is set
+    pub reserve_price: u64,
+    /// sha256(reserve_le_bytes || salt) to keep the real reserve hidden
+    pub reserve_commitment: Option<[u8; 32]>,
+    pub duration_seconds: u64,
+    /// Seconds added per anti-snipe extension
+    pub extension_seconds: u32,
+    /// Window before end_time in which a bid triggers anti-snipe
+    pub extension_window: u32,
+    pub min_bid_increment: u64,
+    /// Percentage or tiered increment, floored at min_bid_increment
+    pub bid_increment: BidIncrement,
+    /// If set, anyone can start the auction after this Unix timestamp
+    pub scheduled_start: Option<i64>,
+    pub anti_snipe_policy: AntiSnipePolicy,
+    /// Cap on total extension beyond the original end time (seconds)
+    pub max_extension_seconds: u32,
+    /// Premium the winner pays on top of the hammer price (bps). Must be 0
+    /// under a house — the house premium applies instead.
+    pub buyer_premium_bps: u16,
+    /// Consignment split of the seller's proceeds (shares sum to 10,000 bps).
+    /// Empty = everything to the seller.
+    pub payout_splits: Vec<PayoutSplit>,
+    /// Hold seller proceeds until the buyer confirms delivery (physical items)
+    pub escrow_terms: Option<EscrowTerms>,
+    /// Merkle root of wallets allowed to deposit and bid. None = open auction.
+    pub allowlist_root: Option<[u8; 32]>,
+    /// Verified Metaplex collection bidders must hold. None = no gate.
+    pub collection_gate: Option<Pubkey>,
+    /// Refuse wallets with DEFAULT_BAN_THRESHOLD or more forfeits
+    pub ban_defaulters: bool,
+    /// Minimum BidderProfile reputation required to deposit or bid
+    pub min_reputation: Option<u32>,
+    /// Reject bidders whose profile shows more forfeits than this
+    pub max_defaults: Option<u32>,
+    /// Lamports the seller stakes on following through (0 = no bond). Held
+    /// in the vault and returned at close unless slashed.
+    pub seller_bond: u64,
+    /// Seconds a bidder must wait between their own bids (0 = no limit)
+    pub min_bid_interval: u32,
+    /// Reject bids from the wallet already holding the top bid
+    pub block_self_outbid: bool,
+}
+
+pub fn handle_create_auction(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Result<()> {
+    let CreateAuctionParams {
+        reserve_price,
+        reserve_commitment,
+        duration_seconds,
+        extension_seconds,
+        extension_window,
+        min_bid_increment,
+        bid_increment,
+        scheduled_start,
+        anti_snipe_policy,
+        max_extension_seconds,
+        buyer_premium_bps,
+        payout_splits,
+        escrow_terms,
+        allowlist_root,
+        collection_gate,
+        ban_defaulters,
+        min_reputation,
+        max_defaults,
+        seller_bond,
+        min_bid_interval,
+        block_self_outbid,
+    } = params;
+
     require!(reserve_price > 0, OutcryError::InvalidReservePrice);
     require!(
-        duration_seconds >= MIN_AUCTION_DURATION && duration_seconds <= MAX_AUCTION_DURATION,
+        (MIN_AUCTION_DURATION..=MAX_AUCTION_DURATION).contains(&duration_seconds),
         OutcryError::InvalidDuration
     );
     require!(min_bid_increment > 0, OutcryError::InvalidBidIncrement);
+    bid_increment.validate()?;
+    let payout_splits = PayoutSplit::pack(&payout_splits)?;
+    if let Some(terms) = escrow_terms {
+        require!(
+            terms.arbiter != Pubkey::default()
+                && terms.dispute_window_seconds > 0
+                && terms.dispute_window_seconds <= MAX_DISPUTE_WINDOW_SECONDS,
+            OutcryError::InvalidEscrowTerms
+        );
+    }
     require!(
         extension_seconds <= 3600,
         OutcryError::InvalidDuration
@@ -82,19 +178,85 @@ pub fn handle_create_auction(
         extension_window <= 3600,
         OutcryError::InvalidDuration
     );
+    require!(
+        max_extension_seconds <= MAX_EXTENSION_SECONDS,
+        OutcryError::InvalidExtensionCap
+    );
+    require!(
+        min_bid_interval <= MAX_BID_INTERVAL_SECONDS,
+        OutcryError::InvalidBidInterval
+    );
+    require!(
+        buyer_premium_bps <= MAX_BUYER_PREMIUM_BPS,
+        OutcryError::InvalidBuyerPremium
+    );
+    if let AntiSnipePolicy::MaxExtensions { max_extensions } = anti_snipe_policy {
+        require!(max_extensions > 0, OutcryError::InvalidAntiSnipePolicy);
+    }
+    if let Some(start) = scheduled_start {
+        require!(
+            start > Clock::get()?.unix_timestamp,
+            OutcryError::InvalidScheduledStart
+        );
+    }
+
+    // House listings: curator must co-sign, house fee + premium are snapshotted
+    let (house_key, house_fee_bps, buyer_premium_bps) = match &ctx.accounts.auction_house {
+        Some(house) => {
+            if house.curator != Pubkey::default() {
+                let curator = ctx
+                    .accounts
+                    .curator
+                    .as_ref()
+                    .ok_or(OutcryError::CuratorApprovalRequired)?;
+                require_keys_eq!(
+                    curator.key(),
+                    house.curator,
+                    OutcryError::CuratorApprovalRequired
+                );
+            }
+            require!(buyer_premium_bps == 0, OutcryError::InvalidBuyerPremium);
+            (house.key(), house.fee_bps, house.buyer_premium_bps)
+        }
+        None => (Pubkey::default(), 0, buyer_premium_bps),
+    };
 
     let auction_state = &mut ctx.accounts.auction_state;
     auction_state.seller = ctx.accounts.seller.key();
     auction_state.nft_mint = ctx.accounts.nft_mint.key();
+    auction_state.house = house_key;
+    auction_state.house_fee_bps = house_fee_bps;
     auction_state.reserve_price = reserve_price;
+    auction_state.reserve_commitment = reserve_commitment.unwrap_or([0u8; 32]);
+    auction_state.reserve_revealed = false;
     auction_state.duration_seconds = duration_seconds;
     auction_state.current_bid = 0;
     auction_state.highest_bidder = Pubkey::default();
     auction_state.start_time = 0;
     auction_state.end_time = 0;
+    auction_state.scheduled_start = scheduled_start.unwrap_or(0);
     auction_state.extension_seconds = extension_seconds;
     auction_state.extension_window = extension_window;
     auction_state.min_bid_increment = min_bid_increment;
+    auction_state.bid_increment = bid_increment;
+    auction_state.anti_snipe_policy = anti_snipe_policy;
+    auction_state.max_extension_seconds = max_extension_seconds;
+    auction_state.extension_count = 0;
+    auction_state.buyer_premium_bps = buyer_premium_bps;
+    auction_state.sale_event = Pubkey::default();
+    auction_state.lot_index = 0;
+    auction_state.sale_stagger_seconds = 0;
+    auction_state.payout_splits = payout_splits;
+    auction_state.payout_arbiter = escrow_terms.map(|t| t.arbiter).unwrap_or_default();
+    auction_state.dispute_window_seconds = escrow_terms.map(|t| t.dispute_window_seconds).unwrap_or(0);
+    auction_state.allowlist_root = allowlist_root.unwrap_or([0u8; 32]);
+    auction_state.collection_gate = collection_gate.unwrap_or_default();
+    auction_state.ban_defaulters = ban_defaulters;
+    auction_state.min_reputation = min_reputation;
+    auction_state.max_defaults = max_defaults;
+    auction_state.min_bid_interval = min_bid_interval;
+    auction_state.block_self_outbid = block_self_outbid;
+    auction_state.recent_bidders = [RecentBid::default(); RECENT_BIDDER_SLOTS];
     auction_state.status = AuctionStatus::Created;
     auction_state.bid_count = 0;
     auction_state.bump = ctx.bumps.auction_state;
@@ -102,6 +264,23 @@ pub fn handle_create_auction(
     let vault = &mut ctx.accounts.auction_vault;
     vault.auction = auction_state.key();
     vault.bump = ctx.bumps.auction_vault;
+    vault.total_deposited = 0;
+    vault.total_refunded = 0;
+    vault.total_paid_out = 0;
+    vault.seller_bond = seller_bond;
+
+    if seller_bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.seller.to_account_info(),
+                    to: ctx.accounts.auction_vault.to_account_info(),
+                },
+            ),
+            seller_bond,
+        )?;
+    }
 
     // Escrow the NFT
     token::transfer(
@@ -120,8 +299,12 @@ pub fn handle_create_auction(
         auction: auction_state.key(),
         seller: auction_state.seller,
         nft_mint: auction_state.nft_mint,
+        house: house_key,
         reserve_price,
         duration_seconds,
+        scheduled_start: auction_state.scheduled_start,
+        hidden_reserve: auction_state.has_hidden_reserve(),
+        seller_bond,
     });
 
     Ok(())
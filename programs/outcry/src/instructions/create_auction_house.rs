@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::AuctionHouseConfigured,
+    state::AuctionHouse,
+};
+
+/// House configuration — shared by create_auction_house and update_auction_house.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuctionHouseParams {
+    /// House cut of the hammer price (bps)
+    pub fee_bps: u16,
+    /// Receives the house fee and buyer's premium
+    pub fee_recipient: Pubkey,
+    /// If set, every listing must be co-signed by this key
+    pub curator: Option<Pubkey>,
+    /// Premium charged to winners (bps)
+    pub buyer_premium_bps: u16,
+}
+
+impl AuctionHouseParams {
+    pub fn apply(&self, house: &mut AuctionHouse) -> Result<()> {
+        require!(self.fee_bps <= MAX_HOUSE_FEE_BPS, OutcryError::InvalidHouseFee);
+        require!(
+            self.buyer_premium_bps <= MAX_BUYER_PREMIUM_BPS,
+            OutcryError::InvalidBuyerPremium
+        );
+
+        house.fee_bps = self.fee_bps;
+        house.fee_recipient = self.fee_recipient;
+        house.curator = self.curator.unwrap_or_default();
+        house.buyer_premium_bps = self.buyer_premium_bps;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CreateAuctionHouse<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AuctionHouse::INIT_SPACE,
+        seeds = [HOUSE_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_auction_house(
+    ctx: Context<CreateAuctionHouse>,
+    params: AuctionHouseParams,
+) -> Result<()> {
+    let house = &mut ctx.accounts.auction_house;
+    house.authority = ctx.accounts.authority.key();
+    house.bump = ctx.bumps.auction_house;
+    params.apply(house)?;
+
+    emit!(AuctionHouseConfigured {
+        house: house.key(),
+        authority: house.authority,
+        fee_bps: house.fee_bps,
+        fee_recipient: house.fee_recipient,
+        curator: house.curator,
+        buyer_premium_bps: house.buyer_premium_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BID_NONCE_SEED, state::BidNonce};
+
+/// Creates a bidder's replay guard for signed bids. Permissionless — a relayer
+/// can set it up for a user who only ever signs messages off-chain.
+#[derive(Accounts)]
+#[instruction(bidder: Pubkey)]
+pub struct CreateBidNonce<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BidNonce::INIT_SPACE,
+        seeds = [BID_NONCE_SEED, bidder.as_ref()],
+        bump,
+    )]
+    pub bid_nonce: Account<'info, BidNonce>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_bid_nonce(ctx: Context<CreateBidNonce>, bidder: Pubkey) -> Result<()> {
+    let bid_nonce = &mut ctx.accounts.bid_nonce;
+    bid_nonce.bidder = bidder;
+    bid_nonce.last_nonce = 0;
+    bid_nonce.bump = ctx.bumps.bid_nonce;
+
+    Ok(())
+}
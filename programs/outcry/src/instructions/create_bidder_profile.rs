@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, state::BidderProfile};
+
+/// Opens a wallet's BidderProfile so settlements start building its
+/// reputation and refunds its refunded-auction count. Defaults are recorded whether or not a profile exists —
+/// forfeit_auction creates one.
+#[derive(Accounts)]
+pub struct CreateBidderProfile<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + BidderProfile::INIT_SPACE,
+        seeds = [BIDDER_PROFILE_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_profile: Account<'info, BidderProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_bidder_profile(ctx: Context<CreateBidderProfile>) -> Result<()> {
+    let bidder_key = ctx.accounts.bidder.key();
+    ctx.accounts
+        .bidder_profile
+        .init(bidder_key, ctx.bumps.bidder_profile);
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_SESSION_DURATION, MAX_SESSION_TARGETS, SESSION_SEED},
+    errors::OutcryError,
+    events::SessionCreated,
+    state::GlobalSession,
+};
+
+#[derive(Accounts)]
+pub struct CreateGlobalSession<'info> {
+    /// The real wallet authorizing this session.
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// Wallet-wide session token. init_if_needed: same reasoning as
+    /// create_session — the PDA is unique per bidder and only the bidder can
+    /// re-authorize it. Fails while delegated (owner is the delegation program).
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + GlobalSession::INIT_SPACE,
+        seeds = [SESSION_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub global_session: Account<'info, GlobalSession>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Terms of a wallet-wide session.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct GlobalSessionParams {
+    pub session_signer: Pubkey,
+    pub expires_at: i64,
+    /// Largest single bid (lamports)
+    pub max_bid_amount: u64,
+    /// Lifetime cap on bid volume across auctions (lamports)
+    pub volume_cap: u64,
+    /// Auctions or houses the session may bid in; empty = any
+    pub allowed_targets: Vec<Pubkey>,
+}
+
+pub fn handle_create_global_session(
+    ctx: Context<CreateGlobalSession>,
+    params: GlobalSessionParams,
+) -> Result<()> {
+    let GlobalSessionParams {
+        session_signer,
+        expires_at,
+        max_bid_amount,
+        volume_cap,
+        allowed_targets,
+    } = params;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at > now && expires_at - now <= MAX_SESSION_DURATION,
+        OutcryError::InvalidSessionExpiry
+    );
+    require!(max_bid_amount > 0 && volume_cap > 0, OutcryError::InvalidSessionCap);
+    require!(
+        allowed_targets.len() <= MAX_SESSION_TARGETS,
+        OutcryError::InvalidSessionTargets
+    );
+
+    let session = &mut ctx.accounts.global_session;
+
+    session.bidder = ctx.accounts.bidder.key();
+    session.session_signer = session_signer;
+    session.created_at = now;
+    session.expires_at = expires_at;
+    session.max_bid_amount = max_bid_amount;
+    session.volume_remaining = volume_cap;
+    session.allowed_targets = allowed_targets;
+    session.revoked = false;
+    session.bump = ctx.bumps.global_session;
+
+    emit!(SessionCreated {
+        auction: Pubkey::default(),
+        bidder: session.bidder,
+        session_signer,
+        expires_at,
+        max_bid_amount,
+    });
+
+    Ok(())
+}
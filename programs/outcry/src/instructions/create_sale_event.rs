@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::SaleEventCreated,
+    state::SaleEvent,
+};
+
+#[derive(Accounts)]
+#[instruction(sale_id: u64)]
+pub struct CreateSaleEvent<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + SaleEvent::INIT_SPACE,
+        seeds = [SALE_SEED, organizer.key().as_ref(), &sale_id.to_le_bytes()],
+        bump,
+    )]
+    pub sale_event: Account<'info, SaleEvent>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_sale_event(
+    ctx: Context<CreateSaleEvent>,
+    sale_id: u64,
+    start_time: i64,
+    stagger_seconds: u32,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        start_time > clock.unix_timestamp,
+        OutcryError::InvalidScheduledStart
+    );
+    require!(
+        stagger_seconds <= MAX_SALE_STAGGER_SECONDS,
+        OutcryError::InvalidSaleStagger
+    );
+
+    let sale = &mut ctx.accounts.sale_event;
+    sale.organizer = ctx.accounts.organizer.key();
+    sale.sale_id = sale_id;
+    sale.start_time = start_time;
+    sale.stagger_seconds = stagger_seconds;
+    sale.lots = Vec::new();
+    sale.bump = ctx.bumps.sale_event;
+
+    emit!(SaleEventCreated {
+        sale: sale.key(),
+        organizer: sale.organizer,
+        start_time,
+        stagger_seconds,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, state::SellerProfile};
+
+/// Opens a wallet's SellerProfile so settled sales start building its
+/// history. Cancellations are recorded whether or not a profile exists —
+/// cancel_auction creates one.
+#[derive(Accounts)]
+pub struct CreateSellerProfile<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + SellerProfile::INIT_SPACE,
+        seeds = [SELLER_PROFILE_SEED, seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_profile: Account<'info, SellerProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_seller_profile(ctx: Context<CreateSellerProfile>) -> Result<()> {
+    let seller_key = ctx.accounts.seller.key();
+    ctx.accounts
+        .seller_profile
+        .init(seller_key, ctx.bumps.seller_profile);
+    Ok(())
+}
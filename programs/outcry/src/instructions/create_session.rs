@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::SESSION_SEED,
-    state::SessionToken,
+    constants::{MAX_SESSION_DURATION, SESSION_SEED},
+    errors::OutcryError,
+    events::SessionCreated,
+    metadata::verify_collection_holder,
+    state::{AuctionState, GateClaim, SessionToken},
 };
 
 #[derive(Accounts)]
@@ -20,8 +23,9 @@ pub struct CreateSession<'info> {
 
     /// Session token PDA linking ephemeral key → real wallet for this auction.
     /// init_if_needed: safe because PDA is unique per (bidder, auction), only
-    /// the bidder can create it, and reinit just updates session_signer (needed
-    /// after page refresh when a new ephemeral key is generated).
+    /// the bidder can create it, and reinit just updates session_signer, expiry
+    /// and cap (needed after page refresh when a new ephemeral key is
+    /// generated, or to re-authorize after revoke_session).
     #[account(
         init_if_needed,
         payer = bidder,
@@ -31,17 +35,102 @@ pub struct CreateSession<'info> {
     )]
     pub session_token: Account<'info, SessionToken>,
 
+    /// Token account holding an asset from the gate collection — only needed
+    /// the first time a bidder proves holdership on a collection-gated auction;
+    /// re-creating the session keeps the marker.
+    /// CHECK: Parsed and checked in metadata::verify_collection_holder
+    pub gate_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metaplex metadata of the gate asset — PDA and owner checked in
+    /// metadata::verify_collection_holder
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Claim on the gate token for this auction, created on first proof so
+    /// each token qualifies only one bidder per auction.
+    /// CHECK: PDA derived from the proven mint and checked in GateClaim::claim
+    #[account(mut)]
+    pub gate_claim: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_create_session(ctx: Context<CreateSession>, session_signer: Pubkey) -> Result<()> {
+pub fn handle_create_session(
+    ctx: Context<CreateSession>,
+    session_signer: Pubkey,
+    expires_at: i64,
+    max_bid_amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at > now && expires_at - now <= MAX_SESSION_DURATION,
+        OutcryError::InvalidSessionExpiry
+    );
+    require!(max_bid_amount > 0, OutcryError::InvalidSessionCap);
+
+    // On allowlisted auctions only listed wallets may authorize a session.
+    // On collection-gated ones, a holdership proof here marks the session
+    // eligible (the bidder's deposit can carry the marker instead).
+    // Readable on L1 whether or not the auction is delegated. Like a
+    // deposit, a re-created session keeps a marker proven earlier.
+    let mut verified_collection = ctx.accounts.session_token.verified_collection;
+    {
+        let data = ctx.accounts.auction_state.try_borrow_data()?;
+        let mut slice: &[u8] = &data;
+        if let Ok(auction) = AuctionState::try_deserialize(&mut slice) {
+            require!(
+                !auction.has_allowlist()
+                    || auction.verify_allowlist_proof(&ctx.accounts.bidder.key(), &proof),
+                OutcryError::InvalidAllowlistProof
+            );
+            if let (true, Some(token_account), Some(metadata)) = (
+                auction.has_collection_gate() && verified_collection != auction.collection_gate,
+                &ctx.accounts.gate_token_account,
+                &ctx.accounts.gate_metadata,
+            ) {
+                let gate_claim = ctx
+                    .accounts
+                    .gate_claim
+                    .as_ref()
+                    .ok_or(OutcryError::InvalidGateClaim)?;
+                let gate_mint = verify_collection_holder(
+                    &ctx.accounts.bidder.key(),
+                    &auction.collection_gate,
+                    token_account,
+                    metadata,
+                )?;
+                GateClaim::claim(
+                    gate_claim,
+                    ctx.accounts.auction_state.key(),
+                    gate_mint,
+                    ctx.accounts.bidder.key(),
+                    &ctx.accounts.bidder,
+                    &ctx.accounts.system_program,
+                )?;
+                verified_collection = auction.collection_gate;
+            }
+        }
+    }
+
     let session = &mut ctx.accounts.session_token;
 
     session.auction = ctx.accounts.auction_state.key();
     session.bidder = ctx.accounts.bidder.key();
     session.session_signer = session_signer;
-    session.created_at = Clock::get()?.unix_timestamp;
+    session.created_at = now;
+    session.expires_at = expires_at;
+    session.max_bid_amount = max_bid_amount;
+    session.revoked = false;
+    session.verified_collection = verified_collection;
     session.bump = ctx.bumps.session_token;
 
+    emit!(SessionCreated {
+        auction: session.auction,
+        bidder: session.bidder,
+        session_signer,
+        expires_at,
+        max_bid_amount,
+    });
+
     Ok(())
 }
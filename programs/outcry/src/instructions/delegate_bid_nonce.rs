@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+
+use crate::constants::BID_NONCE_SEED;
+
+/// Delegates a BidNonce to the Ephemeral Rollup so place_signed_bid can
+/// advance it on delegated auctions. Use the same ER validator as the
+/// auctions. The bidder signs; a relayer may pay. Sends to base layer (L1).
+#[delegate]
+#[derive(Accounts)]
+pub struct DelegateBidNonce<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub bidder: Signer<'info>,
+
+    /// CHECK: The BidNonce PDA to delegate. Validated by seeds.
+    #[account(
+        mut,
+        del,
+        seeds = [BID_NONCE_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub bid_nonce: AccountInfo<'info>,
+}
+
+pub fn handle_delegate_bid_nonce(ctx: Context<DelegateBidNonce>) -> Result<()> {
+    ctx.accounts.delegate_bid_nonce(
+        &ctx.accounts.payer,
+        &[BID_NONCE_SEED, ctx.accounts.bidder.key.as_ref()],
+        DelegateConfig::default(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+
+use crate::constants::BIDDER_ACCOUNT_SEED;
+
+/// Delegates the BidderAccount to the Ephemeral Rollup so bids on delegated
+/// auctions can lock its funds and release them when the owner is outbid.
+/// Use the same ER validator as the auctions. Sends to base layer (L1).
+#[delegate]
+#[derive(Accounts)]
+pub struct DelegateBidderAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: The BidderAccount PDA to delegate. Validated by seeds.
+    #[account(
+        mut,
+        del,
+        seeds = [BIDDER_ACCOUNT_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub bidder_account: AccountInfo<'info>,
+}
+
+pub fn handle_delegate_bidder_account(ctx: Context<DelegateBidderAccount>) -> Result<()> {
+    ctx.accounts.delegate_bidder_account(
+        &ctx.accounts.owner,
+        &[BIDDER_ACCOUNT_SEED, ctx.accounts.owner.key.as_ref()],
+        DelegateConfig::default(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+
+use crate::constants::SESSION_SEED;
+
+/// Delegates the GlobalSession PDA to the Ephemeral Rollup so bids on
+/// delegated auctions can draw down its shared bid-volume cap. Use the same ER
+/// validator as the auctions. Sends to base layer (L1).
+///
+/// The ER takes a write lock on the session for every bid that draws from
+/// it, so bids across several auctions are serialized against the cap.
+#[delegate]
+#[derive(Accounts)]
+pub struct DelegateGlobalSession<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// CHECK: The GlobalSession PDA to delegate. Validated by seeds.
+    #[account(
+        mut,
+        del,
+        seeds = [SESSION_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub global_session: AccountInfo<'info>,
+}
+
+pub fn handle_delegate_global_session(ctx: Context<DelegateGlobalSession>) -> Result<()> {
+    ctx.accounts.delegate_global_session(
+        &ctx.accounts.bidder,
+        &[SESSION_SEED, ctx.accounts.bidder.key.as_ref()],
+        DelegateConfig::default(),
+    )?;
+
+    Ok(())
+}
@@ -5,7 +5,10 @@ use crate::{
     constants::*,
     errors::OutcryError,
     events::DepositMade,
-    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit},
+    metadata::verify_collection_holder,
+    state::{
+        check_bidder_standing, AuctionState, AuctionStatus, AuctionVault, BidderDeposit, GateClaim,
+    },
 };
 
 #[derive(Accounts)]
@@ -37,29 +40,122 @@ pub struct Deposit<'info> {
     )]
     pub auction_vault: Account<'info, AuctionVault>,
 
+    /// Seller's blocklist — may not exist.
+    /// CHECK: The seller is only known once auction_state is read, so the
+    /// PDA is checked in the handler; read in check_bidder_standing
+    pub blocklist: UncheckedAccount<'info>,
+
+    /// Bidder's default history — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [DEFAULT_RECORD_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub default_record: UncheckedAccount<'info>,
+
+    /// Bidder's profile — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [BIDDER_PROFILE_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_profile: UncheckedAccount<'info>,
+
+    /// Token account holding an asset from the gate collection — only needed
+    /// the first time a bidder proves holdership on a collection-gated auction.
+    /// CHECK: Parsed and checked in metadata::verify_collection_holder
+    pub gate_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metaplex metadata of the gate asset — PDA and owner checked in
+    /// metadata::verify_collection_holder
+    pub gate_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Claim on the gate token for this auction, created on first proof so
+    /// each token qualifies only one bidder per auction.
+    /// CHECK: PDA derived from the proven mint and checked in GateClaim::claim
+    #[account(mut)]
+    pub gate_claim: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+pub fn handle_deposit(ctx: Context<Deposit>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
     require!(amount > 0, OutcryError::InvalidDepositAmount);
 
     // Reject deposits after auction has ended/settled/cancelled.
     // When auction is delegated to ER, its owner changes to the delegation program
     // and we can't deserialize — but that means it's Active, so deposits are valid.
+    // The raw data is still readable while delegated, so the allowlist root is
+    // taken from it either way.
     let auction_info = &ctx.accounts.auction_state;
-    if auction_info.owner == &crate::ID {
+    let mut allowlist_root = [0u8; 32];
+    let mut collection_gate = Pubkey::default();
+    {
         let data = auction_info.try_borrow_data()?;
-        if data.len() >= 8 + AuctionState::SPACE - 8 {
-            let mut slice: &[u8] = &data;
-            if let Ok(auction) = AuctionState::try_deserialize(&mut slice) {
+        let mut slice: &[u8] = &data;
+        if let Ok(auction) = AuctionState::try_deserialize(&mut slice) {
+            if auction_info.owner == &crate::ID {
                 require!(
                     auction.status == AuctionStatus::Created
                         || auction.status == AuctionStatus::Active,
                     OutcryError::InvalidAuctionStatus
                 );
             }
+
+            // Blocked or banned wallets can't even open a deposit
+            let (blocklist_key, _) = Pubkey::find_program_address(
+                &[BLOCKLIST_SEED, auction.seller.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                ctx.accounts.blocklist.key(),
+                blocklist_key,
+                OutcryError::InvalidBlockList
+            );
+            check_bidder_standing(
+                &auction,
+                &ctx.accounts.blocklist,
+                &ctx.accounts.default_record,
+                &ctx.accounts.bidder_profile,
+                &ctx.accounts.bidder.key(),
+            )?;
+
+            if auction.has_allowlist()
+                && ctx.accounts.bidder_deposit.verified_root != auction.allowlist_root
+            {
+                require!(
+                    auction.verify_allowlist_proof(&ctx.accounts.bidder.key(), &proof),
+                    OutcryError::InvalidAllowlistProof
+                );
+            }
+            if auction.has_collection_gate()
+                && ctx.accounts.bidder_deposit.verified_collection != auction.collection_gate
+            {
+                let (Some(token_account), Some(metadata), Some(gate_claim)) = (
+                    &ctx.accounts.gate_token_account,
+                    &ctx.accounts.gate_metadata,
+                    &ctx.accounts.gate_claim,
+                ) else {
+                    return err!(OutcryError::NotCollectionHolder);
+                };
+                let gate_mint = verify_collection_holder(
+                    &ctx.accounts.bidder.key(),
+                    &auction.collection_gate,
+                    token_account,
+                    metadata,
+                )?;
+                GateClaim::claim(
+                    gate_claim,
+                    auction_info.key(),
+                    gate_mint,
+                    ctx.accounts.bidder.key(),
+                    &ctx.accounts.bidder,
+                    &ctx.accounts.system_program,
+                )?;
+            }
+            allowlist_root = auction.allowlist_root;
+            collection_gate = auction.collection_gate;
         }
-        drop(data);
     }
 
     let deposit = &mut ctx.accounts.bidder_deposit;
@@ -73,6 +169,10 @@ pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         deposit.bump = ctx.bumps.bidder_deposit;
     }
 
+    // Allowlist and collection-gate markers read by the bid paths (including on the ER)
+    deposit.verified_root = allowlist_root;
+    deposit.verified_collection = collection_gate;
+
     // Update deposit amount
     deposit.amount = deposit
         .amount
@@ -91,6 +191,10 @@ pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         amount,
     )?;
 
+    let vault = &mut ctx.accounts.auction_vault;
+    vault.record_deposit(amount)?;
+    vault.assert_solvent(&vault.to_account_info())?;
+
     emit!(DepositMade {
         auction: auction_key,
         bidder: bidder_key,
@@ -75,6 +75,10 @@ pub fn handle_emergency_refund(ctx: Context<EmergencyRefund>) -> Result<()> {
     **vault_info.try_borrow_mut_lamports()? -= refund_amount;
     **bidder_info.try_borrow_mut_lamports()? += refund_amount;
 
+    let vault = &mut ctx.accounts.auction_vault;
+    vault.record_refund(refund_amount)?;
+    vault.assert_solvent(&vault_info)?;
+
     emit!(RefundClaimed {
         auction: auction_key,
         bidder: bidder_key,
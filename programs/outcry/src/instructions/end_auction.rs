@@ -14,6 +14,8 @@ pub struct EndAuction<'info> {
     #[account(
         mut,
         constraint = auction_state.status == AuctionStatus::Active @ OutcryError::InvalidAuctionStatus,
+        // Later sale lots close through advance_sale_lot so they can't jump the queue
+        constraint = auction_state.lot_index == 0 @ OutcryError::SaleLotOutOfOrder,
     )]
     pub auction_state: Account<'info, AuctionState>,
 }
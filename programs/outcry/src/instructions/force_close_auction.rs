@@ -4,8 +4,8 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use crate::{
     constants::*,
     errors::OutcryError,
-    events::AuctionForceClosed,
-    state::{AuctionState, AuctionStatus, AuctionVault},
+    events::{AuctionForceClosed, SellerBondReturned},
+    state::{AuctionState, AuctionStatus, AuctionVault, UnclaimedFunds},
 };
 
 #[derive(Accounts)]
@@ -18,6 +18,7 @@ pub struct ForceCloseAuction<'info> {
         has_one = seller @ OutcryError::UnauthorizedSeller,
         constraint = auction_state.status == AuctionStatus::Settled
             || auction_state.status == AuctionStatus::Cancelled
+            || auction_state.status == AuctionStatus::ReserveNotMet
             @ OutcryError::InvalidAuctionStatus,
         seeds = [AUCTION_SEED, seller.key().as_ref(), nft_mint.key().as_ref()],
         bump = auction_state.bump,
@@ -42,6 +43,18 @@ pub struct ForceCloseAuction<'info> {
     )]
     pub escrow_nft_token_account: Account<'info, TokenAccount>,
 
+    /// Receives unclaimed deposits so bidders can still redeem them.
+    /// Required when the vault holds more than rent and the seller bond,
+    /// refused otherwise.
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + UnclaimedFunds::INIT_SPACE,
+        seeds = [UNCLAIMED_SEED, auction_state.key().as_ref()],
+        bump,
+    )]
+    pub unclaimed_funds: Option<Account<'info, UnclaimedFunds>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -53,7 +66,7 @@ pub fn handle_force_close_auction(ctx: Context<ForceCloseAuction>) -> Result<()>
     // Grace period check — give bidders time to claim refunds before
     // the seller can sweep unclaimed deposits.
     let grace_deadline = match auction.status {
-        AuctionStatus::Settled => {
+        AuctionStatus::Settled | AuctionStatus::ReserveNotMet => {
             // After settlement, grace = end_time + 7 days
             auction.end_time.checked_add(FORCE_CLOSE_GRACE_PERIOD)
                 .ok_or(OutcryError::ArithmeticOverflow)?
@@ -82,19 +95,42 @@ pub fn handle_force_close_auction(ctx: Context<ForceCloseAuction>) -> Result<()>
         OutcryError::EscrowNotEmpty
     );
 
-    // Drain remaining vault lamports (unclaimed deposits) to seller.
-    // Unlike close_auction, we skip the "vault must be empty" check.
+    // Move unclaimed deposits into UnclaimedFunds, where bidders can still
+    // redeem them with claim_unclaimed_refund. The amount comes from the
+    // ledger, not the vault balance, so `remaining` is exactly what bidders
+    // can claim. Unlike close_auction, we skip the "vault must be empty"
+    // check. The seller bond and any stray lamports stay behind and return
+    // with the vault rent.
     let vault_info = ctx.accounts.auction_vault.to_account_info();
-    let vault_lamports = vault_info.lamports();
-    let rent = Rent::get()?;
-    let vault_rent = rent.minimum_balance(vault_info.data_len());
+    ctx.accounts.auction_vault.assert_solvent(&vault_info)?;
+    let drained = ctx.accounts.auction_vault.liabilities()?;
+    let seller_bond = ctx.accounts.auction_vault.seller_bond;
+
+    if drained > 0 {
+        let auction_key = ctx.accounts.auction_state.key();
+        let unclaimed = ctx
+            .accounts
+            .unclaimed_funds
+            .as_mut()
+            .ok_or(OutcryError::UnclaimedFundsRequired)?;
+        unclaimed.auction = auction_key;
+        unclaimed.seller = ctx.accounts.seller.key();
+        unclaimed.remaining = drained;
+        unclaimed.bump = ctx.bumps.unclaimed_funds.ok_or(OutcryError::UnclaimedFundsRequired)?;
 
-    let mut drained: u64 = 0;
-    if vault_lamports > vault_rent {
-        drained = vault_lamports - vault_rent;
         // Transfer via direct lamport manipulation (vault is a PDA we own)
         **vault_info.try_borrow_mut_lamports()? -= drained;
-        **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += drained;
+        **unclaimed.to_account_info().try_borrow_mut_lamports()? += drained;
+
+        // Handed over to UnclaimedFunds for the bidders
+        ctx.accounts.auction_vault.record_refund(drained)?;
+    } else {
+        // Nothing to hand over. An empty UnclaimedFunds has no claim left to
+        // close it, so it would strand its rent and block relisting the mint
+        require!(
+            ctx.accounts.unclaimed_funds.is_none(),
+            OutcryError::UnclaimedFundsNotNeeded
+        );
     }
 
     // Close escrow token account via PDA-signed CPI — must happen before
@@ -124,6 +160,13 @@ pub fn handle_force_close_auction(ctx: Context<ForceCloseAuction>) -> Result<()>
         seller: ctx.accounts.seller.key(),
         drained_lamports: drained,
     });
+    if seller_bond > 0 {
+        emit!(SellerBondReturned {
+            auction: ctx.accounts.auction_state.key(),
+            seller: ctx.accounts.seller.key(),
+            amount: seller_bond,
+        });
+    }
 
     // AuctionState and AuctionVault are closed by Anchor's `close` constraint.
 
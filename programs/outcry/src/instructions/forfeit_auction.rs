@@ -7,13 +7,16 @@ use anchor_spl::{
 use crate::{
     constants::*,
     errors::OutcryError,
-    events::AuctionSettled,
-    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit},
+    events::{AuctionSettled, WinnerDefaulted},
+    state::{
+        AuctionState, AuctionStatus, AuctionVault, BidderAccount, BidderDeposit, BidderProfile,
+        DefaultRecord,
+    },
 };
 
 /// Handles the case where the winning bidder didn't deposit enough to cover
 /// their bid (griefing/default). Returns NFT to seller, forfeits whatever
-/// deposit the winner had as a penalty, and sets status to Settled so
+/// deposit and locked funds the winner had as a penalty, and sets status to Settled so
 /// other bidders can claim refunds.
 #[derive(Accounts)]
 pub struct ForfeitAuction<'info> {
@@ -44,6 +47,41 @@ pub struct ForfeitAuction<'info> {
     )]
     pub winner_deposit: UncheckedAccount<'info>,
 
+    /// CHECK: Winner's BidderAccount PDA — may not exist if winner never
+    /// funded one. Required so a defaulting winner's lock is always slashed;
+    /// refused while delegated so the lock can't hide on the ER.
+    /// Deserialized manually in handler.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, auction_state.highest_bidder.as_ref()],
+        bump,
+    )]
+    pub winner_bidder_account: UncheckedAccount<'info>,
+
+    /// Winner's protocol-wide default history — incremented here, read by
+    /// auctions with ban_defaulters or max_defaults. Rent paid by the cranker
+    /// on a first default.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + DefaultRecord::INIT_SPACE,
+        seeds = [DEFAULT_RECORD_SEED, auction_state.highest_bidder.as_ref()],
+        bump,
+    )]
+    pub winner_default_record: Account<'info, DefaultRecord>,
+
+    /// Winner's BidderProfile — mirrors the DefaultRecord count into its
+    /// score, created if needed so defaulters can't dodge their reputation
+    /// hit. Rent paid by the cranker on a first default.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + BidderProfile::INIT_SPACE,
+        seeds = [BIDDER_PROFILE_SEED, auction_state.highest_bidder.as_ref()],
+        bump,
+    )]
+    pub winner_profile: Account<'info, BidderProfile>,
+
     /// CHECK: Validated against auction_state.seller
     #[account(
         mut,
@@ -78,7 +116,7 @@ pub struct ForfeitAuction<'info> {
 }
 
 pub fn handle_forfeit_auction(ctx: Context<ForfeitAuction>) -> Result<()> {
-    let winning_bid = ctx.accounts.auction_state.current_bid;
+    let amount_due = ctx.accounts.auction_state.amount_due()?;
     let highest_bidder = ctx.accounts.auction_state.highest_bidder;
     let seller_key = ctx.accounts.auction_state.seller;
     let nft_mint_key = ctx.accounts.nft_mint.key();
@@ -96,17 +134,41 @@ pub fn handle_forfeit_auction(ctx: Context<ForfeitAuction>) -> Result<()> {
         0
     };
 
-    // This instruction is only valid when winner CAN'T cover their bid
+    // Read winner's locked funds (0 if they never funded a BidderAccount)
+    let auction_key = ctx.accounts.auction_state.key();
+    let account_info = ctx.accounts.winner_bidder_account.to_account_info();
+    let winner_lock_amount = if account_info.owner == &crate::ID {
+        let data = account_info.try_borrow_data()?;
+        let bidder_account = BidderAccount::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(OutcryError::InvalidBidderAccount))?;
+        drop(data);
+        bidder_account.lock_amount(&auction_key)
+    } else {
+        require!(
+            account_info.data_is_empty(),
+            OutcryError::BidderAccountDelegated
+        );
+        0
+    };
+
+    // Don't slash a winner whose bid may still fall below a hidden reserve
+    require!(
+        ctx.accounts
+            .auction_state
+            .reserve_cleared(Clock::get()?.unix_timestamp)?,
+        OutcryError::ReserveNotRevealed
+    );
+
+    // This instruction is only valid when winner CAN'T cover their bid + premium
     require!(
-        winner_deposit_amount < winning_bid,
+        (winner_deposit_amount as u128) + (winner_lock_amount as u128) < amount_due as u128,
         OutcryError::ForfeitNotNeeded
     );
 
     // --- Verify vault has sufficient lamports for the penalty transfer ---
     if winner_deposit_amount > 0 {
-        let vault_lamports = ctx.accounts.auction_vault.to_account_info().lamports();
-        let vault_rent = Rent::get()?.minimum_balance(ctx.accounts.auction_vault.to_account_info().data_len());
-        let available = vault_lamports.saturating_sub(vault_rent);
+        let vault = &ctx.accounts.auction_vault;
+        let available = vault.spendable(&vault.to_account_info())?;
         require!(
             available >= winner_deposit_amount,
             OutcryError::InsufficientVaultBalance
@@ -121,6 +183,10 @@ pub fn handle_forfeit_auction(ctx: Context<ForfeitAuction>) -> Result<()> {
         **vault_info.try_borrow_mut_lamports()? -= winner_deposit_amount;
         **seller_info.try_borrow_mut_lamports()? += winner_deposit_amount;
 
+        let vault = &mut ctx.accounts.auction_vault;
+        vault.record_payout(winner_deposit_amount)?;
+        vault.assert_solvent(&vault_info)?;
+
         // Zero out the deposit so winner can't also claim a refund.
         // Use proper Anchor serialization to avoid fragile hardcoded offsets.
         let mut data = ctx.accounts.winner_deposit.try_borrow_mut_data()?;
@@ -133,6 +199,22 @@ pub fn handle_forfeit_auction(ctx: Context<ForfeitAuction>) -> Result<()> {
         drop(data);
     }
 
+    // Forfeit winner's locked funds to seller as well, straight from their
+    // BidderAccount, and free the lock's slot.
+    if winner_lock_amount > 0 {
+        let seller_info = ctx.accounts.seller.to_account_info();
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        let mut bidder_account = BidderAccount::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(OutcryError::InvalidBidderAccount))?;
+        bidder_account.debit_lock(&auction_key, winner_lock_amount)?;
+        bidder_account.try_serialize(&mut &mut data[..])?;
+        drop(data);
+
+        **account_info.try_borrow_mut_lamports()? -= winner_lock_amount;
+        **seller_info.try_borrow_mut_lamports()? += winner_lock_amount;
+    }
+
     // Return NFT from escrow to seller
     let signer_seeds: &[&[&[u8]]] = &[&[
         AUCTION_SEED,
@@ -154,6 +236,31 @@ pub fn handle_forfeit_auction(ctx: Context<ForfeitAuction>) -> Result<()> {
         1,
     )?;
 
+    // Record the default against the winner's wallet
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.winner_default_record;
+    if record.bidder == Pubkey::default() {
+        record.bidder = highest_bidder;
+        record.bump = ctx.bumps.winner_default_record;
+    }
+    record.defaults = record
+        .defaults
+        .checked_add(1)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+    record.last_default_at = now;
+
+    let profile = &mut ctx.accounts.winner_profile;
+    if profile.bidder == Pubkey::default() {
+        profile.init(highest_bidder, ctx.bumps.winner_profile);
+    }
+    profile.record_default(record, winner_deposit_amount + winner_lock_amount)?;
+
+    emit!(WinnerDefaulted {
+        auction: ctx.accounts.auction_state.key(),
+        bidder: highest_bidder,
+        defaults: record.defaults,
+    });
+
     // Mark as Settled after all transfers succeed.
     // Double-forfeit prevented by constraint `status == AuctionStatus::Ended`.
     ctx.accounts.auction_state.status = AuctionStatus::Settled;
@@ -162,9 +269,11 @@ pub fn handle_forfeit_auction(ctx: Context<ForfeitAuction>) -> Result<()> {
         auction: ctx.accounts.auction_state.key(),
         winner: highest_bidder,
         final_price: 0, // No sale — winner defaulted
-        seller_received: winner_deposit_amount, // Forfeited deposit as penalty
+        seller_received: winner_deposit_amount + winner_lock_amount, // Forfeited funds as penalty
         royalties_paid: 0,
         protocol_fee: 0,
+        house_fee: 0,
+        buyer_premium: 0,
     });
 
     Ok(())
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::BidderAccountFunded,
+    state::BidderAccount,
+};
+
+/// Tops up the wallet's cross-auction balance, creating it on first use.
+#[derive(Accounts)]
+pub struct FundBidderAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + BidderAccount::INIT_SPACE,
+        seeds = [BIDDER_ACCOUNT_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub bidder_account: Account<'info, BidderAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_fund_bidder_account(ctx: Context<FundBidderAccount>, amount: u64) -> Result<()> {
+    require!(amount > 0, OutcryError::InvalidDepositAmount);
+
+    let owner_key = ctx.accounts.owner.key();
+    let account = &mut ctx.accounts.bidder_account;
+
+    // Initialize fields if this is a new bidder account
+    if account.owner == Pubkey::default() {
+        account.owner = owner_key;
+        account.bump = ctx.bumps.bidder_account;
+    }
+
+    account.balance = account
+        .balance
+        .checked_add(amount)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.bidder_account.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(BidderAccountFunded {
+        owner: owner_key,
+        amount,
+        balance: ctx.accounts.bidder_account.balance,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AuctionState;
+
+/// Read-only helper for clients: simulate to get the lowest amount the next
+/// bid may be under the auction's reserve and increment rule.
+#[derive(Accounts)]
+pub struct GetMinNextBid<'info> {
+    pub auction_state: Account<'info, AuctionState>,
+}
+
+pub fn handle_get_min_next_bid(ctx: Context<GetMinNextBid>) -> Result<u64> {
+    ctx.accounts.auction_state.min_next_bid()
+}
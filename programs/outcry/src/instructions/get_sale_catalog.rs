@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::OutcryError,
+    state::{AuctionState, SaleEvent, SaleLotSummary},
+};
+
+/// Read-only helper for clients: simulate with the sale's lots passed as
+/// remaining_accounts (in catalog order) to get every lot's live state.
+/// Lots are read straight from account data so this works whether or not
+/// they are currently delegated.
+#[derive(Accounts)]
+pub struct GetSaleCatalog<'info> {
+    pub sale_event: Account<'info, SaleEvent>,
+}
+
+pub fn handle_get_sale_catalog(ctx: Context<GetSaleCatalog>) -> Result<Vec<SaleLotSummary>> {
+    let sale = &ctx.accounts.sale_event;
+    require!(
+        ctx.remaining_accounts.len() == sale.lots.len(),
+        OutcryError::InvalidSaleLot
+    );
+
+    let mut catalog = Vec::with_capacity(sale.lots.len());
+    for (lot_key, lot_info) in sale.lots.iter().zip(ctx.remaining_accounts.iter()) {
+        require_keys_eq!(lot_info.key(), *lot_key, OutcryError::InvalidSaleLot);
+
+        let data = lot_info.try_borrow_data()?;
+        let lot = AuctionState::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(OutcryError::InvalidSaleLot))?;
+
+        catalog.push(SaleLotSummary {
+            auction: *lot_key,
+            lot_index: lot.lot_index,
+            nft_mint: lot.nft_mint,
+            status: lot.status,
+            current_bid: lot.current_bid,
+            highest_bidder: lot.highest_bidder,
+            end_time: lot.end_time,
+        });
+    }
+
+    Ok(catalog)
+}
@@ -15,6 +15,46 @@ pub mod force_close_auction;
 pub mod create_session;
 pub mod place_bid_session;
 pub mod emergency_refund;
+pub mod start_scheduled_auction;
+pub mod get_min_next_bid;
+pub mod reveal_reserve;
+pub mod create_auction_house;
+pub mod update_auction_house;
+pub mod create_sale_event;
+pub mod add_sale_lot;
+pub mod advance_sale_lot;
+pub mod get_sale_catalog;
+pub mod withdraw_creator_earnings;
+pub mod confirm_receipt;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod release_proceeds;
+pub mod claim_unclaimed_refund;
+pub mod claim_refunds_batch;
+pub mod check_solvency;
+pub mod withdraw_deposit;
+pub mod fund_bidder_account;
+pub mod withdraw_bidder_account;
+pub mod close_bidder_account;
+pub mod delegate_bidder_account;
+pub mod undelegate_bidder_account;
+pub mod unlock_bid_funds;
+pub mod revoke_session;
+pub mod close_session;
+pub mod create_global_session;
+pub mod delegate_global_session;
+pub mod undelegate_global_session;
+pub mod revoke_global_session;
+pub mod close_global_session;
+pub mod create_bid_nonce;
+pub mod delegate_bid_nonce;
+pub mod undelegate_bid_nonce;
+pub mod place_signed_bid;
+pub mod update_allowlist_root;
+pub mod block_bidder;
+pub mod unblock_bidder;
+pub mod create_bidder_profile;
+pub mod create_seller_profile;
 
 #[allow(ambiguous_glob_reexports)]
 pub use create_auction::*;
@@ -50,3 +90,83 @@ pub use create_session::*;
 pub use place_bid_session::*;
 #[allow(ambiguous_glob_reexports)]
 pub use emergency_refund::*;
+#[allow(ambiguous_glob_reexports)]
+pub use start_scheduled_auction::*;
+#[allow(ambiguous_glob_reexports)]
+pub use get_min_next_bid::*;
+#[allow(ambiguous_glob_reexports)]
+pub use reveal_reserve::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_auction_house::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_auction_house::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_sale_event::*;
+#[allow(ambiguous_glob_reexports)]
+pub use add_sale_lot::*;
+#[allow(ambiguous_glob_reexports)]
+pub use advance_sale_lot::*;
+#[allow(ambiguous_glob_reexports)]
+pub use get_sale_catalog::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_creator_earnings::*;
+#[allow(ambiguous_glob_reexports)]
+pub use confirm_receipt::*;
+#[allow(ambiguous_glob_reexports)]
+pub use open_dispute::*;
+#[allow(ambiguous_glob_reexports)]
+pub use resolve_dispute::*;
+#[allow(ambiguous_glob_reexports)]
+pub use release_proceeds::*;
+#[allow(ambiguous_glob_reexports)]
+pub use claim_unclaimed_refund::*;
+#[allow(ambiguous_glob_reexports)]
+pub use claim_refunds_batch::*;
+#[allow(ambiguous_glob_reexports)]
+pub use check_solvency::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_deposit::*;
+#[allow(ambiguous_glob_reexports)]
+pub use fund_bidder_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_bidder_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_bidder_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use delegate_bidder_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use undelegate_bidder_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use unlock_bid_funds::*;
+#[allow(ambiguous_glob_reexports)]
+pub use revoke_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_global_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use delegate_global_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use undelegate_global_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use revoke_global_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use close_global_session::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_bid_nonce::*;
+#[allow(ambiguous_glob_reexports)]
+pub use delegate_bid_nonce::*;
+#[allow(ambiguous_glob_reexports)]
+pub use undelegate_bid_nonce::*;
+#[allow(ambiguous_glob_reexports)]
+pub use place_signed_bid::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_allowlist_root::*;
+#[allow(ambiguous_glob_reexports)]
+pub use block_bidder::*;
+#[allow(ambiguous_glob_reexports)]
+pub use unblock_bidder::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_bidder_profile::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_seller_profile::*;
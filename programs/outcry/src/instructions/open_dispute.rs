@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::DisputeOpened,
+    state::ProceedsEscrow,
+};
+
+/// Buyer disputes delivery within the window — freezes the held proceeds
+/// until the arbiter resolves it.
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROCEEDS_SEED, proceeds_escrow.auction.as_ref()],
+        bump = proceeds_escrow.bump,
+        has_one = buyer @ OutcryError::UnauthorizedBuyer,
+        constraint = !proceeds_escrow.disputed @ OutcryError::ProceedsDisputed,
+    )]
+    pub proceeds_escrow: Account<'info, ProceedsEscrow>,
+}
+
+pub fn handle_open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+    let escrow = &mut ctx.accounts.proceeds_escrow;
+    require!(
+        Clock::get()?.unix_timestamp < escrow.release_after,
+        OutcryError::DisputeWindowClosed
+    );
+
+    escrow.disputed = true;
+
+    emit!(DisputeOpened {
+        auction: escrow.auction,
+        buyer: escrow.buyer,
+    });
+
+    Ok(())
+}
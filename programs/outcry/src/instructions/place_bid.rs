@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::MAX_EXTENSION_SECONDS,
+    constants::{
+        BIDDER_ACCOUNT_SEED, BIDDER_PROFILE_SEED, BLOCKLIST_SEED, DEFAULT_RECORD_SEED, DEPOSIT_SEED,
+    },
     errors::OutcryError,
     events::BidPlaced,
-    state::{AuctionState, AuctionStatus},
+    state::{
+        AuctionState, AuctionStatus, BidEligibility, BidFunding, BidGuards, BidderAccount,
+        BidderDeposit,
+    },
 };
 
 #[derive(Accounts)]
@@ -13,77 +18,116 @@ pub struct PlaceBid<'info> {
 
     #[account(
         mut,
+        constraint = auction_state.status != AuctionStatus::Created @ OutcryError::AuctionNotStarted,
         constraint = auction_state.status == AuctionStatus::Active @ OutcryError::InvalidAuctionStatus,
         constraint = auction_state.seller != bidder.key() @ OutcryError::SellerCannotBid,
     )]
     pub auction_state: Account<'info, AuctionState>,
-}
 
-pub fn handle_place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
-    let clock = Clock::get()?;
-    let auction = &mut ctx.accounts.auction_state;
-    let bidder_key = ctx.accounts.bidder.key();
+    /// Bidder's deposit on this auction — required only on gated auctions, as
+    /// proof the wallet passed the allowlist / collection check during
+    /// `deposit`. Lives on L1; the ER reads it as a cloned read-only account.
+    #[account(
+        seeds = [DEPOSIT_SEED, auction_state.key().as_ref(), bidder.key().as_ref()],
+        bump = bidder_deposit.bump,
+    )]
+    pub bidder_deposit: Option<Account<'info, BidderDeposit>>,
 
-    // Verify auction hasn't expired
-    require!(
-        clock.unix_timestamp < auction.end_time,
-        OutcryError::AuctionEnded
-    );
+    /// Seller's blocklist — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [BLOCKLIST_SEED, auction_state.seller.as_ref()],
+        bump,
+    )]
+    pub blocklist: UncheckedAccount<'info>,
 
-    // Validate bid amount
-    if auction.bid_count == 0 {
-        // First bid must meet reserve
-        require!(amount >= auction.reserve_price, OutcryError::BelowReserve);
-    } else {
-        // Subsequent bids must exceed current + increment
-        let min_bid = auction
-            .current_bid
-            .checked_add(auction.min_bid_increment)
-            .ok_or(OutcryError::ArithmeticOverflow)?;
-        require!(amount >= min_bid, OutcryError::BidTooLow);
-    }
+    /// Bidder's default history — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [DEFAULT_RECORD_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub default_record: UncheckedAccount<'info>,
 
-    // NOTE: Deposit validation is deferred to settle_auction on L1.
-    // The ER only tracks bid state — actual SOL lives in the L1 vault.
-    // Frontend enforces deposit checks client-side before allowing a bid.
+    /// Bidder's profile — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [BIDDER_PROFILE_SEED, bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_profile: UncheckedAccount<'info>,
 
-    let previous_bid = auction.current_bid;
+    /// Previous lot in the sale — required when bidding on a sale lot after
+    /// the first, since this lot can't close before it. Read-only; on the ER
+    /// it is delegated alongside this one.
+    pub previous_lot: Option<Account<'info, AuctionState>>,
 
-    auction.current_bid = amount;
-    auction.highest_bidder = bidder_key;
-    auction.bid_count = auction
-        .bid_count
-        .checked_add(1)
-        .ok_or(OutcryError::ArithmeticOverflow)?;
+    /// Bidder's BidderAccount — when passed, the amount due on this bid is
+    /// locked from its balance. On the ER it must be delegated.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, bidder.key().as_ref()],
+        bump = bidder_account.bump,
+    )]
+    pub bidder_account: Option<Account<'info, BidderAccount>>,
 
-    // Anti-snipe: extend if bid arrives within extension_window of end.
-    // Cap total extensions at original_duration + min(original_duration, 1 hour).
-    let time_remaining = auction.end_time
-        .checked_sub(clock.unix_timestamp)
-        .ok_or(OutcryError::ArithmeticOverflow)?;
-    if time_remaining < auction.extension_window as i64 {
-        let max_extension = std::cmp::min(auction.duration_seconds as i64, MAX_EXTENSION_SECONDS);
-        let max_end_time = auction
-            .start_time
-            .checked_add(auction.duration_seconds as i64)
-            .ok_or(OutcryError::ArithmeticOverflow)?
-            .checked_add(max_extension)
-            .ok_or(OutcryError::ArithmeticOverflow)?;
-        let proposed_end = auction
-            .end_time
-            .checked_add(auction.extension_seconds as i64)
-            .ok_or(OutcryError::ArithmeticOverflow)?;
-        auction.end_time = proposed_end.min(max_end_time);
-    }
+    /// Outbid leader's BidderAccount — when passed, their lock on this
+    /// auction goes back to their balance. On the ER it must be delegated.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, auction_state.highest_bidder.as_ref()],
+        bump = outbid_bidder_account.bump,
+    )]
+    pub outbid_bidder_account: Option<Account<'info, BidderAccount>>,
+}
+
+pub fn handle_place_bid(
+    ctx: Context<PlaceBid>,
+    amount: u64,
+    guards: Option<BidGuards>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let bidder_key = ctx.accounts.bidder.key();
+    let auction_key = ctx.accounts.auction_state.key();
+    let auction = &mut ctx.accounts.auction_state;
+
+    let (previous_bid, extended) = auction.validate_and_apply_bid(
+        &bidder_key,
+        amount,
+        guards,
+        BidEligibility {
+            blocklist: &ctx.accounts.blocklist,
+            default_record: &ctx.accounts.default_record,
+            bidder_profile: &ctx.accounts.bidder_profile,
+            deposit: ctx.accounts.bidder_deposit.as_deref(),
+            session: None,
+            previous_lot: ctx.accounts.previous_lot.as_deref(),
+        },
+        BidFunding {
+            auction: auction_key,
+            bidder_account: ctx.accounts.bidder_account.as_deref_mut(),
+            outbid_account: ctx.accounts.outbid_bidder_account.as_deref_mut(),
+        },
+        clock.unix_timestamp,
+    )?;
 
     emit!(BidPlaced {
-        auction: auction.key(),
+        auction: auction_key,
         bidder: bidder_key,
         amount,
         previous_bid,
         bid_count: auction.bid_count,
         new_end_time: auction.end_time,
+        extended,
     });
 
     Ok(())
 }
+
+/// Bids exactly the minimum acceptable next bid, computed on-chain at
+/// execution time and capped by `guards.max_amount`.
+pub fn handle_place_bid_next_increment(ctx: Context<PlaceBid>, guards: BidGuards) -> Result<()> {
+    require!(guards.max_amount.is_some(), OutcryError::BidGuardRequired);
+    let amount = ctx.accounts.auction_state.min_next_bid()?;
+    handle_place_bid(ctx, amount, Some(guards))
+}
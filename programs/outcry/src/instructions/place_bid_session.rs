@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{MAX_EXTENSION_SECONDS, SESSION_SEED},
+    constants::{
+        BIDDER_ACCOUNT_SEED, BIDDER_PROFILE_SEED, BLOCKLIST_SEED, DEFAULT_RECORD_SEED, DEPOSIT_SEED,
+        SESSION_SEED,
+    },
     errors::OutcryError,
     events::BidPlaced,
-    state::{AuctionState, AuctionStatus, SessionToken},
+    state::{
+        AuctionState, AuctionStatus, BidEligibility, BidFunding, BidGuards, BidderAccount,
+        BidderDeposit, GlobalSession, SessionToken,
+    },
 };
 
 #[derive(Accounts)]
@@ -12,89 +18,195 @@ pub struct PlaceBidSession<'info> {
     /// The ephemeral browser keypair — signs tx without wallet popup.
     pub session_signer: Signer<'info>,
 
-    /// Session token proving the ephemeral key is authorized by a real wallet.
-    /// Lives on L1 (never delegated). ER clones it as read-only.
+    /// Per-auction session token proving the ephemeral key is authorized by a
+    /// real wallet. Lives on L1 (never delegated). ER clones it as read-only
+    /// and picks up L1 updates, so revoke_session / close_session reach the
+    /// ER too. Pass this or global_session, not both.
     #[account(
         seeds = [SESSION_SEED, auction_state.key().as_ref(), session_token.bidder.as_ref()],
         bump = session_token.bump,
         constraint = session_token.session_signer == session_signer.key() @ OutcryError::SessionSignerMismatch,
         constraint = session_token.auction == auction_state.key() @ OutcryError::SessionBidderMismatch,
     )]
-    pub session_token: Account<'info, SessionToken>,
+    pub session_token: Option<Account<'info, SessionToken>>,
 
+    /// Wallet-wide session. Writable because each bid draws down its shared
+    /// bid-volume cap — on the ER it must be delegated alongside the auction.
     #[account(
         mut,
+        seeds = [SESSION_SEED, global_session.bidder.as_ref()],
+        bump = global_session.bump,
+        constraint = global_session.session_signer == session_signer.key() @ OutcryError::SessionSignerMismatch,
+    )]
+    pub global_session: Option<Account<'info, GlobalSession>>,
+
+    #[account(
+        mut,
+        constraint = auction_state.status != AuctionStatus::Created @ OutcryError::AuctionNotStarted,
         constraint = auction_state.status == AuctionStatus::Active @ OutcryError::InvalidAuctionStatus,
-        constraint = auction_state.seller != session_token.bidder @ OutcryError::SellerCannotBid,
     )]
     pub auction_state: Account<'info, AuctionState>,
+
+    /// Bidder's deposit on this auction — required only on gated auctions, as
+    /// proof the wallet passed the allowlist / collection check during
+    /// `deposit`. Lives on L1; the ER reads it as a cloned read-only account.
+    #[account(
+        seeds = [DEPOSIT_SEED, auction_state.key().as_ref(), bidder_deposit.bidder.as_ref()],
+        bump = bidder_deposit.bump,
+    )]
+    pub bidder_deposit: Option<Account<'info, BidderDeposit>>,
+
+    /// Seller's blocklist — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [BLOCKLIST_SEED, auction_state.seller.as_ref()],
+        bump,
+    )]
+    pub blocklist: UncheckedAccount<'info>,
+
+    /// Real wallet's default history — may not exist.
+    /// CHECK: The wallet comes from whichever session was passed, so the PDA
+    /// is checked in the handler; read in check_bidder_standing
+    pub default_record: UncheckedAccount<'info>,
+
+    /// Real wallet's profile — may not exist.
+    /// CHECK: The wallet comes from whichever session was passed, so the PDA
+    /// is checked in the handler; read in check_bidder_standing
+    pub bidder_profile: UncheckedAccount<'info>,
+
+    /// Previous lot in the sale — required when bidding on a sale lot after
+    /// the first, since this lot can't close before it. Read-only; on the ER
+    /// it is delegated alongside this one.
+    pub previous_lot: Option<Account<'info, AuctionState>>,
+
+    /// Real wallet's BidderAccount — when passed, the amount due on this bid is
+    /// locked from its balance. On the ER it must be delegated.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, bidder_account.owner.as_ref()],
+        bump = bidder_account.bump,
+    )]
+    pub bidder_account: Option<Account<'info, BidderAccount>>,
+
+    /// Outbid leader's BidderAccount — when passed, their lock on this
+    /// auction goes back to their balance. On the ER it must be delegated.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, auction_state.highest_bidder.as_ref()],
+        bump = outbid_bidder_account.bump,
+    )]
+    pub outbid_bidder_account: Option<Account<'info, BidderAccount>>,
 }
 
-pub fn handle_place_bid_session(ctx: Context<PlaceBidSession>, amount: u64) -> Result<()> {
+pub fn handle_place_bid_session(
+    ctx: Context<PlaceBidSession>,
+    amount: u64,
+    guards: Option<BidGuards>,
+) -> Result<()> {
     let clock = Clock::get()?;
+    let auction_key = ctx.accounts.auction_state.key();
     let auction = &mut ctx.accounts.auction_state;
-    // Use the REAL wallet identity from the session token
-    let bidder_key = ctx.accounts.session_token.bidder;
 
-    // Verify auction hasn't expired
-    require!(
-        clock.unix_timestamp < auction.end_time,
-        OutcryError::AuctionEnded
+    // Use the REAL wallet identity from whichever session was passed
+    let (bidder_key, revoked, expires_at, max_bid_amount) =
+        match (&ctx.accounts.session_token, &ctx.accounts.global_session) {
+            (Some(session), None) => (
+                session.bidder,
+                session.revoked,
+                session.expires_at,
+                session.max_bid_amount,
+            ),
+            (None, Some(session)) => {
+                require!(
+                    session.allows(&auction_key, &auction.house),
+                    OutcryError::SessionTargetNotAllowed
+                );
+                (
+                    session.bidder,
+                    session.revoked,
+                    session.expires_at,
+                    session.max_bid_amount,
+                )
+            }
+            _ => return err!(OutcryError::InvalidSessionAccounts),
+        };
+    let (default_record_key, _) = Pubkey::find_program_address(
+        &[DEFAULT_RECORD_SEED, bidder_key.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.default_record.key(),
+        default_record_key,
+        OutcryError::InvalidDefaultRecord
+    );
+    let (bidder_profile_key, _) = Pubkey::find_program_address(
+        &[BIDDER_PROFILE_SEED, bidder_key.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.bidder_profile.key(),
+        bidder_profile_key,
+        OutcryError::InvalidBidderProfile
     );
 
-    // Validate bid amount
-    if auction.bid_count == 0 {
-        // First bid must meet reserve
-        require!(amount >= auction.reserve_price, OutcryError::BelowReserve);
-    } else {
-        // Subsequent bids must exceed current + increment
-        let min_bid = auction
-            .current_bid
-            .checked_add(auction.min_bid_increment)
-            .ok_or(OutcryError::ArithmeticOverflow)?;
-        require!(amount >= min_bid, OutcryError::BidTooLow);
-    }
+    // Session must be live and the bid within its spend cap
+    require!(!revoked, OutcryError::SessionRevoked);
+    require!(clock.unix_timestamp < expires_at, OutcryError::SessionExpired);
+    require!(amount <= max_bid_amount, OutcryError::SessionCapExceeded);
 
-    // NOTE: Deposit validation is deferred to settle_auction on L1.
-    // The ER only tracks bid state — actual SOL lives in the L1 vault.
-
-    let previous_bid = auction.current_bid;
-
-    auction.current_bid = amount;
-    auction.highest_bidder = bidder_key; // REAL wallet — correct for settlement
-    auction.bid_count = auction
-        .bid_count
-        .checked_add(1)
-        .ok_or(OutcryError::ArithmeticOverflow)?;
-
-    // Anti-snipe: extend if bid arrives within extension_window of end.
-    // Cap total extensions at original_duration + min(original_duration, 1 hour).
-    let time_remaining = auction.end_time
-        .checked_sub(clock.unix_timestamp)
-        .ok_or(OutcryError::ArithmeticOverflow)?;
-    if time_remaining < auction.extension_window as i64 {
-        let max_extension = std::cmp::min(auction.duration_seconds as i64, MAX_EXTENSION_SECONDS);
-        let max_end_time = auction
-            .start_time
-            .checked_add(auction.duration_seconds as i64)
-            .ok_or(OutcryError::ArithmeticOverflow)?
-            .checked_add(max_extension)
-            .ok_or(OutcryError::ArithmeticOverflow)?;
-        let proposed_end = auction
-            .end_time
-            .checked_add(auction.extension_seconds as i64)
-            .ok_or(OutcryError::ArithmeticOverflow)?;
-        auction.end_time = proposed_end.min(max_end_time);
+    let previous_leader = auction.highest_bidder;
+    // highest_bidder becomes the REAL wallet — correct for settlement
+    let (previous_bid, extended) = auction.validate_and_apply_bid(
+        &bidder_key,
+        amount,
+        guards,
+        BidEligibility {
+            blocklist: &ctx.accounts.blocklist,
+            default_record: &ctx.accounts.default_record,
+            bidder_profile: &ctx.accounts.bidder_profile,
+            deposit: ctx.accounts.bidder_deposit.as_deref(),
+            session: ctx.accounts.session_token.as_deref(),
+            previous_lot: ctx.accounts.previous_lot.as_deref(),
+        },
+        BidFunding {
+            auction: auction_key,
+            bidder_account: ctx.accounts.bidder_account.as_deref_mut(),
+            outbid_account: ctx.accounts.outbid_bidder_account.as_deref_mut(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    // Global sessions draw only the volume this bid adds: raising your own
+    // lead costs the difference, taking the lead costs the full amount. Being
+    // outbid never credits it back, so the cap bounds lifetime bid volume.
+    if let Some(session) = ctx.accounts.global_session.as_mut() {
+        let added = if previous_leader == bidder_key {
+            amount.saturating_sub(previous_bid)
+        } else {
+            amount
+        };
+        session.draw_volume(added)?;
     }
 
     emit!(BidPlaced {
-        auction: auction.key(),
+        auction: auction_key,
         bidder: bidder_key, // REAL wallet for event consumers
         amount,
         previous_bid,
         bid_count: auction.bid_count,
         new_end_time: auction.end_time,
+        extended,
     });
 
     Ok(())
 }
+
+/// Session-key version of place_bid_next_increment.
+pub fn handle_place_bid_session_next_increment(
+    ctx: Context<PlaceBidSession>,
+    guards: BidGuards,
+) -> Result<()> {
+    require!(guards.max_amount.is_some(), OutcryError::BidGuardRequired);
+    let amount = ctx.accounts.auction_state.min_next_bid()?;
+    handle_place_bid_session(ctx, amount, Some(guards))
+}
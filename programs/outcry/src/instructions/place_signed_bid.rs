@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+
+use crate::{
+    constants::{
+        BIDDER_ACCOUNT_SEED, BID_NONCE_SEED, BLOCKLIST_SEED, BIDDER_PROFILE_SEED, DEFAULT_RECORD_SEED,
+        DEPOSIT_SEED,
+    },
+    errors::OutcryError,
+    events::BidPlaced,
+    state::{
+        AuctionState, AuctionStatus, BidEligibility, BidFunding, BidNonce, BidderAccount,
+        BidderDeposit,
+    },
+};
+
+/// Bytes the bidder signs: program_id || auction || amount || nonce || expires_at
+/// (integers little-endian).
+pub const SIGNED_BID_MESSAGE_LEN: usize = 32 + 32 + 8 + 8 + 8;
+
+/// Ed25519 precompile layout: num_signatures(1) + padding(1), then one
+/// 14-byte offsets record per signature.
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+
+/// Relays a bid the real bidder signed off-chain. The transaction must put
+/// an Ed25519 verify instruction for the signed message immediately before
+/// this one; the precompile checks the signature, this checks that it covers
+/// this exact bid. Call on ER (or L1 if not delegated) — any relayer can pay.
+#[derive(Accounts)]
+#[instruction(bidder: Pubkey)]
+pub struct PlaceSignedBid<'info> {
+    /// Relayer submitting on the bidder's behalf — pays the fee only
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BID_NONCE_SEED, bidder.as_ref()],
+        bump = bid_nonce.bump,
+    )]
+    pub bid_nonce: Account<'info, BidNonce>,
+
+    #[account(
+        mut,
+        constraint = auction_state.status != AuctionStatus::Created @ OutcryError::AuctionNotStarted,
+        constraint = auction_state.status == AuctionStatus::Active @ OutcryError::InvalidAuctionStatus,
+        constraint = auction_state.seller != bidder @ OutcryError::SellerCannotBid,
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+
+    /// Bidder's deposit on this auction — required only on gated auctions, as
+    /// proof the wallet passed the allowlist / collection check during
+    /// `deposit`. Lives on L1; the ER reads it as a cloned read-only account.
+    #[account(
+        seeds = [DEPOSIT_SEED, auction_state.key().as_ref(), bidder.as_ref()],
+        bump = bidder_deposit.bump,
+    )]
+    pub bidder_deposit: Option<Account<'info, BidderDeposit>>,
+
+    /// Seller's blocklist — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [BLOCKLIST_SEED, auction_state.seller.as_ref()],
+        bump,
+    )]
+    pub blocklist: UncheckedAccount<'info>,
+
+    /// Bidder's default history — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [DEFAULT_RECORD_SEED, bidder.as_ref()],
+        bump,
+    )]
+    pub default_record: UncheckedAccount<'info>,
+
+    /// Bidder's profile — may not exist.
+    /// CHECK: PDA validated via seeds; read in check_bidder_standing
+    #[account(
+        seeds = [BIDDER_PROFILE_SEED, bidder.as_ref()],
+        bump,
+    )]
+    pub bidder_profile: UncheckedAccount<'info>,
+
+    /// Previous lot in the sale — required when bidding on a sale lot after
+    /// the first, since this lot can't close before it. Read-only; on the ER
+    /// it is delegated alongside this one.
+    pub previous_lot: Option<Account<'info, AuctionState>>,
+
+    /// Signing bidder's BidderAccount — when passed, the amount due on this bid is
+    /// locked from its balance. On the ER it must be delegated.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, bidder.as_ref()],
+        bump = bidder_account.bump,
+    )]
+    pub bidder_account: Option<Account<'info, BidderAccount>>,
+
+    /// Outbid leader's BidderAccount — when passed, their lock on this
+    /// auction goes back to their balance. On the ER it must be delegated.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, auction_state.highest_bidder.as_ref()],
+        bump = outbid_bidder_account.bump,
+    )]
+    pub outbid_bidder_account: Option<Account<'info, BidderAccount>>,
+
+    /// CHECK: Instructions sysvar — address-checked
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+/// The message a bidder signs for `place_signed_bid`.
+pub fn signed_bid_message(
+    auction: &Pubkey,
+    amount: u64,
+    nonce: u64,
+    expires_at: i64,
+) -> [u8; SIGNED_BID_MESSAGE_LEN] {
+    let mut message = [0u8; SIGNED_BID_MESSAGE_LEN];
+    message[..32].copy_from_slice(crate::ID.as_ref());
+    message[32..64].copy_from_slice(auction.as_ref());
+    message[64..72].copy_from_slice(&amount.to_le_bytes());
+    message[72..80].copy_from_slice(&nonce.to_le_bytes());
+    message[80..].copy_from_slice(&expires_at.to_le_bytes());
+    message
+}
+
+/// Checks that the instruction before this one is an Ed25519 verify of
+/// exactly `message` by `signer`, with all data inline.
+fn verify_ed25519_instruction(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, OutcryError::InvalidBidSignature);
+    let ix = load_instruction_at_checked((current - 1) as usize, instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        solana_sdk_ids::ed25519_program::ID,
+        OutcryError::InvalidBidSignature
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        OutcryError::InvalidBidSignature
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = ED25519_HEADER_LEN;
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+
+    // Signature, pubkey and message must all live in the precompile's own
+    // data (u16::MAX) — otherwise they could point at unrelated bytes
+    for index_at in [offsets + 2, offsets + 6, offsets + 12] {
+        require!(read_u16(index_at) == u16::MAX, OutcryError::InvalidBidSignature);
+    }
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + ED25519_PUBKEY_LEN)
+        .ok_or(OutcryError::InvalidBidSignature)?;
+    let signed = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(OutcryError::InvalidBidSignature)?;
+    require!(
+        pubkey == signer.as_ref() && signed == message,
+        OutcryError::InvalidBidSignature
+    );
+
+    Ok(())
+}
+
+pub fn handle_place_signed_bid(
+    ctx: Context<PlaceSignedBid>,
+    bidder: Pubkey,
+    amount: u64,
+    nonce: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction_key = ctx.accounts.auction_state.key();
+
+    verify_ed25519_instruction(
+        &ctx.accounts.instructions,
+        &bidder,
+        &signed_bid_message(&auction_key, amount, nonce, expires_at),
+    )?;
+    require!(clock.unix_timestamp <= expires_at, OutcryError::SignedBidExpired);
+
+    // Nonces only move forward, so a relayed bid can never be replayed
+    let bid_nonce = &mut ctx.accounts.bid_nonce;
+    require!(nonce > bid_nonce.last_nonce, OutcryError::SignedBidReplayed);
+    bid_nonce.last_nonce = nonce;
+
+    let auction = &mut ctx.accounts.auction_state;
+    // highest_bidder becomes the signer, not the relayer
+    let (previous_bid, extended) = auction.validate_and_apply_bid(
+        &bidder,
+        amount,
+        None,
+        BidEligibility {
+            blocklist: &ctx.accounts.blocklist,
+            default_record: &ctx.accounts.default_record,
+            bidder_profile: &ctx.accounts.bidder_profile,
+            deposit: ctx.accounts.bidder_deposit.as_deref(),
+            session: None,
+            previous_lot: ctx.accounts.previous_lot.as_deref(),
+        },
+        BidFunding {
+            auction: auction_key,
+            bidder_account: ctx.accounts.bidder_account.as_deref_mut(),
+            outbid_account: ctx.accounts.outbid_bidder_account.as_deref_mut(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    emit!(BidPlaced {
+        auction: auction_key,
+        bidder,
+        amount,
+        previous_bid,
+        bid_count: auction.bid_count,
+        new_end_time: auction.end_time,
+        extended,
+    });
+
+    Ok(())
+}
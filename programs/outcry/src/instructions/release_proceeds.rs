@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::ProceedsReleased,
+    state::{PayoutSplit, ProceedsEscrow},
+};
+
+/// Permissionless crank: once the dispute window has passed without a
+/// dispute, releases the held proceeds to the seller (or payout split
+/// recipients, passed as remaining_accounts).
+#[derive(Accounts)]
+pub struct ReleaseProceeds<'info> {
+    /// Anyone can crank this — permissionless
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROCEEDS_SEED, proceeds_escrow.auction.as_ref()],
+        bump = proceeds_escrow.bump,
+        has_one = seller,
+        constraint = !proceeds_escrow.disputed @ OutcryError::ProceedsDisputed,
+        close = seller,
+    )]
+    pub proceeds_escrow: Account<'info, ProceedsEscrow>,
+
+    /// CHECK: Validated against proceeds_escrow.seller; receives proceeds and rent
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+}
+
+pub fn handle_release_proceeds<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReleaseProceeds<'info>>,
+) -> Result<()> {
+    let escrow = &ctx.accounts.proceeds_escrow;
+    require!(
+        Clock::get()?.unix_timestamp >= escrow.release_after,
+        OutcryError::DisputeWindowOpen
+    );
+
+    let amount = escrow.amount;
+    PayoutSplit::distribute(
+        escrow.active_payout_splits(),
+        amount,
+        &escrow.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(ProceedsReleased {
+        auction: escrow.auction,
+        seller_amount: amount,
+        buyer_refund: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::ProceedsReleased,
+    state::{PayoutSplit, ProceedsEscrow},
+};
+
+/// Arbiter rules on a dispute: `refund_amount` goes back to the buyer (up to
+/// the full held amount) and the rest is released to the seller (or payout
+/// split recipients, passed as remaining_accounts).
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROCEEDS_SEED, proceeds_escrow.auction.as_ref()],
+        bump = proceeds_escrow.bump,
+        has_one = arbiter @ OutcryError::UnauthorizedArbiter,
+        has_one = buyer,
+        has_one = seller,
+        constraint = proceeds_escrow.disputed @ OutcryError::NotDisputed,
+        close = seller,
+    )]
+    pub proceeds_escrow: Account<'info, ProceedsEscrow>,
+
+    /// CHECK: Validated against proceeds_escrow.buyer; receives the refund
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against proceeds_escrow.seller; receives proceeds and rent
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+}
+
+pub fn handle_resolve_dispute<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+    refund_amount: u64,
+) -> Result<()> {
+    let escrow = &ctx.accounts.proceeds_escrow;
+    require!(
+        refund_amount <= escrow.amount,
+        OutcryError::InvalidRefundAmount
+    );
+
+    let escrow_info = escrow.to_account_info();
+    if refund_amount > 0 {
+        let buyer_info = ctx.accounts.buyer.to_account_info();
+        **escrow_info.try_borrow_mut_lamports()? -= refund_amount;
+        **buyer_info.try_borrow_mut_lamports()? += refund_amount;
+    }
+
+    let seller_amount = escrow.amount - refund_amount;
+    if seller_amount > 0 {
+        PayoutSplit::distribute(
+            escrow.active_payout_splits(),
+            seller_amount,
+            &escrow_info,
+            &ctx.accounts.seller.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    emit!(ProceedsReleased {
+        auction: escrow.auction,
+        seller_amount,
+        buyer_refund: refund_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use solana_sha256_hasher::hashv;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::ReserveRevealed,
+    state::{AuctionState, AuctionStatus},
+};
+
+/// Seller reveals a hidden reserve after the auction has ended (on L1).
+/// If the top bid meets it, settlement proceeds as normal. Otherwise the NFT
+/// goes back to the seller and the auction moves to ReserveNotMet so every
+/// bidder can claim a refund.
+#[derive(Accounts)]
+pub struct RevealReserve<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, seller.key().as_ref(), auction_state.nft_mint.as_ref()],
+        bump = auction_state.bump,
+        has_one = seller @ OutcryError::UnauthorizedSeller,
+        constraint = auction_state.status == AuctionStatus::Ended @ OutcryError::InvalidAuctionStatus,
+        constraint = auction_state.bid_count > 0 @ OutcryError::NoBidsToSettle,
+        constraint = auction_state.has_hidden_reserve() @ OutcryError::InvalidReserveReveal,
+        constraint = !auction_state.reserve_revealed @ OutcryError::InvalidReserveReveal,
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+
+    #[account(
+        constraint = nft_mint.key() == auction_state.nft_mint,
+    )]
+    pub nft_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = auction_state,
+    )]
+    pub escrow_nft_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_nft_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_reveal_reserve(
+    ctx: Context<RevealReserve>,
+    reserve_price: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction = &ctx.accounts.auction_state;
+
+    // After the window the reserve is forfeited — settle_auction treats it as met
+    let reveal_deadline = auction
+        .end_time
+        .checked_add(RESERVE_REVEAL_PERIOD)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+    require!(
+        clock.unix_timestamp < reveal_deadline,
+        OutcryError::RevealWindowClosed
+    );
+
+    let commitment = hashv(&[&reserve_price.to_le_bytes(), &salt]).to_bytes();
+    require!(
+        commitment == auction.reserve_commitment,
+        OutcryError::InvalidReserveReveal
+    );
+
+    let winning_bid = auction.current_bid;
+    let reserve_met = winning_bid >= reserve_price;
+
+    if reserve_met {
+        ctx.accounts.auction_state.reserve_revealed = true;
+    } else {
+        // Return NFT to seller — same escrow release as cancel_auction
+        let seller_key = ctx.accounts.seller.key();
+        let nft_mint_key = ctx.accounts.nft_mint.key();
+        let bump = auction.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            AUCTION_SEED,
+            seller_key.as_ref(),
+            nft_mint_key.as_ref(),
+            &[bump],
+        ]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_nft_token_account.to_account_info(),
+                    to: ctx.accounts.seller_nft_token_account.to_account_info(),
+                    authority: ctx.accounts.auction_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        ctx.accounts.auction_state.status = AuctionStatus::ReserveNotMet;
+    }
+
+    emit!(ReserveRevealed {
+        auction: ctx.accounts.auction_state.key(),
+        reserve_price,
+        winning_bid,
+        reserve_met,
+    });
+
+    Ok(())
+}
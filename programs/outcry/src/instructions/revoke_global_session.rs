@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SESSION_SEED,
+    errors::OutcryError,
+    events::SessionRevoked,
+    state::GlobalSession,
+};
+
+/// Real wallet disables its global session key. Send to whichever layer
+/// holds the session: L1 normally, the ER while it is delegated.
+#[derive(Accounts)]
+pub struct RevokeGlobalSession<'info> {
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SESSION_SEED, bidder.key().as_ref()],
+        bump = global_session.bump,
+        constraint = global_session.bidder == bidder.key() @ OutcryError::SessionBidderMismatch,
+    )]
+    pub global_session: Account<'info, GlobalSession>,
+}
+
+pub fn handle_revoke_global_session(ctx: Context<RevokeGlobalSession>) -> Result<()> {
+    let session = &mut ctx.accounts.global_session;
+    let session_signer = session.session_signer;
+
+    session.revoked = true;
+    session.session_signer = Pubkey::default();
+
+    emit!(SessionRevoked {
+        auction: Pubkey::default(),
+        bidder: session.bidder,
+        session_signer,
+    });
+
+    Ok(())
+}
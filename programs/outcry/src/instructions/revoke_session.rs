@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SESSION_SEED,
+    errors::OutcryError,
+    events::SessionRevoked,
+    state::SessionToken,
+};
+
+/// Real wallet disables a session key (e.g. a leaked browser keypair). Call
+/// on L1 — the ER's read-only clone of the token follows the L1 account, so
+/// place_bid_session on the ER starts rejecting the key too.
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SESSION_SEED, session_token.auction.as_ref(), bidder.key().as_ref()],
+        bump = session_token.bump,
+        constraint = session_token.bidder == bidder.key() @ OutcryError::SessionBidderMismatch,
+    )]
+    pub session_token: Account<'info, SessionToken>,
+}
+
+pub fn handle_revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+    let session = &mut ctx.accounts.session_token;
+    let session_signer = session.session_signer;
+
+    // Clearing the signer also fails the signer check for the old key
+    session.revoked = true;
+    session.session_signer = Pubkey::default();
+
+    emit!(SessionRevoked {
+        auction: session.auction,
+        bidder: session.bidder,
+        session_signer,
+    });
+
+    Ok(())
+}
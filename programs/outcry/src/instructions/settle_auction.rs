@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount, Transfer},
@@ -7,86 +8,116 @@ use anchor_spl::{
 use crate::{
     constants::*,
     errors::OutcryError,
-    events::AuctionSettled,
-    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit},
+    events::{AuctionSettled, ProceedsEscrowed, SellerBondSlashed},
+    metadata::parse_metadata_royalties,
+    state::{
+        AuctionHouse, AuctionState, AuctionStatus, AuctionVault, BidderAccount, BidderDeposit,
+        BidderProfile, CreatorEarnings, PayoutSplit, ProceedsEscrow,
+        SellerProfile,
+    },
 };
 
-/// Parsed creator from Metaplex metadata
-struct MetaplexCreator {
-    address: Pubkey,
-    share: u8,
-}
-
-/// Parse seller_fee_basis_points and creators from raw Metaplex metadata account data.
-///
-/// Binary layout (Borsh-serialized):
-///   key(1) + update_authority(32) + mint(32) = 65 bytes header
-///   name: String(4-byte len + bytes)
-///   symbol: String(4-byte len + bytes)
-///   uri: String(4-byte len + bytes)
-///   seller_fee_basis_points: u16
-///   creators: Option<Vec<Creator>>
-///     Creator = address(32) + verified(1) + share(1)
-fn parse_metadata_royalties(data: &[u8]) -> Result<(u16, Vec<MetaplexCreator>)> {
-    let mut offset: usize = 65; // skip key + update_authority + mint
-
-    // Skip 3 Borsh strings: name, symbol, uri
-    for _ in 0..3 {
-        require!(offset + 4 <= data.len(), OutcryError::InvalidMetadata);
-        let len = u32::from_le_bytes(
-            data[offset..offset + 4]
-                .try_into()
-                .map_err(|_| error!(OutcryError::InvalidMetadata))?,
-        ) as usize;
-        // Bounds check: ensure offset + 4 + len doesn't overflow or exceed data
-        let new_offset = offset
-            .checked_add(4)
-            .and_then(|o| o.checked_add(len))
-            .ok_or(error!(OutcryError::InvalidMetadata))?;
-        require!(new_offset <= data.len(), OutcryError::InvalidMetadata);
-        offset = new_offset;
-    }
-
-    // Read seller_fee_basis_points (u16, little-endian)
-    require!(offset + 2 <= data.len(), OutcryError::InvalidMetadata);
-    let seller_fee_bps = u16::from_le_bytes(
-        data[offset..offset + 2]
-            .try_into()
-            .map_err(|_| error!(OutcryError::InvalidMetadata))?,
-    );
-    offset += 2;
-
-    // Read Option<Vec<Creator>>
-    require!(offset + 1 <= data.len(), OutcryError::InvalidMetadata);
-    let has_creators = data[offset] == 1;
-    offset += 1;
-
-    let mut creators = Vec::new();
-    if has_creators {
-        require!(offset + 4 <= data.len(), OutcryError::InvalidMetadata);
-        let count = u32::from_le_bytes(
-            data[offset..offset + 4]
-                .try_into()
-                .map_err(|_| error!(OutcryError::InvalidMetadata))?,
-        ) as usize;
-        offset += 4;
-
-        for _ in 0..count {
-            require!(offset + 34 <= data.len(), OutcryError::InvalidMetadata);
-            let address = Pubkey::try_from(&data[offset..offset + 32])
-                .map_err(|_| error!(OutcryError::InvalidMetadata))?;
-            let verified = data[offset + 32] == 1;
-            let share = data[offset + 33];
-            offset += 34;
-
-            // Only pay verified creators — enforces Metaplex royalty standards
-            if share > 0 && verified {
-                creators.push(MetaplexCreator { address, share });
+/// Credits `amount` from the vault into a creator's CreatorEarnings PDA,
+/// creating the PDA (rent paid by the settlement payer) on first use.
+fn credit_creator_earnings<'info>(
+    earnings_info: &AccountInfo<'info>,
+    creator: Pubkey,
+    bump: u8,
+    amount: u64,
+    vault_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let mut earnings = if earnings_info.data_is_empty() {
+        let space = 8 + CreatorEarnings::INIT_SPACE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let signer_seeds: &[&[&[u8]]] = &[&[EARNINGS_SEED, creator.as_ref(), &[bump]]];
+
+        if earnings_info.lamports() == 0 {
+            system_program::create_account(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    CreateAccount {
+                        from: payer.clone(),
+                        to: earnings_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                rent,
+                space as u64,
+                &crate::ID,
+            )?;
+        } else {
+            // Someone pre-funded the address — top up, then allocate + assign
+            let top_up = rent.saturating_sub(earnings_info.lamports());
+            if top_up > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        system_program.clone(),
+                        system_program::Transfer {
+                            from: payer.clone(),
+                            to: earnings_info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
             }
+            system_program::allocate(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    Allocate {
+                        account_to_allocate: earnings_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                space as u64,
+            )?;
+            system_program::assign(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    Assign {
+                        account_to_assign: earnings_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                &crate::ID,
+            )?;
         }
-    }
 
-    Ok((seller_fee_bps, creators))
+        CreatorEarnings {
+            creator,
+            balance: 0,
+            total_earned: 0,
+            bump,
+        }
+    } else {
+        require_keys_eq!(
+            *earnings_info.owner,
+            crate::ID,
+            OutcryError::InvalidCreatorEarnings
+        );
+        let data = earnings_info.try_borrow_data()?;
+        let earnings = CreatorEarnings::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(OutcryError::InvalidCreatorEarnings))?;
+        require_keys_eq!(earnings.creator, creator, OutcryError::InvalidCreatorEarnings);
+        earnings
+    };
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **earnings_info.try_borrow_mut_lamports()? += amount;
+
+    earnings.balance = earnings
+        .balance
+        .checked_add(amount)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+    earnings.total_earned = earnings
+        .total_earned
+        .checked_add(amount)
+        .ok_or(OutcryError::ArithmeticOverflow)?;
+
+    let mut data = earnings_info.try_borrow_mut_data()?;
+    earnings.try_serialize(&mut &mut data[..])?;
+    Ok(())
 }
 
 #[derive(Accounts)]
@@ -109,15 +140,25 @@ pub struct SettleAuction<'info> {
     )]
     pub auction_vault: Account<'info, AuctionVault>,
 
-    /// Winner's deposit PDA — validates they deposited enough
+    /// Winner's deposit PDA. Together with the winner's bid lock it must
+    /// cover the winning bid plus any buyer's premium.
     #[account(
         mut,
         seeds = [DEPOSIT_SEED, auction_state.key().as_ref(), auction_state.highest_bidder.as_ref()],
         bump = winner_deposit.bump,
-        constraint = winner_deposit.amount >= auction_state.current_bid
-            @ OutcryError::InsufficientDeposit,
     )]
-    pub winner_deposit: Account<'info, BidderDeposit>,
+    pub winner_deposit: Option<Account<'info, BidderDeposit>>,
+
+    /// Winner's BidderAccount, holding the funds their bids locked into this
+    /// auction. Must be back on L1 — undelegate_bidder_account first if the
+    /// winner bid from the ER. Whatever settlement doesn't take goes back to
+    /// their balance.
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, auction_state.highest_bidder.as_ref()],
+        bump = winner_bidder_account.bump,
+    )]
+    pub winner_bidder_account: Option<Account<'info, BidderAccount>>,
 
     /// CHECK: Validated against auction_state.seller
     #[account(
@@ -133,6 +174,24 @@ pub struct SettleAuction<'info> {
     )]
     pub winner: UncheckedAccount<'info>,
 
+    /// Winner's BidderProfile — credited with the win if it exists.
+    /// CHECK: PDA validated via seeds; updated via BidderProfile::update_if_exists
+    #[account(
+        mut,
+        seeds = [BIDDER_PROFILE_SEED, auction_state.highest_bidder.as_ref()],
+        bump,
+    )]
+    pub winner_profile: UncheckedAccount<'info>,
+
+    /// Seller's SellerProfile — credited with the sale if it exists.
+    /// CHECK: PDA validated via seeds; updated via SellerProfile::update_if_exists
+    #[account(
+        mut,
+        seeds = [SELLER_PROFILE_SEED, auction_state.seller.as_ref()],
+        bump,
+    )]
+    pub seller_profile: UncheckedAccount<'info>,
+
     /// CHECK: Protocol treasury — constrained to the hardcoded PROTOCOL_TREASURY address
     #[account(
         mut,
@@ -140,6 +199,23 @@ pub struct SettleAuction<'info> {
     )]
     pub protocol_treasury: UncheckedAccount<'info>,
 
+    /// Required when the auction escrows seller proceeds (physical items)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProceedsEscrow::INIT_SPACE,
+        seeds = [PROCEEDS_SEED, auction_state.key().as_ref()],
+        bump,
+    )]
+    pub proceeds_escrow: Option<Account<'info, ProceedsEscrow>>,
+
+    /// Required when the auction was listed under a house
+    pub auction_house: Option<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Validated against auction_house.fee_recipient in the handler
+    #[account(mut)]
+    pub house_fee_recipient: Option<UncheckedAccount<'info>>,
+
     #[account(
         constraint = nft_mint.key() == auction_state.nft_mint,
     )]
@@ -183,44 +259,162 @@ pub struct SettleAuction<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+pub fn handle_settle_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+) -> Result<()> {
+    // A hidden reserve must be revealed and met (or forfeited by the seller)
+    require!(
+        ctx.accounts
+            .auction_state
+            .reserve_cleared(Clock::get()?.unix_timestamp)?,
+        OutcryError::ReserveNotRevealed
+    );
+
     // Extract all values from auction_state upfront so we can release the
     // mutable borrow before the NFT transfer CPI (which needs an immutable
     // borrow of auction_state for PDA signer authority).
     let winning_bid = ctx.accounts.auction_state.current_bid;
+    let buyer_premium = ctx.accounts.auction_state.buyer_premium()?;
+    let amount_due = ctx.accounts.auction_state.amount_due()?;
     let winner_key = ctx.accounts.auction_state.highest_bidder;
     let seller_key = ctx.accounts.auction_state.seller;
     let auction_bump = ctx.accounts.auction_state.bump;
+    // Reaching here with a hidden reserve still sealed means the reveal
+    // window lapsed
+    let reserve_lapsed = ctx.accounts.auction_state.has_hidden_reserve()
+        && !ctx.accounts.auction_state.reserve_revealed;
+
+    // Winning bid + premium come from the deposit first, then the lock.
+    // Bids lock at least the amount due, so a shortfall only happens when
+    // the winner bid without funding; that's their default.
+    let auction_key = ctx.accounts.auction_state.key();
+    let deposit_amount = ctx.accounts.winner_deposit.as_ref().map_or(0, |d| d.amount);
+    let lock_amount = ctx
+        .accounts
+        .winner_bidder_account
+        .as_ref()
+        .map_or(0, |a| a.lock_amount(&auction_key));
+    require!(
+        (deposit_amount as u128) + (lock_amount as u128) >= amount_due as u128,
+        OutcryError::InsufficientDeposit
+    );
+    let from_deposit = deposit_amount.min(amount_due);
+    let from_lock = amount_due - from_deposit;
 
-    // Deduct winning bid from winner's deposit
-    let winner_deposit = &mut ctx.accounts.winner_deposit;
-    winner_deposit.amount = winner_deposit
-        .amount
-        .checked_sub(winning_bid)
-        .ok_or(OutcryError::ArithmeticOverflow)?;
+    if let Some(winner_deposit) = ctx.accounts.winner_deposit.as_mut() {
+        winner_deposit.amount -= from_deposit;
+    }
+
+    // Pull the locked share into the vault so the payouts below draw from
+    // one place; the rest of the lock returns to the winner's balance
+    if let Some(bidder_account) = ctx.accounts.winner_bidder_account.as_mut() {
+        bidder_account.debit_lock(&auction_key, from_lock)?;
+
+        if from_lock > 0 {
+            let account_info = bidder_account.to_account_info();
+            let vault_info = ctx.accounts.auction_vault.to_account_info();
+            **account_info.try_borrow_mut_lamports()? -= from_lock;
+            **vault_info.try_borrow_mut_lamports()? += from_lock;
+            ctx.accounts.auction_vault.record_deposit(from_lock)?;
+        }
+    }
 
-    // --- Verify vault has sufficient lamports ---
-    let vault_lamports = ctx.accounts.auction_vault.to_account_info().lamports();
-    let vault_rent = Rent::get()?.minimum_balance(ctx.accounts.auction_vault.to_account_info().data_len());
-    let available = vault_lamports.saturating_sub(vault_rent);
+    // --- Verify vault has sufficient lamports (the seller bond isn't spendable) ---
+    let vault = &ctx.accounts.auction_vault;
+    let available = vault.spendable(&vault.to_account_info())?;
     require!(
-        available >= winning_bid,
+        available >= amount_due,
         OutcryError::InsufficientVaultBalance
     );
 
-    // --- Parse royalty info from Metaplex metadata ---
-    let metadata_data = ctx.accounts.nft_metadata.try_borrow_data()?;
-    let (seller_fee_bps, creators) = parse_metadata_royalties(&metadata_data)?;
-    drop(metadata_data); // Release borrow before lamport transfers
+    // --- Resolve house fee recipient (house listings only) ---
+    let house_recipient = if ctx.accounts.auction_state.house != Pubkey::default() {
+        let house = ctx
+            .accounts
+            .auction_house
+            .as_ref()
+            .ok_or(OutcryError::InvalidAuctionHouse)?;
+        require_keys_eq!(
+            house.key(),
+            ctx.accounts.auction_state.house,
+            OutcryError::InvalidAuctionHouse
+        );
+        let recipient = ctx
+            .accounts
+            .house_fee_recipient
+            .as_ref()
+            .ok_or(OutcryError::InvalidFeeRecipient)?;
+        require_keys_eq!(
+            recipient.key(),
+            house.fee_recipient,
+            OutcryError::InvalidFeeRecipient
+        );
+        Some(recipient.to_account_info())
+    } else {
+        None
+    };
 
-    // Calculate total royalties
-    let total_royalties = (winning_bid as u128)
-        .checked_mul(seller_fee_bps as u128)
+    // --- 1. Protocol fee ---
+    let protocol_fee = (winning_bid as u128)
+        .checked_mul(PROTOCOL_FEE_BPS as u128)
         .ok_or(OutcryError::ArithmeticOverflow)?
         .checked_div(10_000)
         .ok_or(OutcryError::ArithmeticOverflow)? as u64;
 
-    // --- Distribute royalties to creators via remaining_accounts ---
+    if protocol_fee > 0 {
+        let vault_info = ctx.accounts.auction_vault.to_account_info();
+        let treasury_info = ctx.accounts.protocol_treasury.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? -= protocol_fee;
+        **treasury_info.try_borrow_mut_lamports()? += protocol_fee;
+    }
+
+    // --- 2. House fee ---
+    let house_fee = (winning_bid as u128)
+        .checked_mul(ctx.accounts.auction_state.house_fee_bps as u128)
+        .ok_or(OutcryError::ArithmeticOverflow)?
+        .checked_div(10_000)
+        .ok_or(OutcryError::ArithmeticOverflow)? as u64;
+
+    if let Some(recipient_info) = &house_recipient {
+        if house_fee > 0 {
+            let vault_info = ctx.accounts.auction_vault.to_account_info();
+            **vault_info.try_borrow_mut_lamports()? -= house_fee;
+            **recipient_info.try_borrow_mut_lamports()? += house_fee;
+        }
+    }
+
+    // --- Buyer's premium goes to the house (or treasury), outside the seller's split ---
+    if buyer_premium > 0 {
+        let vault_info = ctx.accounts.auction_vault.to_account_info();
+        let premium_info = match &house_recipient {
+            Some(recipient_info) => recipient_info.clone(),
+            None => ctx.accounts.protocol_treasury.to_account_info(),
+        };
+        **vault_info.try_borrow_mut_lamports()? -= buyer_premium;
+        **premium_info.try_borrow_mut_lamports()? += buyer_premium;
+    }
+
+    // --- 3. Royalties — parse royalty info from Metaplex metadata ---
+    let metadata_data = ctx.accounts.nft_metadata.try_borrow_data()?;
+    let (seller_fee_bps, creators) = parse_metadata_royalties(&metadata_data)?;
+    drop(metadata_data); // Release borrow before lamport transfers
+
+    // Calculate total royalties, capped at what's left after fees — metadata
+    // may ask for up to 100%, which on top of fees would exceed the bid
+    let after_fees = winning_bid
+        .saturating_sub(protocol_fee)
+        .saturating_sub(house_fee);
+    let total_royalties = std::cmp::min(
+        (winning_bid as u128)
+            .checked_mul(seller_fee_bps as u128)
+            .ok_or(OutcryError::ArithmeticOverflow)?
+            .checked_div(10_000)
+            .ok_or(OutcryError::ArithmeticOverflow)? as u64,
+        after_fees,
+    );
+
+    // Credit royalties to each creator's CreatorEarnings PDA. The PDAs lead
+    // remaining_accounts in any order; creators withdraw on their own time.
     let mut distributed_royalties: u64 = 0;
 
     if total_royalties > 0 && !creators.is_empty() {
@@ -229,15 +423,12 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
             OutcryError::MissingCreatorAccount
         );
 
+        let earnings_accounts = &ctx.remaining_accounts[..creators.len()];
         let vault_info = ctx.accounts.auction_vault.to_account_info();
+        let payer_info = ctx.accounts.payer.to_account_info();
+        let system_program_info = ctx.accounts.system_program.to_account_info();
 
-        for (i, creator) in creators.iter().enumerate() {
-            let creator_account = &ctx.remaining_accounts[i];
-            require!(
-                creator_account.key() == creator.address,
-                OutcryError::MissingCreatorAccount
-            );
-
+        for creator in creators.iter() {
             // Cap each payout so distributed total never exceeds total_royalties
             let creator_royalty = std::cmp::min(
                 (total_royalties as u128)
@@ -249,39 +440,88 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
             );
 
             if creator_royalty > 0 {
-                **vault_info.try_borrow_mut_lamports()? -= creator_royalty;
-                **creator_account.try_borrow_mut_lamports()? += creator_royalty;
+                let (earnings_key, earnings_bump) = Pubkey::find_program_address(
+                    &[EARNINGS_SEED, creator.address.as_ref()],
+                    ctx.program_id,
+                );
+                let earnings_info = earnings_accounts
+                    .iter()
+                    .find(|account| account.key() == earnings_key)
+                    .ok_or(OutcryError::MissingCreatorAccount)?;
+
+                credit_creator_earnings(
+                    earnings_info,
+                    creator.address,
+                    earnings_bump,
+                    creator_royalty,
+                    &vault_info,
+                    &payer_info,
+                    &system_program_info,
+                )?;
                 distributed_royalties += creator_royalty;
             }
         }
     }
 
-    // --- Calculate and transfer protocol fee ---
-    let protocol_fee = (winning_bid as u128)
-        .checked_mul(PROTOCOL_FEE_BPS as u128)
-        .ok_or(OutcryError::ArithmeticOverflow)?
-        .checked_div(10_000)
-        .ok_or(OutcryError::ArithmeticOverflow)? as u64;
-
-    if protocol_fee > 0 {
-        let vault_info = ctx.accounts.auction_vault.to_account_info();
-        let treasury_info = ctx.accounts.protocol_treasury.to_account_info();
-        **vault_info.try_borrow_mut_lamports()? -= protocol_fee;
-        **treasury_info.try_borrow_mut_lamports()? += protocol_fee;
-    }
-
-    // --- Send remainder to seller (winning bid minus royalties minus protocol fee) ---
+    // --- 4. Send remainder to seller (winning bid minus fees minus royalties) ---
     let seller_receives = winning_bid
-        .checked_sub(distributed_royalties)
-        .ok_or(OutcryError::ArithmeticOverflow)?
         .checked_sub(protocol_fee)
+        .ok_or(OutcryError::ArithmeticOverflow)?
+        .checked_sub(house_fee)
+        .ok_or(OutcryError::ArithmeticOverflow)?
+        .checked_sub(distributed_royalties)
         .ok_or(OutcryError::ArithmeticOverflow)?;
 
     let vault_info = ctx.accounts.auction_vault.to_account_info();
-    let seller_info = ctx.accounts.seller.to_account_info();
 
-    **vault_info.try_borrow_mut_lamports()? -= seller_receives;
-    **seller_info.try_borrow_mut_lamports()? += seller_receives;
+    if ctx.accounts.auction_state.payout_arbiter != Pubkey::default() {
+        // Physical item: hold the seller's share until delivery is confirmed
+        let auction = &ctx.accounts.auction_state;
+        let release_after = Clock::get()?
+            .unix_timestamp
+            .checked_add(auction.dispute_window_seconds as i64)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        let escrow = ctx
+            .accounts
+            .proceeds_escrow
+            .as_mut()
+            .ok_or(OutcryError::ProceedsEscrowRequired)?;
+        escrow.auction = auction.key();
+        escrow.seller = auction.seller;
+        escrow.buyer = winner_key;
+        escrow.arbiter = auction.payout_arbiter;
+        escrow.amount = seller_receives;
+        escrow.release_after = release_after;
+        escrow.disputed = false;
+        escrow.payout_splits = auction.payout_splits;
+        escrow.bump = ctx.bumps.proceeds_escrow.ok_or(OutcryError::ProceedsEscrowRequired)?;
+
+        let escrow_info = escrow.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? -= seller_receives;
+        **escrow_info.try_borrow_mut_lamports()? += seller_receives;
+
+        emit!(ProceedsEscrowed {
+            auction: auction.key(),
+            amount: seller_receives,
+            release_after,
+        });
+    } else {
+        require!(
+            ctx.accounts.proceeds_escrow.is_none(),
+            OutcryError::InvalidEscrowTerms
+        );
+
+        // Consignment split recipients follow the creator earnings PDAs in
+        // remaining_accounts, in table order
+        let offset = if total_royalties > 0 { creators.len() } else { 0 };
+        PayoutSplit::distribute(
+            ctx.accounts.auction_state.active_payout_splits(),
+            seller_receives,
+            &vault_info,
+            &ctx.accounts.seller.to_account_info(),
+            ctx.remaining_accounts.get(offset..).unwrap_or(&[]),
+        )?;
+    }
 
     // --- Transfer NFT from escrow to winner ---
     let nft_mint_key = ctx.accounts.nft_mint.key();
@@ -305,6 +545,38 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         1,
     )?;
 
+    // Fees, premium, royalties and seller proceeds add up to amount_due
+    let vault = &mut ctx.accounts.auction_vault;
+    vault.record_payout(amount_due)?;
+
+    // A seller who sat on a hidden reserve forfeits the bond to the winner
+    let vault_info = vault.to_account_info();
+    let slashed = if reserve_lapsed {
+        vault.slash_bond(&vault_info, &ctx.accounts.winner.to_account_info())?
+    } else {
+        0
+    };
+    vault.assert_solvent(&vault_info)?;
+
+    BidderProfile::update_if_exists(&ctx.accounts.winner_profile, |profile| {
+        profile.record_win(amount_due)
+    })?;
+    SellerProfile::update_if_exists(&ctx.accounts.seller_profile, |profile| {
+        profile.record_sale(winning_bid)?;
+        if slashed > 0 {
+            profile.record_slash(slashed)?;
+        }
+        Ok(())
+    })?;
+    if slashed > 0 {
+        emit!(SellerBondSlashed {
+            auction: ctx.accounts.auction_state.key(),
+            seller: seller_key,
+            recipient: winner_key,
+            amount: slashed,
+        });
+    }
+
     // Mark as Settled after all transfers succeed.
     // Double-settle is already prevented by the Anchor constraint
     // `status == AuctionStatus::Ended` — a second call fails validation.
@@ -318,6 +590,8 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         seller_received: seller_receives,
         royalties_paid: distributed_royalties,
         protocol_fee,
+        house_fee,
+        buyer_premium,
     });
 
     Ok(())
@@ -25,12 +25,13 @@ pub fn handle_start_auction(ctx: Context<StartAuction>) -> Result<()> {
     let clock = Clock::get()?;
     let auction = &mut ctx.accounts.auction_state;
 
-    auction.start_time = clock.unix_timestamp;
-    auction.end_time = clock
-        .unix_timestamp
-        .checked_add(auction.duration_seconds as i64)
-        .ok_or(OutcryError::ArithmeticOverflow)?;
-    auction.status = AuctionStatus::Active;
+    // A scheduled drop can't go live early, even by the seller
+    require!(
+        clock.unix_timestamp >= auction.scheduled_start,
+        OutcryError::AuctionNotStarted
+    );
+
+    auction.activate(clock.unix_timestamp)?;
 
     emit!(AuctionStarted {
         auction: auction.key(),
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::delegate;
+use ephemeral_rollups_sdk::cpi::DelegateConfig;
+
+use crate::constants::*;
+use crate::errors::OutcryError;
+use crate::events::AuctionStarted;
+use crate::state::{AuctionState, AuctionStatus};
+
+/// Permissionless crank for scheduled drops. Once `scheduled_start` has
+/// passed, anyone can activate the auction and delegate it to the Ephemeral
+/// Rollup in a single L1 transaction — the seller doesn't need to be online.
+#[delegate]
+#[derive(Accounts)]
+#[instruction(seller: Pubkey, nft_mint: Pubkey)]
+pub struct StartScheduledAuction<'info> {
+    /// Anyone can crank this — pays for the delegation accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The AuctionState PDA to activate and delegate. Validated by seeds.
+    #[account(
+        mut,
+        del,
+        seeds = [AUCTION_SEED, seller.as_ref(), nft_mint.as_ref()],
+        bump,
+    )]
+    pub auction_state: AccountInfo<'info>,
+}
+
+pub fn handle_start_scheduled_auction(
+    ctx: Context<StartScheduledAuction>,
+    seller: Pubkey,
+    nft_mint: Pubkey,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Activate via manual (de)serialization — AccountInfo is required by `del`
+    let mut data = ctx.accounts.auction_state.try_borrow_mut_data()?;
+    let mut slice: &[u8] = &data;
+    let mut auction = AuctionState::try_deserialize(&mut slice)
+        .map_err(|_| error!(OutcryError::InvalidAuctionStatus))?;
+    require!(
+        auction.status == AuctionStatus::Created,
+        OutcryError::InvalidAuctionStatus
+    );
+    require!(auction.scheduled_start > 0, OutcryError::NotScheduled);
+    require!(
+        clock.unix_timestamp >= auction.scheduled_start,
+        OutcryError::AuctionNotStarted
+    );
+
+    auction.activate(clock.unix_timestamp)?;
+
+    let mut writer: &mut [u8] = &mut data[..];
+    auction.try_serialize(&mut writer)?;
+    drop(data);
+
+    emit!(AuctionStarted {
+        auction: ctx.accounts.auction_state.key(),
+        start_time: auction.start_time,
+        end_time: auction.end_time,
+    });
+
+    ctx.accounts.delegate_auction_state(
+        &ctx.accounts.payer,
+        &[AUCTION_SEED, seller.as_ref(), nft_mint.as_ref()],
+        DelegateConfig::default(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, events::BidderUnblocked, state::BlockList};
+
+/// Removes a wallet from the seller's blocklist.
+#[derive(Accounts)]
+pub struct UnblockBidder<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BLOCKLIST_SEED, seller.key().as_ref()],
+        bump = blocklist.bump,
+    )]
+    pub blocklist: Account<'info, BlockList>,
+}
+
+pub fn handle_unblock_bidder(ctx: Context<UnblockBidder>, wallet: Pubkey) -> Result<()> {
+    let list = &mut ctx.accounts.blocklist;
+
+    // Unblocking a wallet that isn't listed is a no-op
+    let Some(index) = list.blocked.iter().position(|w| *w == wallet) else {
+        return Ok(());
+    };
+    list.blocked.swap_remove(index);
+
+    emit!(BidderUnblocked {
+        seller: list.seller,
+        wallet,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+use crate::constants::BID_NONCE_SEED;
+use crate::state::BidNonce;
+
+/// Commits a BidNonce and undelegates it back to L1. The bidder signs; a
+/// relayer may pay. Sends to ER endpoint.
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateBidNonce<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BID_NONCE_SEED, bidder.key().as_ref()],
+        bump = bid_nonce.bump,
+    )]
+    pub bid_nonce: Account<'info, BidNonce>,
+}
+
+pub fn handle_undelegate_bid_nonce(ctx: Context<UndelegateBidNonce>) -> Result<()> {
+    commit_and_undelegate_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.bid_nonce.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    Ok(())
+}
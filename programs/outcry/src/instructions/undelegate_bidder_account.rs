@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+use crate::constants::BIDDER_ACCOUNT_SEED;
+use crate::errors::OutcryError;
+use crate::state::{AuctionState, AuctionStatus, BidderAccount};
+
+/// Commits the balance and locks and undelegates the BidderAccount back to
+/// L1, e.g. before withdrawing or closing it. Sends to ER endpoint.
+///
+/// The owner can always do this. Anyone else can once the owner has won an
+/// auction they hold a lock on (`won_auction` Ended with them on top), so
+/// settle_auction or forfeit_auction can reach the lock on L1.
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateBidderAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, bidder_account.owner.as_ref()],
+        bump = bidder_account.bump,
+    )]
+    pub bidder_account: Account<'info, BidderAccount>,
+
+    /// Required unless the owner signs
+    pub won_auction: Option<Account<'info, AuctionState>>,
+}
+
+pub fn handle_undelegate_bidder_account(ctx: Context<UndelegateBidderAccount>) -> Result<()> {
+    let bidder_account = &ctx.accounts.bidder_account;
+    if ctx.accounts.payer.key() != bidder_account.owner {
+        let auction = ctx
+            .accounts
+            .won_auction
+            .as_ref()
+            .ok_or(OutcryError::InvalidBidderAccount)?;
+        require!(
+            auction.status == AuctionStatus::Ended
+                && auction.highest_bidder == bidder_account.owner
+                && bidder_account.lock_amount(&auction.key()) > 0,
+            OutcryError::InvalidBidderAccount
+        );
+    }
+
+    commit_and_undelegate_accounts(
+        &ctx.accounts.payer,
+        vec![&ctx.accounts.bidder_account.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    Ok(())
+}
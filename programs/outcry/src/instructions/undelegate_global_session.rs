@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+use crate::constants::SESSION_SEED;
+use crate::errors::OutcryError;
+use crate::state::GlobalSession;
+
+/// Commits the remaining bid volume and undelegates the GlobalSession back to
+/// L1, e.g. before close_global_session. Sends to ER endpoint.
+#[commit]
+#[derive(Accounts)]
+pub struct UndelegateGlobalSession<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SESSION_SEED, bidder.key().as_ref()],
+        bump = global_session.bump,
+        constraint = global_session.bidder == bidder.key() @ OutcryError::SessionBidderMismatch,
+    )]
+    pub global_session: Account<'info, GlobalSession>,
+}
+
+pub fn handle_undelegate_global_session(ctx: Context<UndelegateGlobalSession>) -> Result<()> {
+    commit_and_undelegate_accounts(
+        &ctx.accounts.bidder,
+        vec![&ctx.accounts.global_session.to_account_info()],
+        &ctx.accounts.magic_context,
+        &ctx.accounts.magic_program,
+    )?;
+
+    Ok(())
+}
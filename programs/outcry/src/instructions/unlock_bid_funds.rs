@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::BidFundsUnlocked,
+    state::{AuctionState, AuctionStatus, BidderAccount},
+};
+
+/// Returns a lock to its owner's free balance. Allowed as soon as someone
+/// else holds the top bid, or once the auction is Settled, Cancelled,
+/// ReserveNotMet or closed. Bids release the outbid leader's lock
+/// themselves when they carry that BidderAccount; this covers the rest.
+/// Permissionless — funds only move within the owner's account.
+///
+/// Runs wherever the auction is live: on the ER while it is delegated (with
+/// the BidderAccount delegated too), on L1 otherwise. The last committed
+/// state of a delegated auction may be stale, so L1 refuses it.
+#[derive(Accounts)]
+pub struct UnlockBidFunds<'info> {
+    /// CHECK: Owner of the BidderAccount; validated via its seeds
+    pub bidder: UncheckedAccount<'info>,
+
+    /// May be delegated or already closed — read raw.
+    /// CHECK: Only its key and, when live on this layer, its data are used.
+    pub auction_state: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, bidder.key().as_ref()],
+        bump = bidder_account.bump,
+    )]
+    pub bidder_account: Account<'info, BidderAccount>,
+}
+
+pub fn handle_unlock_bid_funds(ctx: Context<UnlockBidFunds>) -> Result<()> {
+    let bidder_key = ctx.accounts.bidder.key();
+
+    // A closed auction has nothing left to settle
+    let auction_info = &ctx.accounts.auction_state;
+    if !auction_info.data_is_empty() {
+        require!(
+            auction_info.owner == &crate::ID,
+            OutcryError::BidLockAuctionDelegated
+        );
+        let data = auction_info.try_borrow_data()?;
+        let auction = AuctionState::try_deserialize(&mut &data[..])
+            .map_err(|_| error!(OutcryError::InvalidAuctionStatus))?;
+        drop(data);
+
+        let released = match auction.status {
+            AuctionStatus::Settled | AuctionStatus::Cancelled | AuctionStatus::ReserveNotMet => {
+                true
+            }
+            _ => auction.highest_bidder != bidder_key,
+        };
+        require!(released, OutcryError::BidLockActive);
+    }
+
+    let auction_key = ctx.accounts.auction_state.key();
+    let amount = ctx.accounts.bidder_account.release(&auction_key)?;
+    require!(amount > 0, OutcryError::NothingToRefund);
+
+    emit!(BidFundsUnlocked {
+        auction: auction_key,
+        bidder: bidder_key,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::OutcryError,
+    events::AllowlistUpdated,
+    state::{AuctionState, AuctionStatus},
+};
+
+/// Seller replaces (or removes) the allowlist root before the auction
+/// starts. Deposits verified against the old root lose their marker and
+/// must re-verify with a new proof.
+#[derive(Accounts)]
+pub struct UpdateAllowlistRoot<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = seller @ OutcryError::UnauthorizedSeller,
+        constraint = auction_state.status == AuctionStatus::Created @ OutcryError::InvalidAuctionStatus,
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+}
+
+pub fn handle_update_allowlist_root(
+    ctx: Context<UpdateAllowlistRoot>,
+    allowlist_root: Option<[u8; 32]>,
+) -> Result<()> {
+    let auction = &mut ctx.accounts.auction_state;
+    auction.allowlist_root = allowlist_root.unwrap_or([0u8; 32]);
+
+    emit!(AllowlistUpdated {
+        auction: auction.key(),
+        root: auction.allowlist_root,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::AuctionHouseConfigured,
+    instructions::create_auction_house::AuctionHouseParams,
+    state::AuctionHouse,
+};
+
+/// Updates house fee, recipient, curator and premium. Existing listings keep
+/// the fee and premium snapshotted at their creation.
+#[derive(Accounts)]
+pub struct UpdateAuctionHouse<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [HOUSE_SEED, authority.key().as_ref()],
+        bump = auction_house.bump,
+        has_one = authority @ OutcryError::InvalidAuctionHouse,
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+}
+
+pub fn handle_update_auction_house(
+    ctx: Context<UpdateAuctionHouse>,
+    params: AuctionHouseParams,
+) -> Result<()> {
+    let house = &mut ctx.accounts.auction_house;
+    params.apply(house)?;
+
+    emit!(AuctionHouseConfigured {
+        house: house.key(),
+        authority: house.authority,
+        fee_bps: house.fee_bps,
+        fee_recipient: house.fee_recipient,
+        curator: house.curator,
+        buyer_premium_bps: house.buyer_premium_bps,
+    });
+
+    Ok(())
+}
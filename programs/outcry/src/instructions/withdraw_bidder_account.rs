@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::BidderAccountWithdrawn,
+    state::BidderAccount,
+};
+
+/// Owner withdraws from the free balance. Locked funds stay until their
+/// auctions release them.
+#[derive(Accounts)]
+pub struct WithdrawBidderAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BIDDER_ACCOUNT_SEED, owner.key().as_ref()],
+        bump = bidder_account.bump,
+        has_one = owner @ OutcryError::InvalidBidderAccount,
+    )]
+    pub bidder_account: Account<'info, BidderAccount>,
+}
+
+pub fn handle_withdraw_bidder_account(
+    ctx: Context<WithdrawBidderAccount>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, OutcryError::NothingToWithdraw);
+
+    let account = &mut ctx.accounts.bidder_account;
+    require!(amount <= account.balance, OutcryError::InsufficientBalance);
+    account.balance -= amount;
+
+    let account_info = ctx.accounts.bidder_account.to_account_info();
+    let owner_info = ctx.accounts.owner.to_account_info();
+    **account_info.try_borrow_mut_lamports()? -= amount;
+    **owner_info.try_borrow_mut_lamports()? += amount;
+
+    emit!(BidderAccountWithdrawn {
+        owner: ctx.accounts.owner.key(),
+        amount,
+        balance: ctx.accounts.bidder_account.balance,
+    });
+
+    Ok(())
+}
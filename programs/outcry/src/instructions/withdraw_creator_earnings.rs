@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::CreatorEarningsWithdrawn,
+    state::CreatorEarnings,
+};
+
+/// Creator pulls royalties credited at settlement. The PDA keeps its rent so
+/// future settlements can keep crediting it.
+#[derive(Accounts)]
+pub struct WithdrawCreatorEarnings<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EARNINGS_SEED, creator.key().as_ref()],
+        bump = creator_earnings.bump,
+        has_one = creator @ OutcryError::InvalidCreatorEarnings,
+    )]
+    pub creator_earnings: Account<'info, CreatorEarnings>,
+}
+
+pub fn handle_withdraw_creator_earnings(ctx: Context<WithdrawCreatorEarnings>) -> Result<()> {
+    let amount = ctx.accounts.creator_earnings.balance;
+    require!(amount > 0, OutcryError::NothingToWithdraw);
+
+    ctx.accounts.creator_earnings.balance = 0;
+
+    let earnings_info = ctx.accounts.creator_earnings.to_account_info();
+    let creator_info = ctx.accounts.creator.to_account_info();
+    **earnings_info.try_borrow_mut_lamports()? -= amount;
+    **creator_info.try_borrow_mut_lamports()? += amount;
+
+    emit!(CreatorEarningsWithdrawn {
+        creator: ctx.accounts.creator.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::OutcryError,
+    events::DepositWithdrawn,
+    state::{AuctionState, AuctionStatus, AuctionVault, BidderDeposit},
+};
+
+/// Bidder pulls back the part of their deposit above their current
+/// commitment while the auction is live. The leader must keep `current_bid`
+/// plus any buyer's premium; everyone else is uncommitted.
+///
+/// While delegated, the handler reads the state last committed from the ER,
+/// which can trail the live bids. The committed leader can't withdraw at all,
+/// and everyone else keeps `delegated_withdraw_floor` as a safety margin in
+/// case they took the lead since the last commit.
+#[derive(Accounts)]
+pub struct WithdrawDeposit<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// The auction this deposit is for. UncheckedAccount for the same reason as
+    /// `deposit`: while delegated, the L1 owner is the delegation program, and
+    /// the data is the state last committed from the ER.
+    /// CHECK: Implicitly validated via bidder_deposit and auction_vault seeds.
+    pub auction_state: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [DEPOSIT_SEED, auction_state.key().as_ref(), bidder.key().as_ref()],
+        bump = bidder_deposit.bump,
+    )]
+    pub bidder_deposit: Account<'info, BidderDeposit>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, auction_state.key().as_ref()],
+        bump = auction_vault.bump,
+    )]
+    pub auction_vault: Account<'info, AuctionVault>,
+}
+
+pub fn handle_withdraw_deposit(ctx: Context<WithdrawDeposit>, amount: u64) -> Result<()> {
+    require!(amount > 0, OutcryError::InvalidDepositAmount);
+
+    // Read the latest committed AuctionState, delegated or not
+    let delegated = ctx.accounts.auction_state.owner != &crate::ID;
+    let data = ctx.accounts.auction_state.try_borrow_data()?;
+    let auction = AuctionState::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(OutcryError::InvalidAuctionStatus))?;
+    drop(data);
+
+    require!(
+        auction.status == AuctionStatus::Created || auction.status == AuctionStatus::Active,
+        OutcryError::InvalidAuctionStatus
+    );
+
+    let bidder_key = ctx.accounts.bidder.key();
+    let leading = auction.bid_count > 0 && auction.highest_bidder == bidder_key;
+    let commitment = if delegated {
+        require!(!leading, OutcryError::LeaderWithdrawWhileDelegated);
+        auction.delegated_withdraw_floor()?
+    } else if leading {
+        auction.amount_due()?
+    } else {
+        0
+    };
+
+    let deposit = &mut ctx.accounts.bidder_deposit;
+    let withdrawable = deposit.amount.saturating_sub(commitment);
+    require!(amount <= withdrawable, OutcryError::WithdrawExceedsExcess);
+
+    deposit.amount -= amount;
+    let remaining = deposit.amount;
+
+    let vault_info = ctx.accounts.auction_vault.to_account_info();
+    let bidder_info = ctx.accounts.bidder.to_account_info();
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **bidder_info.try_borrow_mut_lamports()? += amount;
+
+    let vault = &mut ctx.accounts.auction_vault;
+    vault.record_refund(amount)?;
+    vault.assert_solvent(&vault_info)?;
+
+    emit!(DepositWithdrawn {
+        auction: ctx.accounts.auction_state.key(),
+        bidder: bidder_key,
+        amount,
+        remaining_deposit: remaining,
+    });
+
+    Ok(())
+}
@@ -5,9 +5,11 @@ pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod metadata;
 pub mod state;
 
 use instructions::*;
+use state::{BidGuards, SaleLotSummary};
 
 declare_id!("J7r5mzvVUjSNQteoqn6Hd3LjZ3ksmwoD5xsnUvMJwPZo");
 
@@ -16,26 +18,125 @@ declare_id!("J7r5mzvVUjSNQteoqn6Hd3LjZ3ksmwoD5xsnUvMJwPZo");
 pub mod outcry {
     use super::*;
 
-    pub fn create_auction(
-        ctx: Context<CreateAuction>,
-        reserve_price: u64,
-        duration_seconds: u64,
-        extension_seconds: u32,
-        extension_window: u32,
-        min_bid_increment: u64,
+    /// Creates a white-label auction house with its own fee, premium and
+    /// optional curator. Call on L1.
+    pub fn create_auction_house(
+        ctx: Context<CreateAuctionHouse>,
+        params: AuctionHouseParams,
+    ) -> Result<()> {
+        instructions::create_auction_house::handle_create_auction_house(ctx, params)
+    }
+
+    pub fn update_auction_house(
+        ctx: Context<UpdateAuctionHouse>,
+        params: AuctionHouseParams,
+    ) -> Result<()> {
+        instructions::update_auction_house::handle_update_auction_house(ctx, params)
+    }
+
+    /// Creates a sale event: a catalog of lots with a shared start and
+    /// staggered closing times. Call on L1.
+    pub fn create_sale_event(
+        ctx: Context<CreateSaleEvent>,
+        sale_id: u64,
+        start_time: i64,
+        stagger_seconds: u32,
     ) -> Result<()> {
-        instructions::create_auction::handle_create_auction(
+        instructions::create_sale_event::handle_create_sale_event(
             ctx,
-            reserve_price,
-            duration_seconds,
-            extension_seconds,
-            extension_window,
-            min_bid_increment,
+            sale_id,
+            start_time,
+            stagger_seconds,
         )
     }
 
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-        instructions::deposit::handle_deposit(ctx, amount)
+    /// Appends a Created auction to a sale's closing order. Call on L1
+    /// before the sale starts.
+    pub fn add_sale_lot(ctx: Context<AddSaleLot>) -> Result<()> {
+        instructions::add_sale_lot::handle_add_sale_lot(ctx)
+    }
+
+    pub fn create_auction(ctx: Context<CreateAuction>, params: CreateAuctionParams) -> Result<()> {
+        instructions::create_auction::handle_create_auction(ctx, params)
+    }
+
+    /// Replaces or removes (None) the allowlist root. Seller only, before
+    /// start. Bidders verified against the old root must deposit again with
+    /// a new proof.
+    pub fn update_allowlist_root(
+        ctx: Context<UpdateAllowlistRoot>,
+        allowlist_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::update_allowlist_root::handle_update_allowlist_root(ctx, allowlist_root)
+    }
+
+    /// Adds a wallet to the seller's blocklist, refused by deposit and every
+    /// bid path on all of the seller's auctions. Call on L1.
+    pub fn block_bidder(ctx: Context<BlockBidder>, wallet: Pubkey) -> Result<()> {
+        instructions::block_bidder::handle_block_bidder(ctx, wallet)
+    }
+
+    /// Removes a wallet from the seller's blocklist. Call on L1.
+    pub fn unblock_bidder(ctx: Context<UnblockBidder>, wallet: Pubkey) -> Result<()> {
+        instructions::unblock_bidder::handle_unblock_bidder(ctx, wallet)
+    }
+
+    /// Opens the caller's BidderProfile so wins build their reputation and
+    /// refunds count toward `auctions_participated`. Call on L1.
+    pub fn create_bidder_profile(ctx: Context<CreateBidderProfile>) -> Result<()> {
+        instructions::create_bidder_profile::handle_create_bidder_profile(ctx)
+    }
+
+    /// Opens the caller's SellerProfile so settled sales build their
+    /// history. Call on L1.
+    pub fn create_seller_profile(ctx: Context<CreateSellerProfile>) -> Result<()> {
+        instructions::create_seller_profile::handle_create_seller_profile(ctx)
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::deposit::handle_deposit(ctx, amount, proof)
+    }
+
+    /// Withdraws the part of a deposit above the bidder's current commitment
+    /// during a live auction. Call on L1 — reads the last committed state
+    /// when delegated.
+    pub fn withdraw_deposit(ctx: Context<WithdrawDeposit>, amount: u64) -> Result<()> {
+        instructions::withdraw_deposit::handle_withdraw_deposit(ctx, amount)
+    }
+
+    /// Tops up the wallet's cross-auction BidderAccount. Call on L1.
+    pub fn fund_bidder_account(ctx: Context<FundBidderAccount>, amount: u64) -> Result<()> {
+        instructions::fund_bidder_account::handle_fund_bidder_account(ctx, amount)
+    }
+
+    /// Withdraws from the BidderAccount's free (unlocked) balance.
+    pub fn withdraw_bidder_account(ctx: Context<WithdrawBidderAccount>, amount: u64) -> Result<()> {
+        instructions::withdraw_bidder_account::handle_withdraw_bidder_account(ctx, amount)
+    }
+
+    /// Closes an empty-of-locks BidderAccount, returning balance and rent to
+    /// the owner. Call on L1.
+    pub fn close_bidder_account(ctx: Context<CloseBidderAccount>) -> Result<()> {
+        instructions::close_bidder_account::handle_close_bidder_account(ctx)
+    }
+
+    /// Delegates the BidderAccount to the ER so bids there can lock its funds.
+    /// Call on L1.
+    pub fn delegate_bidder_account(ctx: Context<DelegateBidderAccount>) -> Result<()> {
+        instructions::delegate_bidder_account::handle_delegate_bidder_account(ctx)
+    }
+
+    /// Commits and undelegates the BidderAccount back to L1. Owner, or anyone
+    /// once the owner has won an auction they hold a lock on. Call on ER.
+    pub fn undelegate_bidder_account(ctx: Context<UndelegateBidderAccount>) -> Result<()> {
+        instructions::undelegate_bidder_account::handle_undelegate_bidder_account(ctx)
+    }
+
+    /// Releases a lock back to the BidderAccount balance once its owner is
+    /// outbid (or the auction was settled, cancelled or closed). Permissionless.
+    /// Call where the auction is live.
+    pub fn unlock_bid_funds(ctx: Context<UnlockBidFunds>) -> Result<()> {
+        instructions::unlock_bid_funds::handle_unlock_bid_funds(ctx)
     }
 
     /// Sets auction to Active with start/end times. Call on L1.
@@ -48,9 +149,31 @@ pub mod outcry {
         instructions::delegate_auction::handle_delegate_auction(ctx, nft_mint)
     }
 
+    /// Permissionless crank: activates a scheduled auction once its start time
+    /// has passed and delegates it to the Ephemeral Rollup. Call on L1.
+    pub fn start_scheduled_auction(
+        ctx: Context<StartScheduledAuction>,
+        seller: Pubkey,
+        nft_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::start_scheduled_auction::handle_start_scheduled_auction(ctx, seller, nft_mint)
+    }
+
     /// Places a bid. Call on ER (sub-50ms) when delegated, or L1 if not.
-    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
-        instructions::place_bid::handle_place_bid(ctx, amount)
+    /// Optional `guards` reject the bid if the price moved, it exceeds the
+    /// bidder's cap, or the transaction is stale.
+    pub fn place_bid(
+        ctx: Context<PlaceBid>,
+        amount: u64,
+        guards: Option<BidGuards>,
+    ) -> Result<()> {
+        instructions::place_bid::handle_place_bid(ctx, amount, guards)
+    }
+
+    /// Bids the minimum next increment, computed on-chain, up to
+    /// `guards.max_amount`. Call on ER when delegated, or L1 if not.
+    pub fn place_bid_next_increment(ctx: Context<PlaceBid>, guards: BidGuards) -> Result<()> {
+        instructions::place_bid::handle_place_bid_next_increment(ctx, guards)
     }
 
     /// Sets auction to Ended. Call on ER when delegated, or L1 if not.
@@ -58,15 +181,66 @@ pub mod outcry {
         instructions::end_auction::handle_end_auction(ctx)
     }
 
+    /// Permissionless crank for sale lots after the first: pushes the lot back
+    /// behind an extended predecessor, or ends it in order. Call on ER.
+    pub fn advance_sale_lot(ctx: Context<AdvanceSaleLot>) -> Result<()> {
+        instructions::advance_sale_lot::handle_advance_sale_lot(ctx)
+    }
+
     /// Commits state and undelegates AuctionState back to L1. Call on ER after end_auction.
     pub fn undelegate_auction(ctx: Context<UndelegateAuction>) -> Result<()> {
         instructions::undelegate_auction::handle_undelegate_auction(ctx)
     }
 
-    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+    /// Seller reveals a hidden reserve after end_auction. Below-reserve
+    /// auctions move to ReserveNotMet: NFT back to seller, refunds open.
+    pub fn reveal_reserve(
+        ctx: Context<RevealReserve>,
+        reserve_price: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_reserve::handle_reveal_reserve(ctx, reserve_price, salt)
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+    ) -> Result<()> {
         instructions::settle_auction::handle_settle_auction(ctx)
     }
 
+    /// Buyer confirms delivery of a physical item, releasing escrowed proceeds.
+    pub fn confirm_receipt<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfirmReceipt<'info>>,
+    ) -> Result<()> {
+        instructions::confirm_receipt::handle_confirm_receipt(ctx)
+    }
+
+    /// Buyer disputes delivery within the dispute window.
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        instructions::open_dispute::handle_open_dispute(ctx)
+    }
+
+    /// Arbiter resolves a dispute with a full or partial refund to the buyer.
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        refund_amount: u64,
+    ) -> Result<()> {
+        instructions::resolve_dispute::handle_resolve_dispute(ctx, refund_amount)
+    }
+
+    /// Permissionless crank: releases escrowed proceeds once the dispute
+    /// window passes without a dispute.
+    pub fn release_proceeds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleaseProceeds<'info>>,
+    ) -> Result<()> {
+        instructions::release_proceeds::handle_release_proceeds(ctx)
+    }
+
+    /// Creator withdraws royalties credited to their CreatorEarnings PDA.
+    pub fn withdraw_creator_earnings(ctx: Context<WithdrawCreatorEarnings>) -> Result<()> {
+        instructions::withdraw_creator_earnings::handle_withdraw_creator_earnings(ctx)
+    }
+
     pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
         instructions::claim_refund::handle_claim_refund(ctx)
     }
@@ -77,6 +251,15 @@ pub mod outcry {
         instructions::claim_refund_for::handle_claim_refund_for(ctx)
     }
 
+    /// Permissionless batch refund — (bidder, bidder_deposit, bidder_profile)
+    /// triples passed as remaining_accounts are refunded and closed in one
+    /// transaction.
+    pub fn claim_refunds_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRefundsBatch<'info>>,
+    ) -> Result<()> {
+        instructions::claim_refunds_batch::handle_claim_refunds_batch(ctx)
+    }
+
     pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
         instructions::cancel_auction::handle_cancel_auction(ctx)
     }
@@ -95,23 +278,130 @@ pub mod outcry {
     }
 
     /// Force-closes an auction after the 7-day grace period.
-    /// Drains any unclaimed deposits from the vault to the seller and closes
-    /// all accounts. Use when bidders haven't claimed refunds and the seller's
-    /// accounts are stuck.
+    /// Moves any unclaimed deposits into an UnclaimedFunds PDA (still
+    /// redeemable by bidders) and closes all accounts. Use when bidders
+    /// haven't claimed refunds and the seller's accounts are stuck.
     pub fn force_close_auction(ctx: Context<ForceCloseAuction>) -> Result<()> {
         instructions::force_close_auction::handle_force_close_auction(ctx)
     }
 
+    /// Redeems a deposit left in UnclaimedFunds after force_close_auction.
+    /// No deadline.
+    pub fn claim_unclaimed_refund(ctx: Context<ClaimUnclaimedRefund>) -> Result<()> {
+        instructions::claim_unclaimed_refund::handle_claim_unclaimed_refund(ctx)
+    }
+
     /// Creates a session token linking an ephemeral browser keypair to the
-    /// real wallet. One popup to authorize, then all bids are instant.
-    pub fn create_session(ctx: Context<CreateSession>, session_signer: Pubkey) -> Result<()> {
-        instructions::create_session::handle_create_session(ctx, session_signer)
+    /// real wallet. One popup to authorize, then all bids are instant until
+    /// `expires_at`, up to `max_bid_amount` per bid.
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_signer: Pubkey,
+        expires_at: i64,
+        max_bid_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::create_session::handle_create_session(
+            ctx,
+            session_signer,
+            expires_at,
+            max_bid_amount,
+            proof,
+        )
+    }
+
+    /// Disables a session key. Call on L1 — takes effect on the ER as the
+    /// cloned session token updates.
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session::handle_revoke_session(ctx)
+    }
+
+    /// Closes a session token and returns its rent to the real wallet.
+    pub fn close_session(ctx: Context<CloseSession>) -> Result<()> {
+        instructions::close_session::handle_close_session(ctx)
+    }
+
+    /// Creates a wallet-wide session usable across auctions (optionally an
+    /// allowlist of auctions or houses) with a shared bid-volume cap. Call on L1.
+    pub fn create_global_session(
+        ctx: Context<CreateGlobalSession>,
+        params: GlobalSessionParams,
+    ) -> Result<()> {
+        instructions::create_global_session::handle_create_global_session(ctx, params)
+    }
+
+    /// Delegates the GlobalSession to the Ephemeral Rollup so bids on
+    /// delegated auctions can draw down its bid volume. Call on L1.
+    pub fn delegate_global_session(ctx: Context<DelegateGlobalSession>) -> Result<()> {
+        instructions::delegate_global_session::handle_delegate_global_session(ctx)
+    }
+
+    /// Commits the GlobalSession and undelegates it back to L1. Call on ER.
+    pub fn undelegate_global_session(ctx: Context<UndelegateGlobalSession>) -> Result<()> {
+        instructions::undelegate_global_session::handle_undelegate_global_session(ctx)
+    }
+
+    /// Disables a global session key. Call on L1, or on ER while delegated.
+    pub fn revoke_global_session(ctx: Context<RevokeGlobalSession>) -> Result<()> {
+        instructions::revoke_global_session::handle_revoke_global_session(ctx)
+    }
+
+    /// Closes a global session and returns its rent. Call on L1.
+    pub fn close_global_session(ctx: Context<CloseGlobalSession>) -> Result<()> {
+        instructions::close_global_session::handle_close_global_session(ctx)
     }
 
     /// Places a bid using a session key (ephemeral signer). No wallet popup.
-    /// The session token resolves the real wallet for settlement + anti-shill.
-    pub fn place_bid_session(ctx: Context<PlaceBidSession>, amount: u64) -> Result<()> {
-        instructions::place_bid_session::handle_place_bid_session(ctx, amount)
+    /// Accepts a per-auction SessionToken or a GlobalSession, which resolves
+    /// the real wallet for settlement + anti-shill.
+    pub fn place_bid_session(
+        ctx: Context<PlaceBidSession>,
+        amount: u64,
+        guards: Option<BidGuards>,
+    ) -> Result<()> {
+        instructions::place_bid_session::handle_place_bid_session(ctx, amount, guards)
+    }
+
+    /// Session-key version of place_bid_next_increment.
+    pub fn place_bid_session_next_increment(
+        ctx: Context<PlaceBidSession>,
+        guards: BidGuards,
+    ) -> Result<()> {
+        instructions::place_bid_session::handle_place_bid_session_next_increment(ctx, guards)
+    }
+
+    /// Creates a bidder's BidNonce replay guard for signed bids. Call on L1.
+    pub fn create_bid_nonce(ctx: Context<CreateBidNonce>, bidder: Pubkey) -> Result<()> {
+        instructions::create_bid_nonce::handle_create_bid_nonce(ctx, bidder)
+    }
+
+    /// Delegates the bidder's BidNonce to the Ephemeral Rollup. Call on L1.
+    pub fn delegate_bid_nonce(ctx: Context<DelegateBidNonce>) -> Result<()> {
+        instructions::delegate_bid_nonce::handle_delegate_bid_nonce(ctx)
+    }
+
+    /// Commits the bidder's BidNonce and undelegates it back to L1. Call on ER.
+    pub fn undelegate_bid_nonce(ctx: Context<UndelegateBidNonce>) -> Result<()> {
+        instructions::undelegate_bid_nonce::handle_undelegate_bid_nonce(ctx)
+    }
+
+    /// Places a bid the bidder signed off-chain, submitted by any relayer.
+    /// Requires a preceding Ed25519 verify instruction over
+    /// (program id, auction, amount, nonce, expiry). Call on ER or L1.
+    pub fn place_signed_bid(
+        ctx: Context<PlaceSignedBid>,
+        bidder: Pubkey,
+        amount: u64,
+        nonce: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::place_signed_bid::handle_place_signed_bid(
+            ctx,
+            bidder,
+            amount,
+            nonce,
+            expires_at,
+        )
     }
 
     /// Emergency refund for deposits stuck in a delegated (ER-stuck) auction.
@@ -120,4 +410,22 @@ pub mod outcry {
     pub fn emergency_refund(ctx: Context<EmergencyRefund>) -> Result<()> {
         instructions::emergency_refund::handle_emergency_refund(ctx)
     }
+
+    /// Returns the minimum acceptable next bid via return data. Simulate on
+    /// ER (when delegated) or L1 — it never mutates state.
+    pub fn get_min_next_bid(ctx: Context<GetMinNextBid>) -> Result<u64> {
+        instructions::get_min_next_bid::handle_get_min_next_bid(ctx)
+    }
+
+    /// Read-only: fails if the vault holds less than rent plus the deposits
+    /// it still owes. Simulate on L1.
+    pub fn check_solvency(ctx: Context<CheckSolvency>) -> Result<()> {
+        instructions::check_solvency::handle_check_solvency(ctx)
+    }
+
+    /// Returns the state of every lot in a sale via return data. Pass the
+    /// lots as remaining_accounts in catalog order.
+    pub fn get_sale_catalog(ctx: Context<GetSaleCatalog>) -> Result<Vec<SaleLotSummary>> {
+        instructions::get_sale_catalog::handle_get_sale_catalog(ctx)
+    }
 }
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{constants::TOKEN_METADATA_PROGRAM_ID, errors::OutcryError};
+
+/// Metaplex `Key::MetadataV1` discriminator byte
+const METADATA_V1_KEY: u8 = 4;
+
+/// Parsed creator from Metaplex metadata
+pub struct MetaplexCreator {
+    pub address: Pubkey,
+    pub share: u8,
+}
+
+/// Reads a little-endian u32 length prefix at `offset`.
+fn read_len(data: &[u8], offset: usize) -> Result<usize> {
+    require!(offset + 4 <= data.len(), OutcryError::InvalidMetadata);
+    Ok(u32::from_le_bytes(
+        data[offset..offset + 4]
+            .try_into()
+            .map_err(|_| error!(OutcryError::InvalidMetadata))?,
+    ) as usize)
+}
+
+/// Skips the header and the name/symbol/uri strings, returning the offset of
+/// seller_fee_basis_points.
+fn skip_to_seller_fee(data: &[u8]) -> Result<usize> {
+    let mut offset: usize = 65; // skip key + update_authority + mint
+
+    // Skip 3 Borsh strings: name, symbol, uri
+    for _ in 0..3 {
+        let len = read_len(data, offset)?;
+        // Bounds check: ensure offset + 4 + len doesn't overflow or exceed data
+        let new_offset = offset
+            .checked_add(4)
+            .and_then(|o| o.checked_add(len))
+            .ok_or(error!(OutcryError::InvalidMetadata))?;
+        require!(new_offset <= data.len(), OutcryError::InvalidMetadata);
+        offset = new_offset;
+    }
+    Ok(offset)
+}
+
+/// Parse seller_fee_basis_points and creators from raw Metaplex metadata account data.
+///
+/// Binary layout (Borsh-serialized):
+///   key(1) + update_authority(32) + mint(32) = 65 bytes header
+///   name: String(4-byte len + bytes)
+///   symbol: String(4-byte len + bytes)
+///   uri: String(4-byte len + bytes)
+///   seller_fee_basis_points: u16
+///   creators: Option<Vec<Creator>>
+///     Creator = address(32) + verified(1) + share(1)
+pub fn parse_metadata_royalties(data: &[u8]) -> Result<(u16, Vec<MetaplexCreator>)> {
+    let mut offset = skip_to_seller_fee(data)?;
+
+    // Read seller_fee_basis_points (u16, little-endian)
+    require!(offset + 2 <= data.len(), OutcryError::InvalidMetadata);
+    let seller_fee_bps = u16::from_le_bytes(
+        data[offset..offset + 2]
+            .try_into()
+            .map_err(|_| error!(OutcryError::InvalidMetadata))?,
+    );
+    offset += 2;
+
+    // Read Option<Vec<Creator>>
+    require!(offset + 1 <= data.len(), OutcryError::InvalidMetadata);
+    let has_creators = data[offset] == 1;
+    offset += 1;
+
+    let mut creators = Vec::new();
+    if has_creators {
+        let count = read_len(data, offset)?;
+        offset += 4;
+
+        for _ in 0..count {
+            require!(offset + 34 <= data.len(), OutcryError::InvalidMetadata);
+            let address = Pubkey::try_from(&data[offset..offset + 32])
+                .map_err(|_| error!(OutcryError::InvalidMetadata))?;
+            let verified = data[offset + 32] == 1;
+            let share = data[offset + 33];
+            offset += 34;
+
+            // Only pay verified creators — enforces Metaplex royalty standards
+            if share > 0 && verified {
+                creators.push(MetaplexCreator { address, share });
+            }
+        }
+    }
+
+    Ok((seller_fee_bps, creators))
+}
+
+/// Parse the collection from raw Metaplex metadata account data, returning
+/// its key only if the collection is verified.
+///
+/// Continues past the creators:
+///   primary_sale_happened(1) + is_mutable(1)
+///   edition_nonce: Option<u8>
+///   token_standard: Option<u8>
+///   collection: Option<Collection>
+///     Collection = verified(1) + key(32)
+pub fn parse_metadata_collection(data: &[u8]) -> Result<Option<Pubkey>> {
+    let mut offset = skip_to_seller_fee(data)? + 2;
+
+    require!(offset < data.len(), OutcryError::InvalidMetadata);
+    let has_creators = data[offset] == 1;
+    offset += 1;
+    if has_creators {
+        let count = read_len(data, offset)?;
+        offset = count
+            .checked_mul(34)
+            .and_then(|n| n.checked_add(offset + 4))
+            .ok_or(error!(OutcryError::InvalidMetadata))?;
+    }
+
+    offset += 2; // primary_sale_happened + is_mutable
+    for _ in 0..2 {
+        // edition_nonce, token_standard: Option<u8>
+        require!(offset < data.len(), OutcryError::InvalidMetadata);
+        offset += if data[offset] == 1 { 2 } else { 1 };
+    }
+
+    require!(offset < data.len(), OutcryError::InvalidMetadata);
+    if data[offset] != 1 {
+        return Ok(None);
+    }
+    require!(offset + 34 <= data.len(), OutcryError::InvalidMetadata);
+    let verified = data[offset + 1] == 1;
+    let key = Pubkey::try_from(&data[offset + 2..offset + 34])
+        .map_err(|_| error!(OutcryError::InvalidMetadata))?;
+
+    Ok(verified.then_some(key))
+}
+
+/// Checks that `wallet` holds a token from the verified `collection` and
+/// returns the token's mint, which callers claim per auction.
+///
+/// Dispatches on the program owning `token_account`. Only SPL Token assets
+/// with Metaplex metadata are supported today; Token-2022 and Core assets
+/// slot in here as further branches — callers and the recorded eligibility
+/// marker don't change.
+pub fn verify_collection_holder(
+    wallet: &Pubkey,
+    collection: &Pubkey,
+    token_account: &AccountInfo,
+    metadata: &AccountInfo,
+) -> Result<Pubkey> {
+    require!(
+        token_account.owner == &anchor_spl::token::ID,
+        OutcryError::UnsupportedGateAsset
+    );
+
+    let token = TokenAccount::try_deserialize(&mut &token_account.try_borrow_data()?[..])?;
+    require!(
+        token.owner == *wallet && token.amount > 0,
+        OutcryError::NotCollectionHolder
+    );
+
+    let (metadata_key, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            token.mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    require_keys_eq!(metadata.key(), metadata_key, OutcryError::InvalidMetadata);
+    require!(
+        metadata.owner == &TOKEN_METADATA_PROGRAM_ID,
+        OutcryError::InvalidMetadata
+    );
+
+    let data = metadata.try_borrow_data()?;
+    require!(
+        data.first() == Some(&METADATA_V1_KEY),
+        OutcryError::InvalidMetadata
+    );
+    require!(
+        parse_metadata_collection(&data)? == Some(*collection),
+        OutcryError::NotCollectionHolder
+    );
+
+    Ok(token.mint)
+}
@@ -1,13 +1,31 @@
 use anchor_lang::prelude::*;
 
+use solana_sha256_hasher::hashv;
+
+use crate::constants::{
+    MAX_ALLOWLIST_PROOF_LEN, MAX_INCREMENT_TIERS, MAX_PAYOUT_SPLITS, MAX_SESSION_TARGETS,
+    RECENT_BIDDER_SLOTS, RESERVE_REVEAL_PERIOD,
+};
+use crate::errors::OutcryError;
+use crate::state::{check_bidder_standing, BidderAccount};
+
 #[account]
 pub struct AuctionState {
     /// The seller / artist who created this auction
     pub seller: Pubkey,
     /// The NFT mint being auctioned
     pub nft_mint: Pubkey,
-    /// Minimum acceptable first bid (lamports)
+    /// AuctionHouse this listing belongs to, Pubkey::default() if none
+    pub house: Pubkey,
+    /// House fee snapshotted at creation (bps)
+    pub house_fee_bps: u16,
+    /// Minimum acceptable first bid (lamports). With a hidden reserve this is
+    /// only the opening price.
     pub reserve_price: u64,
+    /// sha256(reserve_le_bytes || salt) of a hidden reserve; all zeros if none
+    pub reserve_commitment: [u8; 32],
+    /// Set once the seller reveals a hidden reserve that the top bid meets
+    pub reserve_revealed: bool,
     /// Auction duration in seconds (set at creation, used by start_auction)
     pub duration_seconds: u64,
     /// Current highest bid amount (lamports), 0 if no bids
@@ -18,12 +36,55 @@ pub struct AuctionState {
     pub start_time: i64,
     /// Unix timestamp when auction ends (extended on anti-snipe)
     pub end_time: i64,
+    /// Unix timestamp after which anyone may start the auction (0 = seller starts manually)
+    pub scheduled_start: i64,
     /// Seconds to extend when anti-snipe triggers
     pub extension_seconds: u32,
     /// Window before end_time that triggers anti-snipe extension
     pub extension_window: u32,
-    /// Minimum increment over current_bid for a new bid
+    /// Minimum increment over current_bid for a new bid (floor for every rule)
     pub min_bid_increment: u64,
+    /// How the required increment scales with current_bid
+    pub bid_increment: BidIncrement,
+    /// How late bids move end_time
+    pub anti_snipe_policy: AntiSnipePolicy,
+    /// Cap on total extension beyond the original end time (seconds)
+    pub max_extension_seconds: u32,
+    /// Number of times anti-snipe has extended end_time
+    pub extension_count: u16,
+    /// Premium charged to the winner on top of the hammer price (bps)
+    pub buyer_premium_bps: u16,
+    /// SaleEvent this lot belongs to, Pubkey::default() if standalone
+    pub sale_event: Pubkey,
+    /// Position in the sale's closing order (0 for standalone auctions)
+    pub lot_index: u16,
+    /// Sale's gap between consecutive lot closes, copied when the lot is added
+    pub sale_stagger_seconds: u32,
+    /// Consignment split of the seller's proceeds; unused slots have
+    /// `bps == 0`. All empty = everything to the seller.
+    pub payout_splits: [PayoutSplit; MAX_PAYOUT_SPLITS],
+    /// Arbiter for escrowed payouts; Pubkey::default() = pay seller at settlement
+    pub payout_arbiter: Pubkey,
+    /// Buyer's dispute window after settlement for escrowed payouts (seconds)
+    pub dispute_window_seconds: u32,
+    /// Merkle root of wallets allowed to bid; all zeros = open to everyone
+    pub allowlist_root: [u8; 32],
+    /// Verified Metaplex collection bidders must hold a token from;
+    /// Pubkey::default() = no collection gate
+    pub collection_gate: Pubkey,
+    /// Reject wallets banned by the protocol default history
+    pub ban_defaulters: bool,
+    /// Minimum BidderProfile reputation to deposit or bid (None = no minimum)
+    pub min_reputation: Option<u32>,
+    /// Most forfeits a bidder's profile may show (None = no limit)
+    pub max_defaults: Option<u32>,
+    /// Seconds a bidder must wait between their own bids (0 = no limit)
+    pub min_bid_interval: u32,
+    /// Reject bids from the wallet already holding the top bid
+    pub block_self_outbid: bool,
+    /// Last bid time of recent bidders, for `min_bid_interval`. Oldest entry
+    /// is overwritten when full.
+    pub recent_bidders: [RecentBid; RECENT_BIDDER_SLOTS],
     /// Auction lifecycle status
     pub status: AuctionStatus,
     /// Total number of bids placed
@@ -37,18 +98,440 @@ impl AuctionState {
     pub const SPACE: usize = 8   // discriminator
         + 32   // seller
         + 32   // nft_mint
+        + 32   // house
+        + 2    // house_fee_bps
         + 8    // reserve_price
+        + 32   // reserve_commitment
+        + 1    // reserve_revealed
         + 8    // duration_seconds
         + 8    // current_bid
         + 32   // highest_bidder
         + 8    // start_time
         + 8    // end_time
+        + 8    // scheduled_start
         + 4    // extension_seconds
         + 4    // extension_window
         + 8    // min_bid_increment
+        + 1 + 16 * MAX_INCREMENT_TIERS // bid_increment (tag + largest variant)
+        + 3    // anti_snipe_policy (tag + u16)
+        + 4    // max_extension_seconds
+        + 2    // extension_count
+        + 2    // buyer_premium_bps
+        + 32   // sale_event
+        + 2    // lot_index
+        + 4    // sale_stagger_seconds
+        + 34 * MAX_PAYOUT_SPLITS // payout_splits
+        + 32   // payout_arbiter
+        + 4    // dispute_window_seconds
+        + 32   // allowlist_root
+        + 32   // collection_gate
+        + 1    // ban_defaulters
+        + 5    // min_reputation (Option<u32>)
+        + 5    // max_defaults (Option<u32>)
+        + 4    // min_bid_interval
+        + 1    // block_self_outbid
+        + 40 * RECENT_BIDDER_SLOTS // recent_bidders
         + 1    // status
         + 4    // bid_count
         + 1;   // bump
+
+    /// Active rows of the payout split table
+    pub fn active_payout_splits(&self) -> &[PayoutSplit] {
+        let count = self.payout_splits.iter().take_while(|s| s.bps > 0).count();
+        &self.payout_splits[..count]
+    }
+
+    pub fn has_allowlist(&self) -> bool {
+        self.allowlist_root != [0u8; 32]
+    }
+
+    /// Verifies a Merkle proof that `wallet` is on the allowlist. Leaves are
+    /// sha256(0x00 || wallet); parents are sha256(0x01 || lo || hi) with the
+    /// children sorted, so proofs need no left/right flags.
+    pub fn verify_allowlist_proof(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        if proof.len() > MAX_ALLOWLIST_PROOF_LEN {
+            return false;
+        }
+        let mut node = hashv(&[&[0u8], wallet.as_ref()]).to_bytes();
+        for sibling in proof {
+            let (lo, hi) = if node <= *sibling {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            node = hashv(&[&[1u8], &lo, &hi]).to_bytes();
+        }
+        node == self.allowlist_root
+    }
+
+    /// Bid-path check for allowlisted auctions: the bidder's deposit must
+    /// carry the marker set when `deposit` verified their proof against the
+    /// current root. Cheap enough for the ER, which clones the deposit
+    /// read-only from L1.
+    pub fn check_allowlist_marker(
+        &self,
+        deposit: Option<&BidderDeposit>,
+        bidder: &Pubkey,
+    ) -> Result<()> {
+        if !self.has_allowlist() {
+            return Ok(());
+        }
+        let deposit = deposit.ok_or(OutcryError::NotAllowlisted)?;
+        require!(
+            deposit.bidder == *bidder && deposit.verified_root == self.allowlist_root,
+            OutcryError::NotAllowlisted
+        );
+        Ok(())
+    }
+
+    pub fn has_collection_gate(&self) -> bool {
+        self.collection_gate != Pubkey::default()
+    }
+
+    /// Bid-path check for collection-gated auctions: eligibility is recorded
+    /// on L1 when the bidder proves holdership, on their deposit or on the
+    /// per-auction session token.
+    pub fn check_collection_marker(
+        &self,
+        deposit: Option<&BidderDeposit>,
+        session: Option<&SessionToken>,
+        bidder: &Pubkey,
+    ) -> Result<()> {
+        if !self.has_collection_gate() {
+            return Ok(());
+        }
+        let by_deposit = deposit.is_some_and(|d| {
+            d.bidder == *bidder && d.verified_collection == self.collection_gate
+        });
+        let by_session = session.is_some_and(|s| {
+            s.bidder == *bidder && s.verified_collection == self.collection_gate
+        });
+        require!(by_deposit || by_session, OutcryError::NotCollectionHolder);
+        Ok(())
+    }
+
+    /// Shared bid checks and state update for place_bid, place_bid_session
+    /// and place_signed_bid, in one fixed order: intent guards first so a
+    /// raced bid reports what actually moved, then the bidder's standing and
+    /// gate markers, the timer, the amount and the rate rules, then the bid
+    /// is recorded and its funding locked. Path-specific checks (signatures,
+    /// session limits) run before this. Returns the previous bid and whether
+    /// `end_time` moved.
+    pub fn validate_and_apply_bid(
+        &mut self,
+        bidder: &Pubkey,
+        amount: u64,
+        guards: Option<BidGuards>,
+        eligibility: BidEligibility,
+        funding: BidFunding,
+        now: i64,
+    ) -> Result<(u64, bool)> {
+        if let Some(guards) = guards {
+            guards.check(self, amount, now)?;
+        }
+
+        require!(self.seller != *bidder, OutcryError::SellerCannotBid);
+        check_bidder_standing(
+            self,
+            eligibility.blocklist,
+            eligibility.default_record,
+            eligibility.bidder_profile,
+            bidder,
+        )?;
+        self.check_allowlist_marker(eligibility.deposit, bidder)?;
+        self.check_collection_marker(eligibility.deposit, eligibility.session, bidder)?;
+
+        // A later sale lot can't close before the lot ahead of it
+        let rescheduled = self.sync_sale_close(eligibility.previous_lot, now)?;
+
+        // Verify auction hasn't expired
+        require!(now < self.end_time, OutcryError::AuctionEnded);
+
+        // Validate bid amount — first bid must meet reserve, subsequent bids
+        // must exceed current + the increment rule
+        let min_bid = self.min_next_bid()?;
+        if self.bid_count == 0 {
+            require!(amount >= min_bid, OutcryError::BelowReserve);
+        } else {
+            require!(amount >= min_bid, OutcryError::BidTooLow);
+        }
+
+        self.apply_bid_rate_limit(bidder, now)?;
+
+        // NOTE: Deposit validation is deferred to settle_auction on L1.
+        // The ER only tracks bid state — actual SOL lives in the L1 vault.
+
+        let previous_bid = self.current_bid;
+        let previous_leader = self.highest_bidder;
+        self.current_bid = amount;
+        self.highest_bidder = *bidder;
+        self.bid_count = self
+            .bid_count
+            .checked_add(1)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+
+        // A BidderAccount backing the bid locks what it would owe, and the
+        // outbid leader's lock goes back to their balance
+        if let Some(account) = funding.bidder_account {
+            require!(account.owner == *bidder, OutcryError::InvalidBidderAccount);
+            account.set_lock(&funding.auction, self.amount_due()?)?;
+        }
+        if let Some(account) = funding.outbid_account {
+            require!(
+                account.owner == previous_leader && previous_leader != *bidder,
+                OutcryError::InvalidBidderAccount
+            );
+            account.release(&funding.auction)?;
+        }
+
+        // Anti-snipe: extend per the auction's policy if bid arrives within
+        // extension_window of end.
+        let extended = self.apply_anti_snipe(now)?;
+
+        Ok((previous_bid, rescheduled || extended))
+    }
+
+    pub fn is_later_sale_lot(&self) -> bool {
+        self.sale_event != Pubkey::default() && self.lot_index > 0
+    }
+
+    /// Whether this lot has stopped taking bids for good, so the next lot in
+    /// its sale can settle its own close. A lot cancelled before it ran
+    /// counts, otherwise it would hold up the rest of the catalog.
+    pub fn sale_lot_closed(&self) -> bool {
+        matches!(
+            self.status,
+            AuctionStatus::Ended
+                | AuctionStatus::Settled
+                | AuctionStatus::ReserveNotMet
+                | AuctionStatus::Cancelled
+        )
+    }
+
+    /// Goes live at `now`. Sale lots are anchored to the sale's shared
+    /// `scheduled_start` instead, so starting them late or out of order
+    /// can't break the stagger `add_sale_lot` set up.
+    pub fn activate(&mut self, now: i64) -> Result<()> {
+        self.start_time = if self.sale_event != Pubkey::default() {
+            self.scheduled_start
+        } else {
+            now
+        };
+        self.end_time = self
+            .start_time
+            .checked_add(self.duration_seconds as i64)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.status = AuctionStatus::Active;
+        Ok(())
+    }
+
+    /// Keys a later sale lot's close to its predecessor: never sooner than
+    /// `sale_stagger_seconds` after the previous lot closes. Until that lot
+    /// has closed its end is still open, so it counts as closing no earlier
+    /// than `now`. Pushes `end_time` (and `duration_seconds`, so the
+    /// anti-snipe cap moves with it) and returns whether it moved. No-op for
+    /// standalone auctions and the first lot.
+    pub fn sync_sale_close(&mut self, previous: Option<&AuctionState>, now: i64) -> Result<bool> {
+        if !self.is_later_sale_lot() {
+            return Ok(false);
+        }
+        let previous = previous.ok_or(OutcryError::InvalidSaleLot)?;
+        require!(
+            previous.sale_event == self.sale_event && previous.lot_index + 1 == self.lot_index,
+            OutcryError::InvalidSaleLot
+        );
+
+        let previous_close = if previous.sale_lot_closed() {
+            previous.end_time
+        } else {
+            previous.end_time.max(now)
+        };
+        let close_after = previous_close
+            .checked_add(self.sale_stagger_seconds as i64)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        if close_after <= self.end_time {
+            return Ok(false);
+        }
+
+        let delay = close_after
+            .checked_sub(self.end_time)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.duration_seconds = self
+            .duration_seconds
+            .checked_add(delay as u64)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.end_time = close_after;
+        Ok(true)
+    }
+
+    /// Per-bidder rate rules, checked on every bid path before the bid
+    /// lands. Records the bid time when an interval is set.
+    pub fn apply_bid_rate_limit(&mut self, bidder: &Pubkey, now: i64) -> Result<()> {
+        if self.block_self_outbid {
+            require!(
+                self.bid_count == 0 || self.highest_bidder != *bidder,
+                OutcryError::SelfOutbid
+            );
+        }
+        if self.min_bid_interval == 0 {
+            return Ok(());
+        }
+
+        let slot = match self.recent_bidders.iter().position(|r| r.bidder == *bidder) {
+            Some(i) => {
+                let since = now
+                    .checked_sub(self.recent_bidders[i].at)
+                    .ok_or(OutcryError::ArithmeticOverflow)?;
+                require!(
+                    since >= self.min_bid_interval as i64,
+                    OutcryError::BidRateLimited
+                );
+                i
+            }
+            None => self
+                .recent_bidders
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, r)| r.at)
+                .map(|(i, _)| i)
+                .unwrap_or(0),
+        };
+        self.recent_bidders[slot] = RecentBid {
+            bidder: *bidder,
+            at: now,
+        };
+        Ok(())
+    }
+
+    pub fn has_hidden_reserve(&self) -> bool {
+        self.reserve_commitment != [0u8; 32]
+    }
+
+    /// Whether settlement may proceed as a sale. A hidden reserve clears when
+    /// revealed and met, or when the seller let the reveal window lapse — an
+    /// unrevealed reserve is forfeited in the bidders' favour.
+    pub fn reserve_cleared(&self, now: i64) -> Result<bool> {
+        if !self.has_hidden_reserve() || self.reserve_revealed {
+            return Ok(true);
+        }
+        let reveal_deadline = self
+            .end_time
+            .checked_add(RESERVE_REVEAL_PERIOD)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(now >= reveal_deadline)
+    }
+
+    /// Buyer's premium owed on the current (hammer) bid
+    pub fn buyer_premium(&self) -> Result<u64> {
+        Ok((self.current_bid as u128)
+            .checked_mul(self.buyer_premium_bps as u128)
+            .ok_or(OutcryError::ArithmeticOverflow)?
+            .checked_div(10_000)
+            .ok_or(OutcryError::ArithmeticOverflow)? as u64)
+    }
+
+    /// Total the winner must have deposited: hammer price plus premium
+    pub fn amount_due(&self) -> Result<u64> {
+        self.current_bid
+            .checked_add(self.buyer_premium()?)
+            .ok_or(error!(OutcryError::ArithmeticOverflow))
+    }
+
+    /// Deposit a bidder who doesn't lead the committed copy must keep while
+    /// the auction is delegated. The L1 data can lag the ER by a few commits,
+    /// so the bidder may already lead there with any bid from `min_next_bid`
+    /// up; the floor is that lowest possible bid plus its premium.
+    pub fn delegated_withdraw_floor(&self) -> Result<u64> {
+        let min_bid = self.min_next_bid()?;
+        let premium = (min_bid as u128)
+            .checked_mul(self.buyer_premium_bps as u128)
+            .ok_or(OutcryError::ArithmeticOverflow)?
+            .checked_div(10_000)
+            .ok_or(OutcryError::ArithmeticOverflow)? as u64;
+        min_bid
+            .checked_add(premium)
+            .ok_or(error!(OutcryError::ArithmeticOverflow))
+    }
+
+    /// Lowest amount the next bid may be: the reserve for the first bid,
+    /// otherwise current_bid plus the increment required by `bid_increment`.
+    pub fn min_next_bid(&self) -> Result<u64> {
+        if self.bid_count == 0 {
+            return Ok(self.reserve_price);
+        }
+        let increment = match self.bid_increment {
+            BidIncrement::Flat => self.min_bid_increment,
+            BidIncrement::Percentage { bps } => (self.current_bid as u128)
+                .checked_mul(bps as u128)
+                .ok_or(OutcryError::ArithmeticOverflow)?
+                .checked_div(10_000)
+                .ok_or(OutcryError::ArithmeticOverflow)? as u64,
+            BidIncrement::Tiered { tiers } => {
+                let active = tiers.iter().take_while(|t| t.below > 0);
+                let mut increment = 0;
+                for tier in active {
+                    increment = tier.increment;
+                    if self.current_bid < tier.below {
+                        break;
+                    }
+                }
+                increment
+            }
+        };
+        self.current_bid
+            .checked_add(increment.max(self.min_bid_increment))
+            .ok_or(error!(OutcryError::ArithmeticOverflow))
+    }
+
+    /// Applies the anti-snipe policy for a bid landing at `now`.
+    /// Returns true if end_time was extended.
+    pub fn apply_anti_snipe(&mut self, now: i64) -> Result<bool> {
+        let time_remaining = self
+            .end_time
+            .checked_sub(now)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        if time_remaining >= self.extension_window as i64 {
+            return Ok(false);
+        }
+
+        let proposed_end = match self.anti_snipe_policy {
+            AntiSnipePolicy::Disabled => return Ok(false),
+            AntiSnipePolicy::FixedAdd => self
+                .end_time
+                .checked_add(self.extension_seconds as i64)
+                .ok_or(OutcryError::ArithmeticOverflow)?,
+            AntiSnipePolicy::ResetToWindow => now
+                .checked_add(self.extension_window as i64)
+                .ok_or(OutcryError::ArithmeticOverflow)?,
+            AntiSnipePolicy::MaxExtensions { max_extensions } => {
+                if self.extension_count >= max_extensions {
+                    return Ok(false);
+                }
+                self.end_time
+                    .checked_add(self.extension_seconds as i64)
+                    .ok_or(OutcryError::ArithmeticOverflow)?
+            }
+        };
+
+        // Never extend past original end + the per-auction cap
+        let max_end_time = self
+            .start_time
+            .checked_add(self.duration_seconds as i64)
+            .ok_or(OutcryError::ArithmeticOverflow)?
+            .checked_add(self.max_extension_seconds as i64)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        let new_end = proposed_end.min(max_end_time);
+        if new_end <= self.end_time {
+            return Ok(false);
+        }
+
+        self.end_time = new_end;
+        self.extension_count = self
+            .extension_count
+            .checked_add(1)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(true)
+    }
 }
 
 #[account]
@@ -58,6 +541,84 @@ pub struct AuctionVault {
     pub auction: Pubkey,
     /// PDA bump seed
     pub bump: u8,
+    /// Lifetime lamports deposited by bidders
+    pub total_deposited: u64,
+    /// Lifetime lamports returned to bidders (refunds, unclaimed-funds handover)
+    pub total_refunded: u64,
+    /// Lifetime lamports paid out of deposits (settlement, forfeit penalty)
+    pub total_paid_out: u64,
+    /// Seller's listing bond held alongside the deposits; 0 once slashed.
+    /// Returned with the vault rent when the auction closes.
+    pub seller_bond: u64,
+}
+
+impl AuctionVault {
+    /// Lamports the vault still owes to depositors
+    pub fn liabilities(&self) -> Result<u64> {
+        self.total_deposited
+            .checked_sub(self.total_refunded)
+            .and_then(|v| v.checked_sub(self.total_paid_out))
+            .ok_or(error!(OutcryError::VaultInsolvent))
+    }
+
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.total_refunded = self
+            .total_refunded
+            .checked_add(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid_out = self
+            .total_paid_out
+            .checked_add(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Lamports above rent and the seller bond — what deposits can draw on
+    pub fn spendable(&self, vault_info: &AccountInfo) -> Result<u64> {
+        let rent = Rent::get()?.minimum_balance(vault_info.data_len());
+        Ok(vault_info
+            .lamports()
+            .saturating_sub(rent)
+            .saturating_sub(self.seller_bond))
+    }
+
+    /// Moves the whole seller bond to `recipient`. Returns the amount slashed.
+    pub fn slash_bond(&mut self, vault_info: &AccountInfo, recipient: &AccountInfo) -> Result<u64> {
+        let amount = self.seller_bond;
+        if amount > 0 {
+            **vault_info.try_borrow_mut_lamports()? -= amount;
+            **recipient.try_borrow_mut_lamports()? += amount;
+            self.seller_bond = 0;
+        }
+        Ok(amount)
+    }
+
+    /// Solvency invariant: vault lamports >= rent + outstanding liabilities
+    /// + the seller bond still held
+    pub fn assert_solvent(&self, vault_info: &AccountInfo) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(vault_info.data_len());
+        let required = rent
+            .checked_add(self.liabilities()?)
+            .and_then(|v| v.checked_add(self.seller_bond))
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        require!(
+            vault_info.lamports() >= required,
+            OutcryError::VaultInsolvent
+        );
+        Ok(())
+    }
 }
 
 /// Per-bidder deposit tracking — lives on L1, never delegated.
@@ -71,6 +632,12 @@ pub struct BidderDeposit {
     pub bidder: Pubkey,
     /// Total deposited amount (lamports)
     pub amount: u64,
+    /// Allowlist root this bidder's proof was verified against (all zeros
+    /// if never verified) — the ER-side allowlist marker
+    pub verified_root: [u8; 32],
+    /// Gate collection this bidder proved holdership of (default if never
+    /// proven) — the ER-side collection-gate marker
+    pub verified_collection: Pubkey,
     /// PDA bump seed
     pub bump: u8,
 }
@@ -87,6 +654,204 @@ pub enum AuctionStatus {
     Settled,
     /// Seller cancelled (no bids placed)
     Cancelled,
+    /// Hidden reserve revealed above the top bid — NFT returned, refunds open
+    ReserveNotMet,
+}
+
+/// Anti-snipe behaviour when a bid lands within `extension_window` of the end.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AntiSnipePolicy {
+    /// Late bids never move end_time
+    Disabled,
+    /// end_time += extension_seconds
+    FixedAdd,
+    /// end_time = now + extension_window
+    ResetToWindow,
+    /// end_time += extension_seconds, at most `max_extensions` times
+    MaxExtensions { max_extensions: u16 },
+}
+
+/// A bidder's most recent bid time, one slot of `AuctionState::recent_bidders`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub struct RecentBid {
+    pub bidder: Pubkey,
+    pub at: i64,
+}
+
+/// One row of a tiered increment table: bids below `below` lamports must
+/// rise by at least `increment`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub struct IncrementTier {
+    pub below: u64,
+    pub increment: u64,
+}
+
+/// Optional client-side intent checks for a bid, so a bid that raced
+/// another one fails instead of landing at a price the bidder never saw.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BidGuards {
+    /// Fail if current_bid has moved from what the bidder saw
+    pub expected_current_bid: Option<u64>,
+    /// Most the bidder will pay — required for next-increment bids
+    pub max_amount: Option<u64>,
+    /// Reject the transaction after this Unix timestamp
+    pub valid_until: Option<i64>,
+}
+
+impl BidGuards {
+    pub fn check(&self, auction: &AuctionState, amount: u64, now: i64) -> Result<()> {
+        if let Some(valid_until) = self.valid_until {
+            require!(now <= valid_until, OutcryError::BidIntentExpired);
+        }
+        if let Some(expected) = self.expected_current_bid {
+            require!(auction.current_bid == expected, OutcryError::BidStateChanged);
+        }
+        if let Some(max_amount) = self.max_amount {
+            require!(amount <= max_amount, OutcryError::BidAboveMax);
+        }
+        Ok(())
+    }
+}
+
+/// Accounts the shared bid checks read. The standing accounts must already
+/// be validated as the bidder's / seller's PDAs; any of them may not exist.
+pub struct BidEligibility<'a, 'info> {
+    pub blocklist: &'a AccountInfo<'info>,
+    pub default_record: &'a AccountInfo<'info>,
+    pub bidder_profile: &'a AccountInfo<'info>,
+    /// Bidder's deposit, carrying the allowlist / collection markers
+    pub deposit: Option<&'a BidderDeposit>,
+    /// Per-auction session token, which can also carry the collection marker
+    pub session: Option<&'a SessionToken>,
+    /// Previous lot in the sale — required for sale lots after the first
+    pub previous_lot: Option<&'a AuctionState>,
+}
+
+/// BidderAccounts a bid moves funds in. Both optional: a bid can still be
+/// backed by a BidderDeposit, and an outbid leader's lock can be released
+/// later with unlock_bid_funds.
+pub struct BidFunding<'a> {
+    /// The auction being bid on
+    pub auction: Pubkey,
+    /// Bidder's account — the amount due on this bid is locked from it
+    pub bidder_account: Option<&'a mut BidderAccount>,
+    /// Outbid leader's account — their lock on this auction is released
+    pub outbid_account: Option<&'a mut BidderAccount>,
+}
+
+/// Increment rule applied on top of the flat `min_bid_increment` floor.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BidIncrement {
+    /// Always min_bid_increment
+    Flat,
+    /// Basis points of current_bid
+    Percentage { bps: u16 },
+    /// Ascending tiers; unused slots have `below == 0`. Bids above the last
+    /// tier keep using its increment.
+    Tiered { tiers: [IncrementTier; MAX_INCREMENT_TIERS] },
+}
+
+impl BidIncrement {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            BidIncrement::Flat => {}
+            BidIncrement::Percentage { bps } => {
+                require!(*bps > 0 && *bps <= 10_000, OutcryError::InvalidBidIncrement);
+            }
+            BidIncrement::Tiered { tiers } => {
+                let count = tiers.iter().take_while(|t| t.below > 0).count();
+                require!(count > 0, OutcryError::InvalidBidIncrement);
+                // Unused slots must all be trailing
+                require!(
+                    tiers[count..].iter().all(|t| *t == IncrementTier::default()),
+                    OutcryError::InvalidBidIncrement
+                );
+                for (i, tier) in tiers[..count].iter().enumerate() {
+                    require!(tier.increment > 0, OutcryError::InvalidBidIncrement);
+                    if i > 0 {
+                        require!(tier.below > tiers[i - 1].below, OutcryError::InvalidBidIncrement);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One recipient of the seller's proceeds and their share (bps of the
+/// amount left after fees and royalties).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub struct PayoutSplit {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+impl PayoutSplit {
+    /// Validates a split table from create_auction and packs it into the
+    /// fixed-size form stored on AuctionState.
+    pub fn pack(splits: &[PayoutSplit]) -> Result<[PayoutSplit; MAX_PAYOUT_SPLITS]> {
+        let mut packed = [PayoutSplit::default(); MAX_PAYOUT_SPLITS];
+        if splits.is_empty() {
+            return Ok(packed);
+        }
+        require!(splits.len() <= MAX_PAYOUT_SPLITS, OutcryError::InvalidPayoutSplit);
+
+        let mut total: u32 = 0;
+        for (slot, split) in packed.iter_mut().zip(splits) {
+            require!(split.bps > 0, OutcryError::InvalidPayoutSplit);
+            total += split.bps as u32;
+            *slot = *split;
+        }
+        require!(total == 10_000, OutcryError::InvalidPayoutSplit);
+        Ok(packed)
+    }
+
+    /// Moves `amount` of seller proceeds out of `from`: all to `seller` when
+    /// there is no split table, otherwise across `recipients` (in table
+    /// order), with the last recipient taking the rounding remainder.
+    pub fn distribute<'info>(
+        splits: &[PayoutSplit],
+        amount: u64,
+        from: &AccountInfo<'info>,
+        seller: &AccountInfo<'info>,
+        recipients: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        if splits.is_empty() {
+            **from.try_borrow_mut_lamports()? -= amount;
+            **seller.try_borrow_mut_lamports()? += amount;
+            return Ok(());
+        }
+
+        require!(
+            recipients.len() >= splits.len(),
+            OutcryError::MissingPayoutAccount
+        );
+
+        let mut paid: u64 = 0;
+        for (i, (split, recipient)) in splits.iter().zip(recipients).enumerate() {
+            require!(
+                recipient.key() == split.recipient,
+                OutcryError::MissingPayoutAccount
+            );
+
+            let share = if i + 1 == splits.len() {
+                amount - paid
+            } else {
+                (amount as u128)
+                    .checked_mul(split.bps as u128)
+                    .ok_or(OutcryError::ArithmeticOverflow)?
+                    .checked_div(10_000)
+                    .ok_or(OutcryError::ArithmeticOverflow)? as u64
+            };
+
+            if share > 0 {
+                **from.try_borrow_mut_lamports()? -= share;
+                **recipient.try_borrow_mut_lamports()? += share;
+                paid += share;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Session key token — links an ephemeral browser keypair to a real wallet.
@@ -102,6 +867,79 @@ pub struct SessionToken {
     pub session_signer: Pubkey,
     /// When this session was created (informational)
     pub created_at: i64,
+    /// Unix timestamp after which the session can no longer bid
+    pub expires_at: i64,
+    /// Largest bid (lamports) the session key may place
+    pub max_bid_amount: u64,
+    /// Set by revoke_session; cleared when create_session re-authorizes
+    pub revoked: bool,
+    /// Gate collection the bidder proved holdership of at create_session
+    pub verified_collection: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Wallet-wide session key usable in any auction (or an allowlisted subset),
+/// with one bid-volume cap shared across them. Delegate it to the same ER as
+/// the auctions so bids there can draw down the cap.
+/// Seeds: [b"session", bidder.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct GlobalSession {
+    /// The REAL wallet (used for settlement, deposit matching, anti-shill)
+    pub bidder: Pubkey,
+    /// The ephemeral browser keypair that can sign bids without wallet popups
+    pub session_signer: Pubkey,
+    /// When this session was created (informational)
+    pub created_at: i64,
+    /// Unix timestamp after which the session can no longer bid
+    pub expires_at: i64,
+    /// Largest single bid (lamports) the session key may place
+    pub max_bid_amount: u64,
+    /// Bid volume left across all auctions (lamports). Each bid draws down
+    /// the amount it adds to the bidder's standing bid in that auction; being
+    /// outbid does not credit it back, so this caps cumulative volume rather
+    /// than open exposure.
+    pub volume_remaining: u64,
+    /// Auctions or houses the session may bid in; empty = any
+    #[max_len(MAX_SESSION_TARGETS)]
+    pub allowed_targets: Vec<Pubkey>,
+    /// Set by revoke_global_session; cleared when re-created
+    pub revoked: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Replay guard for relayed signed bids: each bid must carry a nonce above
+/// `last_nonce`. Delegated to the ER with the auctions so bids there can
+/// advance it. Seeds: [b"bid_nonce", bidder.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct BidNonce {
+    /// The wallet whose signed bids this guards
+    pub bidder: Pubkey,
+    /// Highest nonce consumed so far
+    pub last_nonce: u64,
     /// PDA bump seed
     pub bump: u8,
 }
+
+impl GlobalSession {
+    /// Whether the allowlist admits this auction, directly or via its house
+    pub fn allows(&self, auction: &Pubkey, house: &Pubkey) -> bool {
+        self.allowed_targets.is_empty()
+            || self
+                .allowed_targets
+                .iter()
+                .any(|target| target == auction || (*house != Pubkey::default() && target == house))
+    }
+
+    /// Draws `amount` from the shared bid-volume cap
+    pub fn draw_volume(&mut self, amount: u64) -> Result<()> {
+        self.volume_remaining = self
+            .volume_remaining
+            .checked_sub(amount)
+            .ok_or(OutcryError::SessionVolumeCapExceeded)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_OPEN_BID_LOCKS;
+use crate::errors::OutcryError;
+
+/// Per-wallet balance shared across auctions. Holds `balance + locked`
+/// lamports on top of its own rent. Bids lock funds into `locks` directly,
+/// so to bid on delegated auctions the account is delegated alongside them.
+/// Seeds: [b"bidder_account", owner.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct BidderAccount {
+    /// Wallet that funds and withdraws this account
+    pub owner: Pubkey,
+    /// Lamports free to withdraw or lock into an auction
+    pub balance: u64,
+    /// Lamports locked across all open `locks`
+    pub locked: u64,
+    /// Funds committed per auction; unused slots have `auction == default`
+    pub locks: [BidLock; MAX_OPEN_BID_LOCKS],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Funds from a BidderAccount committed to one auction. Set to the amount
+/// due on the owner's latest bid there, released back to the balance once
+/// they are outbid, and debited at settlement if they win.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct BidLock {
+    /// The auction these funds are committed to
+    pub auction: Pubkey,
+    /// Locked amount (lamports)
+    pub amount: u64,
+}
+
+impl BidderAccount {
+    /// Lamports locked into `auction`, 0 if none.
+    pub fn lock_amount(&self, auction: &Pubkey) -> u64 {
+        self.locks
+            .iter()
+            .find(|l| l.auction == *auction)
+            .map_or(0, |l| l.amount)
+    }
+
+    /// Sets the lock on `auction` to `amount`, moving the difference between
+    /// the free balance and the locked total. Opens a slot on first use.
+    pub fn set_lock(&mut self, auction: &Pubkey, amount: u64) -> Result<()> {
+        let index = match self.locks.iter().position(|l| l.auction == *auction) {
+            Some(index) => index,
+            None => self
+                .locks
+                .iter()
+                .position(|l| l.auction == Pubkey::default())
+                .ok_or(OutcryError::BidLocksFull)?,
+        };
+
+        let current = self.locks[index].amount;
+        if amount > current {
+            self.lock(amount - current)?;
+        } else {
+            self.unlock(current - amount)?;
+        }
+        self.locks[index] = BidLock {
+            auction: *auction,
+            amount,
+        };
+        Ok(())
+    }
+
+    /// Frees the slot for `auction` and returns its amount to the balance.
+    /// Returns the amount released, 0 if there was no lock.
+    pub fn release(&mut self, auction: &Pubkey) -> Result<u64> {
+        let amount = self.take_lock(auction);
+        self.unlock(amount)?;
+        Ok(amount)
+    }
+
+    /// Frees the slot for `auction`, takes `amount` of it out of the locked
+    /// total and returns the rest to the balance. The taken lamports are
+    /// leaving the account (settlement or forfeit); the caller moves them.
+    pub fn debit_lock(&mut self, auction: &Pubkey, amount: u64) -> Result<()> {
+        let held = self.take_lock(auction);
+        require!(amount <= held, OutcryError::InsufficientDeposit);
+        self.locked = self
+            .locked
+            .checked_sub(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.unlock(held - amount)
+    }
+
+    fn take_lock(&mut self, auction: &Pubkey) -> u64 {
+        match self.locks.iter_mut().find(|l| l.auction == *auction) {
+            Some(slot) => std::mem::take(slot).amount,
+            None => 0,
+        }
+    }
+
+    /// Moves `amount` from the free balance into the locked total.
+    fn lock(&mut self, amount: u64) -> Result<()> {
+        require!(amount <= self.balance, OutcryError::InsufficientBalance);
+        self.balance -= amount;
+        self.locked = self
+            .locked
+            .checked_add(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Returns `amount` of a released lock to the free balance.
+    fn unlock(&mut self, amount: u64) -> Result<()> {
+        self.locked = self
+            .locked
+            .checked_sub(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.balance = self
+            .balance
+            .checked_add(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
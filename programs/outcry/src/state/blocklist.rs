@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DEFAULT_BAN_THRESHOLD, MAX_BLOCKED_WALLETS};
+use crate::errors::OutcryError;
+use crate::state::{AuctionState, BidderProfile};
+
+/// Wallets a seller refuses as bidders, across all their auctions.
+/// Seeds: [b"blocklist", seller.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct BlockList {
+    pub seller: Pubkey,
+    #[max_len(MAX_BLOCKED_WALLETS)]
+    pub blocked: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BlockList {
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        self.blocked.contains(wallet)
+    }
+}
+
+/// Protocol-wide default history for a wallet, written by forfeit_auction.
+/// Auctions with `ban_defaulters` reject wallets at DEFAULT_BAN_THRESHOLD.
+/// Seeds: [b"default_record", bidder.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct DefaultRecord {
+    pub bidder: Pubkey,
+    /// Auctions this wallet won and then forfeited
+    pub defaults: u32,
+    pub last_default_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DefaultRecord {
+    pub fn is_banned(&self) -> bool {
+        self.defaults >= DEFAULT_BAN_THRESHOLD
+    }
+}
+
+/// Rejects a bidder on the seller's blocklist, or whose history fails the
+/// auction's requirements: the protocol default ban (if opted in) and
+/// `max_defaults` against the DefaultRecord, `min_reputation` against the
+/// profile. All accounts must already be validated as the right PDAs; any
+/// may not exist, which means "not listed" / "no history".
+pub fn check_bidder_standing(
+    auction: &AuctionState,
+    blocklist: &AccountInfo,
+    default_record: &AccountInfo,
+    bidder_profile: &AccountInfo,
+    bidder: &Pubkey,
+) -> Result<()> {
+    if blocklist.owner == &crate::ID {
+        let list = BlockList::try_deserialize(&mut &blocklist.try_borrow_data()?[..])?;
+        require!(!list.contains(bidder), OutcryError::WalletBlocked);
+    }
+
+    let record = if default_record.owner == &crate::ID {
+        Some(DefaultRecord::try_deserialize(
+            &mut &default_record.try_borrow_data()?[..],
+        )?)
+    } else {
+        None
+    };
+    let defaults = record.as_ref().map_or(0, |r| r.defaults);
+    require!(
+        !auction.ban_defaulters || !record.as_ref().is_some_and(DefaultRecord::is_banned),
+        OutcryError::WalletBanned
+    );
+    if let Some(max_defaults) = auction.max_defaults {
+        require!(defaults <= max_defaults, OutcryError::TooManyDefaults);
+    }
+
+    if let Some(min_reputation) = auction.min_reputation {
+        let reputation = if bidder_profile.owner == &crate::ID {
+            BidderProfile::try_deserialize(&mut &bidder_profile.try_borrow_data()?[..])?
+                .reputation
+        } else {
+            0
+        };
+        require!(reputation >= min_reputation, OutcryError::ReputationTooLow);
+    }
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Royalties owed to a creator, credited at settlement and pulled by the
+/// creator with withdraw_creator_earnings. Holds the owed lamports on top of
+/// its own rent. Seeds: [b"earnings", creator.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorEarnings {
+    /// The creator these earnings belong to
+    pub creator: Pubkey,
+    /// Lamports available to withdraw
+    pub balance: u64,
+    /// Lifetime royalties credited (lamports)
+    pub total_earned: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PAYOUT_SPLITS;
+use crate::state::PayoutSplit;
+
+/// Seller proceeds held after settlement of an escrowed-payout auction
+/// (physical items). Released to the seller on buyer confirmation, after the
+/// dispute window, or by the arbiter's ruling.
+/// Seeds: [b"proceeds", auction_state.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct ProceedsEscrow {
+    /// The settled auction
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    /// Auction winner — may confirm receipt or open a dispute
+    pub buyer: Pubkey,
+    /// Resolves disputes
+    pub arbiter: Pubkey,
+    /// Held proceeds (lamports), on top of this account's rent
+    pub amount: u64,
+    /// Unix timestamp after which proceeds release automatically
+    pub release_after: i64,
+    /// Set by open_dispute; blocks automatic release
+    pub disputed: bool,
+    /// Payout split table snapshotted from the auction
+    pub payout_splits: [PayoutSplit; MAX_PAYOUT_SPLITS],
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ProceedsEscrow {
+    pub fn active_payout_splits(&self) -> &[PayoutSplit] {
+        let count = self.payout_splits.iter().take_while(|s| s.bps > 0).count();
+        &self.payout_splits[..count]
+    }
+}
+
+/// Optional escrowed-payout terms chosen at auction creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct EscrowTerms {
+    /// Key that resolves disputes
+    pub arbiter: Pubkey,
+    /// How long the buyer has to open a dispute after settlement (seconds)
+    pub dispute_window_seconds: u32,
+}
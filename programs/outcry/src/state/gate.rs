@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount};
+
+use crate::{constants::GATE_CLAIM_SEED, errors::OutcryError};
+
+/// Records which bidder a gate-collection token qualified on an auction, so
+/// one token can't be passed around to make several wallets eligible.
+/// Seeds: [b"gate_claim", auction_state.key(), gate_mint.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct GateClaim {
+    /// The collection-gated auction
+    pub auction: Pubkey,
+    /// Mint of the gate token that was proven
+    pub mint: Pubkey,
+    /// Bidder the token qualified
+    pub bidder: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl GateClaim {
+    /// Claims `mint` for `bidder` on `auction`, creating the claim (rent paid
+    /// by `payer`) on first use. A token already claimed by the same bidder
+    /// passes again; one claimed by anyone else is rejected.
+    pub fn claim<'info>(
+        claim_info: &AccountInfo<'info>,
+        auction: Pubkey,
+        mint: Pubkey,
+        bidder: Pubkey,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (claim_key, bump) = Pubkey::find_program_address(
+            &[GATE_CLAIM_SEED, auction.as_ref(), mint.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(claim_info.key(), claim_key, OutcryError::InvalidGateClaim);
+
+        if claim_info.owner == &crate::ID {
+            let data = claim_info.try_borrow_data()?;
+            let claim = GateClaim::try_deserialize(&mut &data[..])
+                .map_err(|_| error!(OutcryError::InvalidGateClaim))?;
+            require_keys_eq!(claim.bidder, bidder, OutcryError::GateTokenClaimed);
+            return Ok(());
+        }
+
+        let space = 8 + GateClaim::INIT_SPACE;
+        let rent = Rent::get()?.minimum_balance(space);
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[GATE_CLAIM_SEED, auction.as_ref(), mint.as_ref(), &[bump]]];
+
+        if claim_info.lamports() == 0 {
+            system_program::create_account(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    CreateAccount {
+                        from: payer.clone(),
+                        to: claim_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                rent,
+                space as u64,
+                &crate::ID,
+            )?;
+        } else {
+            // Someone pre-funded the address — top up, then allocate + assign
+            let top_up = rent.saturating_sub(claim_info.lamports());
+            if top_up > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        system_program.clone(),
+                        system_program::Transfer {
+                            from: payer.clone(),
+                            to: claim_info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            system_program::allocate(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    Allocate {
+                        account_to_allocate: claim_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                space as u64,
+            )?;
+            system_program::assign(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    Assign {
+                        account_to_assign: claim_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                &crate::ID,
+            )?;
+        }
+
+        let claim = GateClaim {
+            auction,
+            mint,
+            bidder,
+            bump,
+        };
+        let mut data = claim_info.try_borrow_mut_data()?;
+        claim.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+}
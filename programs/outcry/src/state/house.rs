@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// White-label marketplace running on OUTCRY with its own fee and curation.
+/// Seeds: [b"house", authority.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct AuctionHouse {
+    /// Can update the house configuration
+    pub authority: Pubkey,
+    /// House cut of the hammer price (bps), taken after the protocol fee
+    pub fee_bps: u16,
+    /// Receives the house fee and buyer's premium at settlement
+    pub fee_recipient: Pubkey,
+    /// Must co-sign every listing; Pubkey::default() = open listings
+    pub curator: Pubkey,
+    /// Premium charged to winners of this house's auctions (bps)
+    pub buyer_premium_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+}
@@ -1,3 +1,21 @@
 pub mod auction;
+pub mod bidder;
+pub mod blocklist;
+pub mod earnings;
+pub mod escrow;
+pub mod gate;
+pub mod house;
+pub mod profile;
+pub mod sale;
+pub mod unclaimed;
 
 pub use auction::*;
+pub use bidder::*;
+pub use blocklist::*;
+pub use earnings::*;
+pub use escrow::*;
+pub use gate::*;
+pub use house::*;
+pub use profile::*;
+pub use sale::*;
+pub use unclaimed::*;
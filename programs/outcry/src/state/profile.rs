@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BIDDER_PROFILE_VERSION, REPUTATION_DEFAULT_PENALTY, REPUTATION_WIN_POINTS,
+    SELLER_PROFILE_VERSION,
+};
+use crate::errors::OutcryError;
+use crate::state::DefaultRecord;
+
+/// Protocol-wide bidding history for a wallet. Created by the bidder
+/// (create_bidder_profile) or by forfeit_auction on a first default.
+/// Seeds: [b"bidder_profile", bidder.key()]
+///
+/// Stable layout for other programs — every field is fixed-size, new fields
+/// are carved out of `reserved` and bump `version`. Byte offsets including
+/// the 8-byte discriminator:
+///   8 version(u8) | 9 bidder | 41 reputation(u32) | 45 auctions_won(u32)
+///   49 total_spent(u64) | 57 auctions_participated(u32) | 61 defaults(u32)
+///   65 forfeited_amount(u64) | 73 last_default_at(i64) | 81 bump
+///   82 reserved[64]
+#[account]
+#[derive(InitSpace)]
+pub struct BidderProfile {
+    pub version: u8,
+    pub bidder: Pubkey,
+    /// Score derived from the counters below, kept current on every update
+    pub reputation: u32,
+    pub auctions_won: u32,
+    /// Lamports paid across won auctions (bid + buyer's premium)
+    pub total_spent: u64,
+    /// Auctions (other than cancelled ones) this wallet received a deposit
+    /// refund from — as an outbid bidder, a depositor who never bid, the
+    /// winner's leftover or a ReserveNotMet auction. Informational only: a
+    /// refund doesn't prove a bid, so it never feeds the score
+    pub auctions_participated: u32,
+    /// Copy of the wallet's DefaultRecord count — the record is the
+    /// authoritative history; this feeds the score
+    pub defaults: u32,
+    /// Lamports slashed across all forfeits
+    pub forfeited_amount: u64,
+    /// Copy of the DefaultRecord's last_default_at
+    pub last_default_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+impl BidderProfile {
+    pub fn init(&mut self, bidder: Pubkey, bump: u8) {
+        self.version = BIDDER_PROFILE_VERSION;
+        self.bidder = bidder;
+        self.bump = bump;
+    }
+
+    pub fn record_win(&mut self, amount_paid: u64) -> Result<()> {
+        self.auctions_won = self.auctions_won.saturating_add(1);
+        self.total_spent = self
+            .total_spent
+            .checked_add(amount_paid)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.refresh_reputation();
+        Ok(())
+    }
+
+    pub fn record_participation(&mut self) {
+        self.auctions_participated = self.auctions_participated.saturating_add(1);
+    }
+
+    /// Reads the default history from the wallet's (already updated)
+    /// DefaultRecord and adds the slashed amount
+    pub fn record_default(&mut self, record: &DefaultRecord, forfeited: u64) -> Result<()> {
+        self.defaults = record.defaults;
+        self.forfeited_amount = self
+            .forfeited_amount
+            .checked_add(forfeited)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        self.last_default_at = record.last_default_at;
+        self.refresh_reputation();
+        Ok(())
+    }
+
+    /// Wins earn points, defaults cost them; floors at 0.
+    fn refresh_reputation(&mut self) {
+        let earned = (self.auctions_won as u64) * REPUTATION_WIN_POINTS as u64;
+        let penalty = (self.defaults as u64) * REPUTATION_DEFAULT_PENALTY as u64;
+        self.reputation = earned.saturating_sub(penalty).min(u32::MAX as u64) as u32;
+    }
+
+    /// Applies `update` to the profile behind `info` if one exists. For
+    /// instructions that credit history but shouldn't create profiles.
+    pub fn update_if_exists(
+        info: &AccountInfo,
+        update: impl FnOnce(&mut BidderProfile) -> Result<()>,
+    ) -> Result<()> {
+        if info.owner != &crate::ID {
+            return Ok(());
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        let mut profile = BidderProfile::try_deserialize(&mut &data[..])?;
+        update(&mut profile)?;
+        profile.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+}
+
+/// Protocol-wide selling history for a wallet. Created by the seller
+/// (create_seller_profile) or by cancel_auction on a first cancellation.
+/// Seeds: [b"seller_profile", seller.key()]
+///
+/// Same stability rules as BidderProfile. Byte offsets including the
+/// 8-byte discriminator:
+///   8 version(u8) | 9 seller | 41 completed_sales(u32) | 45 total_volume(u64)
+///   53 cancellations(u32) | 57 bonds_slashed(u32) | 61 slashed_amount(u64)
+///   69 bump | 70 reserved[64]
+#[account]
+#[derive(InitSpace)]
+pub struct SellerProfile {
+    pub version: u8,
+    pub seller: Pubkey,
+    /// Auctions settled as a sale
+    pub completed_sales: u32,
+    /// Hammer prices across completed sales (lamports)
+    pub total_volume: u64,
+    pub cancellations: u32,
+    /// Auctions whose listing bond was slashed
+    pub bonds_slashed: u32,
+    /// Lamports lost across all slashed bonds
+    pub slashed_amount: u64,
+    /// PDA bump seed
+    pub bump: u8,
+    pub reserved: [u8; 64],
+}
+
+impl SellerProfile {
+    pub fn init(&mut self, seller: Pubkey, bump: u8) {
+        self.version = SELLER_PROFILE_VERSION;
+        self.seller = seller;
+        self.bump = bump;
+    }
+
+    pub fn record_sale(&mut self, hammer_price: u64) -> Result<()> {
+        self.completed_sales = self.completed_sales.saturating_add(1);
+        self.total_volume = self
+            .total_volume
+            .checked_add(hammer_price)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_cancellation(&mut self) {
+        self.cancellations = self.cancellations.saturating_add(1);
+    }
+
+    pub fn record_slash(&mut self, amount: u64) -> Result<()> {
+        self.bonds_slashed = self.bonds_slashed.saturating_add(1);
+        self.slashed_amount = self
+            .slashed_amount
+            .checked_add(amount)
+            .ok_or(OutcryError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Applies `update` to the profile behind `info` if one exists
+    pub fn update_if_exists(
+        info: &AccountInfo,
+        update: impl FnOnce(&mut SellerProfile) -> Result<()>,
+    ) -> Result<()> {
+        if info.owner != &crate::ID {
+            return Ok(());
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        let mut profile = SellerProfile::try_deserialize(&mut &data[..])?;
+        update(&mut profile)?;
+        profile.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SALE_LOTS;
+use crate::state::AuctionStatus;
+
+/// Catalog of lots that open together and close one after another.
+/// Stays on L1; lots are ordinary AuctionState accounts that point back here.
+/// Seeds: [b"sale", organizer.key(), sale_id.to_le_bytes()]
+#[account]
+#[derive(InitSpace)]
+pub struct SaleEvent {
+    /// Organizer of the sale — also the seller of every lot
+    pub organizer: Pubkey,
+    /// Organizer-chosen id, lets one organizer run many sales
+    pub sale_id: u64,
+    /// Shared scheduled start for every lot
+    pub start_time: i64,
+    /// Gap between consecutive lots' closing times (seconds)
+    pub stagger_seconds: u32,
+    /// Lots in closing order
+    #[max_len(MAX_SALE_LOTS)]
+    pub lots: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// One row of the catalog returned by get_sale_catalog.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SaleLotSummary {
+    pub auction: Pubkey,
+    pub lot_index: u16,
+    pub nft_mint: Pubkey,
+    pub status: AuctionStatus,
+    pub current_bid: u64,
+    pub highest_bidder: Pubkey,
+    pub end_time: i64,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Deposits left unclaimed when an auction was force-closed. The bidders'
+/// BidderDeposit PDAs stay open as the per-bidder ledger and can be redeemed
+/// here at any time. Seeds: [b"unclaimed", auction_state.key()]
+#[account]
+#[derive(InitSpace)]
+pub struct UnclaimedFunds {
+    /// The force-closed auction
+    pub auction: Pubkey,
+    /// Seller — receives this account's rent once every deposit is claimed
+    pub seller: Pubkey,
+    /// Lamports still owed to bidders, on top of this account's rent
+    pub remaining: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
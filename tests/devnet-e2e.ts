@@ -37,7 +37,13 @@ import path from "path";
 import {
   PROTOCOL_TREASURY,
   getMetadataPDA,
+  getCreatorEarningsPDA,
   getDepositPDA,
+  getBlockListPDA,
+  getDefaultRecordPDA,
+  getBidderProfilePDA,
+  getSellerProfilePDA,
+  getUnclaimedFundsPDA,
   createMetadataV3Instruction,
   sleep,
 } from "./helpers";
@@ -260,13 +266,29 @@ describe("outcry devnet e2e", function () {
     );
 
     const tx = await program.methods
-      .createAuction(
+      .createAuction({
         reservePrice,
+        reserveCommitment: null,
         durationSeconds,
         extensionSeconds,
         extensionWindow,
-        minBidIncrement
-      )
+        minBidIncrement,
+        scheduledStart: null,
+        antiSnipePolicy: { fixedAdd: {} },
+        maxExtensionSeconds: 3600,
+        bidIncrement: { flat: {} },
+        allowlistRoot: null,
+        collectionGate: null,
+        banDefaulters: false,
+        minReputation: null,
+        maxDefaults: null,
+        sellerBond: new anchor.BN(0),
+        minBidInterval: 0,
+        blockSelfOutbid: false,
+        escrowTerms: null,
+        payoutSplits: [],
+        buyerPremiumBps: 0,
+      })
       .accountsStrict({
         seller: seller.publicKey,
         nftMint: nftMint,
@@ -274,9 +296,12 @@ describe("outcry devnet e2e", function () {
         escrowNftTokenAccount: escrowNftAta,
         auctionState: auctionState,
         auctionVault: auctionVault,
+        unclaimedFunds: getUnclaimedFundsPDA(auctionState, program.programId)[0],
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        auctionHouse: null,
+        curator: null,
       })
       .signers([seller])
       .rpc();
@@ -316,13 +341,19 @@ describe("outcry devnet e2e", function () {
     );
 
     const tx = await program.methods
-      .deposit(depositAmount)
+      .deposit(depositAmount, [])
       .accountsStrict({
         bidder: bidder.publicKey,
         auctionState: auctionState,
         bidderDeposit: bidderDepositPda,
         auctionVault: auctionVault,
         systemProgram: SystemProgram.programId,
+        gateTokenAccount: null,
+        gateMetadata: null,
+        gateClaim: null,
+        blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+        defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+        bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
       })
       .signers([bidder])
       .rpc();
@@ -386,10 +417,17 @@ describe("outcry devnet e2e", function () {
     );
 
     const tx = await program.methods
-      .placeBid(bidAmount)
+      .placeBid(bidAmount, null)
       .accountsStrict({
         bidder: bidder.publicKey,
         auctionState: auctionState,
+        bidderDeposit: null,
+        blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+        defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+        bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+        previousLot: null,
+        bidderAccount: null,
+        outbidBidderAccount: null,
       })
       .signers([bidder])
       .rpc();
@@ -486,7 +524,7 @@ describe("outcry devnet e2e", function () {
     const [winnerDepositPda] = getDepositPDA(auctionState, bidder.publicKey, PROGRAM_ID);
     console.log(`    Winner Deposit PDA: ${winnerDepositPda.toBase58()}`);
 
-    // Pass creator accounts via remainingAccounts (seller is the sole creator)
+    // Pass creator earnings PDAs via remainingAccounts (seller is the sole creator)
     const tx = await program.methods
       .settleAuction()
       .accountsStrict({
@@ -504,9 +542,19 @@ describe("outcry devnet e2e", function () {
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        auctionHouse: null,
+        houseFeeRecipient: null,
+        proceedsEscrow: null,
+        winnerBidderAccount: null,
+        winnerProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+        sellerProfile: getSellerProfilePDA(seller.publicKey, program.programId)[0],
       })
       .remainingAccounts([
-        { pubkey: seller.publicKey, isSigner: false, isWritable: true },
+        {
+          pubkey: getCreatorEarningsPDA(seller.publicKey, program.programId)[0],
+          isSigner: false,
+          isWritable: true,
+        },
       ])
       .signers([seller])
       .rpc();
@@ -582,6 +630,7 @@ describe("outcry devnet e2e", function () {
         bidderDeposit: bidderDepositPda,
         auctionVault: auctionVault,
         systemProgram: SystemProgram.programId,
+        bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
       })
       .signers([bidder])
       .rpc();
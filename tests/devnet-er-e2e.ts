@@ -52,7 +52,13 @@ const PROGRAM_ID = new PublicKey(
 import {
   PROTOCOL_TREASURY,
   getMetadataPDA,
+  getCreatorEarningsPDA,
   getDepositPDA as getDepositPDAHelper,
+  getBlockListPDA,
+  getDefaultRecordPDA,
+  getBidderProfilePDA,
+  getSellerProfilePDA,
+  getUnclaimedFundsPDA,
   createMetadataV3Instruction,
   sleep,
 } from "./helpers";
@@ -402,13 +408,29 @@ describe("outcry devnet ER e2e", function () {
     );
 
     const tx = await l1Program.methods
-      .createAuction(
+      .createAuction({
         reservePrice,
+        reserveCommitment: null,
         durationSeconds,
         extensionSeconds,
         extensionWindow,
-        minBidIncrement
-      )
+        minBidIncrement,
+        scheduledStart: null,
+        antiSnipePolicy: { fixedAdd: {} },
+        maxExtensionSeconds: 3600,
+        bidIncrement: { flat: {} },
+        allowlistRoot: null,
+        collectionGate: null,
+        banDefaulters: false,
+        minReputation: null,
+        maxDefaults: null,
+        sellerBond: new anchor.BN(0),
+        minBidInterval: 0,
+        blockSelfOutbid: false,
+        escrowTerms: null,
+        payoutSplits: [],
+        buyerPremiumBps: 0,
+      })
       .accountsStrict({
         seller: seller.publicKey,
         nftMint: nftMint,
@@ -416,9 +438,12 @@ describe("outcry devnet ER e2e", function () {
         escrowNftTokenAccount: escrowNftAta,
         auctionState: auctionState,
         auctionVault: auctionVault,
+        unclaimedFunds: getUnclaimedFundsPDA(auctionState, PROGRAM_ID)[0],
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        auctionHouse: null,
+        curator: null,
       })
       .signers([seller])
       .rpc();
@@ -444,13 +469,19 @@ describe("outcry devnet ER e2e", function () {
     );
 
     const tx = await l1Program.methods
-      .deposit(depositAmount)
+      .deposit(depositAmount, [])
       .accountsStrict({
         bidder: bidder.publicKey,
         auctionState: auctionState,
         bidderDeposit: bidderDepositPda,
         auctionVault: auctionVault,
         systemProgram: SystemProgram.programId,
+        gateTokenAccount: null,
+        gateMetadata: null,
+        gateClaim: null,
+        blocklist: getBlockListPDA(seller.publicKey, PROGRAM_ID)[0],
+        defaultRecord: getDefaultRecordPDA(bidder.publicKey, PROGRAM_ID)[0],
+        bidderProfile: getBidderProfilePDA(bidder.publicKey, PROGRAM_ID)[0],
       })
       .signers([bidder])
       .rpc();
@@ -555,10 +586,17 @@ describe("outcry devnet ER e2e", function () {
     );
 
     const methodBuilder = erProgram.methods
-      .placeBid(bidAmount)
+      .placeBid(bidAmount, null)
       .accountsStrict({
         bidder: bidder.publicKey,
         auctionState: auctionState,
+        bidderDeposit: null,
+        blocklist: getBlockListPDA(seller.publicKey, PROGRAM_ID)[0],
+        defaultRecord: getDefaultRecordPDA(bidder.publicKey, PROGRAM_ID)[0],
+        bidderProfile: getBidderProfilePDA(bidder.publicKey, PROGRAM_ID)[0],
+        previousLot: null,
+        bidderAccount: null,
+        outbidBidderAccount: null,
       });
 
     const sig = await sendErTransaction(
@@ -587,6 +625,39 @@ describe("outcry devnet ER e2e", function () {
     console.log(`    Bid processed on ER ✓`);
   });
 
+  // -----------------------------------------------------------------------
+  // 5b. withdraw_deposit (L1) — reads the last committed state
+  // -----------------------------------------------------------------------
+
+  it("5b. withdraw_deposit (L1) — new leader can't withdraw below their ER bid", async () => {
+    console.log("\n  [withdraw_deposit while delegated]");
+
+    // Depending on whether the bid has been committed yet, the L1 copy shows
+    // the bidder as leader (refused outright) or not (must keep the lowest
+    // possible leading bid) — either way the full deposit can't come out
+    try {
+      await l1Program.methods
+        .withdrawDeposit(new BN(0.1 * LAMPORTS_PER_SOL))
+        .accountsStrict({
+          bidder: bidder.publicKey,
+          auctionState: auctionState,
+          bidderDeposit: bidderDepositPda,
+          auctionVault: auctionVault,
+        })
+        .signers([bidder])
+        .rpc();
+      expect.fail("Should have thrown");
+    } catch (err: any) {
+      expect(["LeaderWithdrawWhileDelegated", "WithdrawExceedsExcess"]).to.include(
+        err.error.errorCode.code
+      );
+    }
+
+    const deposit = await l1Program.account.bidderDeposit.fetch(bidderDepositPda);
+    expect(deposit.amount.toNumber()).to.equal(0.1 * LAMPORTS_PER_SOL);
+    console.log("    Withdrawal below the live bid rejected ✓");
+  });
+
   // -----------------------------------------------------------------------
   // 6. Wait for auction timer to expire
   // -----------------------------------------------------------------------
@@ -740,9 +811,19 @@ describe("outcry devnet ER e2e", function () {
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        auctionHouse: null,
+        houseFeeRecipient: null,
+        proceedsEscrow: null,
+        winnerBidderAccount: null,
+        winnerProfile: getBidderProfilePDA(bidder.publicKey, PROGRAM_ID)[0],
+        sellerProfile: getSellerProfilePDA(seller.publicKey, PROGRAM_ID)[0],
       })
       .remainingAccounts([
-        { pubkey: seller.publicKey, isSigner: false, isWritable: true },
+        {
+          pubkey: getCreatorEarningsPDA(seller.publicKey, PROGRAM_ID)[0],
+          isSigner: false,
+          isWritable: true,
+        },
       ])
       .signers([seller])
       .rpc();
@@ -798,6 +879,7 @@ describe("outcry devnet ER e2e", function () {
         bidderDeposit: bidderDepositPda,
         auctionVault: auctionVault,
         systemProgram: SystemProgram.programId,
+        bidderProfile: getBidderProfilePDA(bidder.publicKey, PROGRAM_ID)[0],
       })
       .signers([bidder])
       .rpc();
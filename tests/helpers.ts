@@ -1,4 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
+import { createHash } from "crypto";
 import { Program } from "@coral-xyz/anchor";
 import { Outcry } from "../target/types/outcry";
 import {
@@ -36,6 +37,10 @@ export const PROTOCOL_TREASURY = new PublicKey(
   "B6MtVeqn7BrJ8HTX6CeP8VugNWyCqqbfcDMxYBknzPt7"
 );
 
+export const DELEGATION_PROGRAM_ID = new PublicKey(
+  "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh"
+);
+
 // ---------------------------------------------------------------------------
 // PDA Helpers
 // ---------------------------------------------------------------------------
@@ -61,6 +66,87 @@ export function getVaultPDA(
   );
 }
 
+export function getSessionPDA(
+  auctionState: PublicKey,
+  bidder: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("session"), auctionState.toBuffer(), bidder.toBuffer()],
+    programId
+  );
+}
+
+export function getGlobalSessionPDA(
+  bidder: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("session"), bidder.toBuffer()],
+    programId
+  );
+}
+
+export function getBidNoncePDA(
+  bidder: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bid_nonce"), bidder.toBuffer()],
+    programId
+  );
+}
+
+export function getBidderAccountPDA(
+  owner: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bidder_account"), owner.toBuffer()],
+    programId
+  );
+}
+
+export function getBlockListPDA(
+  seller: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("blocklist"), seller.toBuffer()],
+    programId
+  );
+}
+
+export function getDefaultRecordPDA(
+  bidder: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("default_record"), bidder.toBuffer()],
+    programId
+  );
+}
+
+export function getBidderProfilePDA(
+  bidder: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bidder_profile"), bidder.toBuffer()],
+    programId
+  );
+}
+
+export function getSellerProfilePDA(
+  seller: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("seller_profile"), seller.toBuffer()],
+    programId
+  );
+}
+
 export function getDepositPDA(
   auctionState: PublicKey,
   bidder: PublicKey,
@@ -72,6 +158,58 @@ export function getDepositPDA(
   );
 }
 
+export function getHousePDA(
+  authority: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("house"), authority.toBuffer()],
+    programId
+  );
+}
+
+export function getSalePDA(
+  organizer: PublicKey,
+  saleId: anchor.BN,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("sale"), organizer.toBuffer(), saleId.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+}
+
+export function getCreatorEarningsPDA(
+  creator: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("earnings"), creator.toBuffer()],
+    programId
+  );
+}
+
+export function getGateClaimPDA(
+  auctionState: PublicKey,
+  gateMint: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("gate_claim"), auctionState.toBuffer(), gateMint.toBuffer()],
+    programId
+  );
+}
+
+export function getUnclaimedFundsPDA(
+  auctionState: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("unclaimed"), auctionState.toBuffer()],
+    programId
+  );
+}
+
 export function getMetadataPDA(mint: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [
@@ -83,6 +221,42 @@ export function getMetadataPDA(mint: PublicKey): [PublicKey, number] {
   );
 }
 
+export function getMasterEditionPDA(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("metadata"),
+      TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      mint.toBuffer(),
+      Buffer.from("edition"),
+    ],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+}
+
+export function getDelegationBufferPDA(
+  account: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("buffer"), account.toBuffer()],
+    programId
+  );
+}
+
+export function getDelegationRecordPDA(account: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("delegation"), account.toBuffer()],
+    DELEGATION_PROGRAM_ID
+  );
+}
+
+export function getDelegationMetadataPDA(account: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("delegation-metadata"), account.toBuffer()],
+    DELEGATION_PROGRAM_ID
+  );
+}
+
 // ---------------------------------------------------------------------------
 // Metaplex metadata helper — builds CreateMetadataAccountV3 instruction
 // ---------------------------------------------------------------------------
@@ -97,7 +271,8 @@ export function createMetadataV3Instruction(
   symbol: string,
   uri: string,
   sellerFeeBps: number,
-  creators: { address: PublicKey; verified: boolean; share: number }[]
+  creators: { address: PublicKey; verified: boolean; share: number }[],
+  collection?: PublicKey
 ): TransactionInstruction {
   const nameBytes = Buffer.from(name);
   const symbolBytes = Buffer.from(symbol);
@@ -113,7 +288,7 @@ export function createMetadataV3Instruction(
     2 +
     1 +
     (hasCreators ? 4 + creators.length * 34 : 0) +
-    1 + 1 + 1 + 1;
+    1 + (collection ? 33 : 0) + 1 + 1 + 1;
 
   const data = Buffer.alloc(size);
   let offset = 0;
@@ -143,7 +318,14 @@ export function createMetadataV3Instruction(
     data.writeUInt8(0, offset); offset += 1;
   }
 
-  data.writeUInt8(0, offset); offset += 1; // collection: None
+  if (collection) {
+    // collection: Some({ verified: false, key }) — only verify_collection can verify it
+    data.writeUInt8(1, offset); offset += 1;
+    data.writeUInt8(0, offset); offset += 1;
+    collection.toBuffer().copy(data, offset); offset += 32;
+  } else {
+    data.writeUInt8(0, offset); offset += 1; // collection: None
+  }
   data.writeUInt8(0, offset); offset += 1; // uses: None
   data.writeUInt8(1, offset); offset += 1; // is_mutable: true
   data.writeUInt8(0, offset); offset += 1; // collection_details: None
@@ -184,6 +366,7 @@ export async function createTestNft(
     name?: string;
     symbol?: string;
     uri?: string;
+    collection?: PublicKey;
   }
 ): Promise<TestNft> {
   const owner = opts?.owner ?? payer;
@@ -226,7 +409,8 @@ export async function createTestNft(
     symbol,
     uri,
     sellerFeeBps,
-    creators
+    creators,
+    opts?.collection
   );
   const tx = new Transaction().add(metaIx);
   const signers = [payer];
@@ -236,6 +420,69 @@ export async function createTestNft(
   return { mint, ownerAta, metadata };
 }
 
+/**
+ * Creates a collection NFT (with the master edition Metaplex requires)
+ * whose update authority is `payer`.
+ */
+export async function createCollectionNft(
+  connection: Connection,
+  payer: Keypair
+): Promise<TestNft & { masterEdition: PublicKey }> {
+  const nft = await createTestNft(connection, payer);
+  const [masterEdition] = getMasterEditionPDA(nft.mint);
+
+  // CreateMasterEditionV3 { max_supply: Some(0) }
+  const data = Buffer.alloc(10);
+  data.writeUInt8(17, 0);
+  data.writeUInt8(1, 1);
+  data.writeBigUInt64LE(BigInt(0), 2);
+
+  const ix = new TransactionInstruction({
+    programId: TOKEN_METADATA_PROGRAM_ID,
+    keys: [
+      { pubkey: masterEdition, isSigner: false, isWritable: true },
+      { pubkey: nft.mint, isSigner: false, isWritable: true },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+      { pubkey: nft.metadata, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SYSVAR_RENT, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+  await sendAndConfirmTransaction(connection, new Transaction().add(ix), [payer]);
+
+  return { ...nft, masterEdition };
+}
+
+/**
+ * Marks an item's collection as verified. `authority` must be the
+ * collection NFT's update authority.
+ */
+export async function verifyCollectionItem(
+  connection: Connection,
+  authority: Keypair,
+  itemMetadata: PublicKey,
+  collection: { mint: PublicKey; metadata: PublicKey; masterEdition: PublicKey }
+): Promise<void> {
+  // VerifyCollection (no args)
+  const ix = new TransactionInstruction({
+    programId: TOKEN_METADATA_PROGRAM_ID,
+    keys: [
+      { pubkey: itemMetadata, isSigner: false, isWritable: true },
+      { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+      { pubkey: authority.publicKey, isSigner: true, isWritable: true },
+      { pubkey: collection.mint, isSigner: false, isWritable: false },
+      { pubkey: collection.metadata, isSigner: false, isWritable: false },
+      { pubkey: collection.masterEdition, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.from([18]),
+  });
+  await sendAndConfirmTransaction(connection, new Transaction().add(ix), [authority]);
+}
+
 // ---------------------------------------------------------------------------
 // Full auction setup helper
 // ---------------------------------------------------------------------------
@@ -263,6 +510,24 @@ export async function setupAuction(
     extensionSeconds?: number;
     extensionWindow?: number;
     minBidIncrement?: anchor.BN;
+    scheduledStart?: anchor.BN;
+    antiSnipePolicy?: object;
+    maxExtensionSeconds?: number;
+    bidIncrement?: object;
+    allowlistRoot?: number[] | null;
+    collectionGate?: PublicKey | null;
+    banDefaulters?: boolean;
+    minReputation?: number | null;
+    maxDefaults?: number | null;
+    sellerBond?: anchor.BN;
+    minBidInterval?: number;
+    blockSelfOutbid?: boolean;
+    escrowTerms?: { arbiter: PublicKey; disputeWindowSeconds: number };
+    payoutSplits?: { recipient: PublicKey; bps: number }[];
+    buyerPremiumBps?: number;
+    reserveCommitment?: number[];
+    auctionHouse?: PublicKey;
+    curator?: Keypair;
   }
 ): Promise<AuctionSetup> {
   const mintAuthority = opts?.mintAuthority ?? seller;
@@ -287,13 +552,29 @@ export async function setupAuction(
   );
 
   await program.methods
-    .createAuction(
-      opts?.reservePrice ?? new anchor.BN(1 * LAMPORTS_PER_SOL),
-      opts?.durationSeconds ?? new anchor.BN(5),
-      opts?.extensionSeconds ?? 2,
-      opts?.extensionWindow ?? 2,
-      opts?.minBidIncrement ?? new anchor.BN(0.1 * LAMPORTS_PER_SOL)
-    )
+    .createAuction({
+      reservePrice: opts?.reservePrice ?? new anchor.BN(1 * LAMPORTS_PER_SOL),
+      reserveCommitment: opts?.reserveCommitment ?? null,
+      durationSeconds: opts?.durationSeconds ?? new anchor.BN(5),
+      extensionSeconds: opts?.extensionSeconds ?? 2,
+      extensionWindow: opts?.extensionWindow ?? 2,
+      minBidIncrement: opts?.minBidIncrement ?? new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+      scheduledStart: opts?.scheduledStart ?? null,
+      antiSnipePolicy: opts?.antiSnipePolicy ?? { fixedAdd: {} },
+      maxExtensionSeconds: opts?.maxExtensionSeconds ?? 3600,
+      bidIncrement: opts?.bidIncrement ?? { flat: {} },
+      allowlistRoot: opts?.allowlistRoot ?? null,
+      collectionGate: opts?.collectionGate ?? null,
+      banDefaulters: opts?.banDefaulters ?? false,
+      minReputation: opts?.minReputation ?? null,
+      maxDefaults: opts?.maxDefaults ?? null,
+      sellerBond: opts?.sellerBond ?? new anchor.BN(0),
+      minBidInterval: opts?.minBidInterval ?? 0,
+      blockSelfOutbid: opts?.blockSelfOutbid ?? false,
+      escrowTerms: opts?.escrowTerms ?? null,
+      payoutSplits: opts?.payoutSplits ?? [],
+      buyerPremiumBps: opts?.buyerPremiumBps ?? 0,
+    })
     .accountsStrict({
       seller: seller.publicKey,
       nftMint: nft.mint,
@@ -301,11 +582,14 @@ export async function setupAuction(
       escrowNftTokenAccount: escrowNftAta,
       auctionState,
       auctionVault,
+      unclaimedFunds: getUnclaimedFundsPDA(auctionState, program.programId)[0],
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
+      auctionHouse: opts?.auctionHouse ?? null,
+      curator: opts?.curator?.publicKey ?? null,
     })
-    .signers([seller])
+    .signers(opts?.curator ? [seller, opts.curator] : [seller])
     .rpc();
 
   return {
@@ -323,6 +607,59 @@ export async function setupAuction(
 // Utilities
 // ---------------------------------------------------------------------------
 
+/** sha256(reserve_le_u64 || salt) — matches the on-chain hidden reserve commitment. */
+export function hashReserve(reserve: anchor.BN, salt: Buffer): number[] {
+  return Array.from(
+    createHash("sha256")
+      .update(reserve.toArrayLike(Buffer, "le", 8))
+      .update(salt)
+      .digest()
+  );
+}
+
+/**
+ * Sorted-pair sha256 Merkle tree over wallets — matches the on-chain
+ * allowlist: leaf = sha256(0x00 || wallet), node = sha256(0x01 || lo || hi).
+ */
+export function buildAllowlist(wallets: PublicKey[]): {
+  root: number[];
+  proof: (wallet: PublicKey) => number[][];
+} {
+  const sha = (...parts: Buffer[]) => {
+    const h = createHash("sha256");
+    parts.forEach((p) => h.update(p));
+    return h.digest();
+  };
+  const leaf = (w: PublicKey) => sha(Buffer.from([0]), w.toBuffer());
+  const parent = (a: Buffer, b: Buffer) =>
+    Buffer.compare(a, b) <= 0 ? sha(Buffer.from([1]), a, b) : sha(Buffer.from([1]), b, a);
+
+  const levels: Buffer[][] = [wallets.map(leaf)];
+  while (levels[levels.length - 1].length > 1) {
+    const prev = levels[levels.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < prev.length; i += 2) {
+      // An odd node out is promoted unchanged
+      next.push(i + 1 < prev.length ? parent(prev[i], prev[i + 1]) : prev[i]);
+    }
+    levels.push(next);
+  }
+
+  const proof = (wallet: PublicKey) => {
+    let index = wallets.findIndex((w) => w.equals(wallet));
+    if (index < 0) throw new Error("wallet not in allowlist");
+    const path: number[][] = [];
+    for (const level of levels.slice(0, -1)) {
+      const sibling = index ^ 1;
+      if (sibling < level.length) path.push(Array.from(level[sibling]));
+      index >>= 1;
+    }
+    return path;
+  };
+
+  return { root: Array.from(levels[levels.length - 1][0]), proof };
+}
+
 export function sleep(ms: number): Promise<void> {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
@@ -6,6 +6,8 @@ import {
   PublicKey,
   SystemProgram,
   LAMPORTS_PER_SOL,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -15,19 +17,42 @@ import {
   getAssociatedTokenAddress,
   createAssociatedTokenAccount,
   getAccount,
+  transfer,
 } from "@solana/spl-token";
 import { expect } from "chai";
 
 import {
   PROTOCOL_TREASURY,
+  DELEGATION_PROGRAM_ID,
   getAuctionPDA,
   getVaultPDA,
   getDepositPDA,
+  getBlockListPDA,
+  getDefaultRecordPDA,
+  getBidderProfilePDA,
+  getSellerProfilePDA,
+  getBidderAccountPDA,
+  getSessionPDA,
+  getGlobalSessionPDA,
+  getBidNoncePDA,
   getMetadataPDA,
+  getHousePDA,
+  getSalePDA,
+  getCreatorEarningsPDA,
+  getUnclaimedFundsPDA,
+  getGateClaimPDA,
+  getDelegationBufferPDA,
+  getDelegationRecordPDA,
+  getDelegationMetadataPDA,
   createTestNft,
+  createCollectionNft,
+  verifyCollectionItem,
   setupAuction,
   sleep,
   createMetadataV3Instruction,
+  hashReserve,
+  buildAllowlist,
+  AuctionSetup,
 } from "./helpers";
 
 // ---------------------------------------------------------------------------
@@ -80,7 +105,29 @@ describe("outcry", () => {
 
   it("creates an auction", async () => {
     await program.methods
-      .createAuction(reservePrice, durationSeconds, extensionSeconds, extensionWindow, minBidIncrement)
+      .createAuction({
+        reservePrice,
+        reserveCommitment: null,
+        durationSeconds,
+        extensionSeconds,
+        extensionWindow,
+        minBidIncrement,
+        scheduledStart: null,
+        antiSnipePolicy: { fixedAdd: {} },
+        maxExtensionSeconds: 3600,
+        bidIncrement: { flat: {} },
+        allowlistRoot: null,
+        collectionGate: null,
+        banDefaulters: false,
+        minReputation: null,
+        maxDefaults: null,
+        sellerBond: new anchor.BN(0),
+        minBidInterval: 0,
+        blockSelfOutbid: false,
+        escrowTerms: null,
+        payoutSplits: [],
+        buyerPremiumBps: 0,
+      })
       .accountsStrict({
         seller: seller.publicKey,
         nftMint,
@@ -88,9 +135,12 @@ describe("outcry", () => {
         escrowNftTokenAccount: escrowNftAta,
         auctionState,
         auctionVault,
+        unclaimedFunds: getUnclaimedFundsPDA(auctionState, program.programId)[0],
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        auctionHouse: null,
+        curator: null,
       })
       .signers([seller])
       .rpc();
@@ -115,13 +165,19 @@ describe("outcry", () => {
 
     const [bidder1Deposit] = getDepositPDA(auctionState, bidder1.publicKey, program.programId);
     await program.methods
-      .deposit(depositAmount)
+      .deposit(depositAmount, [])
       .accountsStrict({
         bidder: bidder1.publicKey,
         auctionState,
         bidderDeposit: bidder1Deposit,
         auctionVault,
         systemProgram: SystemProgram.programId,
+        gateTokenAccount: null,
+        gateMetadata: null,
+        gateClaim: null,
+        blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+        defaultRecord: getDefaultRecordPDA(bidder1.publicKey, program.programId)[0],
+        bidderProfile: getBidderProfilePDA(bidder1.publicKey, program.programId)[0],
       })
       .signers([bidder1])
       .rpc();
@@ -132,13 +188,19 @@ describe("outcry", () => {
 
     const [bidder2Deposit] = getDepositPDA(auctionState, bidder2.publicKey, program.programId);
     await program.methods
-      .deposit(depositAmount)
+      .deposit(depositAmount, [])
       .accountsStrict({
         bidder: bidder2.publicKey,
         auctionState,
         bidderDeposit: bidder2Deposit,
         auctionVault,
         systemProgram: SystemProgram.programId,
+        gateTokenAccount: null,
+        gateMetadata: null,
+        gateClaim: null,
+        blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+        defaultRecord: getDefaultRecordPDA(bidder2.publicKey, program.programId)[0],
+        bidderProfile: getBidderProfilePDA(bidder2.publicKey, program.programId)[0],
       })
       .signers([bidder2])
       .rpc();
@@ -163,8 +225,18 @@ describe("outcry", () => {
 
   it("places bids (deposit check deferred to settlement)", async () => {
     await program.methods
-      .placeBid(reservePrice)
-      .accountsStrict({ bidder: bidder1.publicKey, auctionState })
+      .placeBid(reservePrice, null)
+      .accountsStrict({
+        bidder: bidder1.publicKey,
+        auctionState,
+        bidderDeposit: null,
+        blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+        defaultRecord: getDefaultRecordPDA(bidder1.publicKey, program.programId)[0],
+        bidderProfile: getBidderProfilePDA(bidder1.publicKey, program.programId)[0],
+        previousLot: null,
+        bidderAccount: null,
+        outbidBidderAccount: null,
+      })
       .signers([bidder1])
       .rpc();
 
@@ -175,8 +247,18 @@ describe("outcry", () => {
 
     const bid2Amount = new anchor.BN(1.2 * LAMPORTS_PER_SOL);
     await program.methods
-      .placeBid(bid2Amount)
-      .accountsStrict({ bidder: bidder2.publicKey, auctionState })
+      .placeBid(bid2Amount, null)
+      .accountsStrict({
+        bidder: bidder2.publicKey,
+        auctionState,
+        bidderDeposit: null,
+        blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+        defaultRecord: getDefaultRecordPDA(bidder2.publicKey, program.programId)[0],
+        bidderProfile: getBidderProfilePDA(bidder2.publicKey, program.programId)[0],
+        previousLot: null,
+        bidderAccount: null,
+        outbidBidderAccount: null,
+      })
       .signers([bidder2])
       .rpc();
 
@@ -190,8 +272,18 @@ describe("outcry", () => {
     const lowBid = new anchor.BN(1.25 * LAMPORTS_PER_SOL);
     try {
       await program.methods
-        .placeBid(lowBid)
-        .accountsStrict({ bidder: bidder1.publicKey, auctionState })
+        .placeBid(lowBid, null)
+        .accountsStrict({
+          bidder: bidder1.publicKey,
+          auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(bidder1.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(bidder1.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([bidder1])
         .rpc();
       expect.fail("Should have thrown");
@@ -200,12 +292,31 @@ describe("outcry", () => {
     }
   });
 
+  it("reports the next minimum bid for clients", async () => {
+    const minNext = await program.methods
+      .getMinNextBid()
+      .accountsStrict({ auctionState })
+      .view();
+    // current 1.2 SOL + flat 0.1 SOL increment
+    expect(minNext.toNumber()).to.equal(1.3 * LAMPORTS_PER_SOL);
+  });
+
   it("rejects seller bidding on own auction", async () => {
     const bid = new anchor.BN(2 * LAMPORTS_PER_SOL);
     try {
       await program.methods
-        .placeBid(bid)
-        .accountsStrict({ bidder: seller.publicKey, auctionState })
+        .placeBid(bid, null)
+        .accountsStrict({
+          bidder: seller.publicKey,
+          auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(seller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(seller.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(seller.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([seller])
         .rpc();
       expect.fail("Should have thrown");
@@ -269,9 +380,19 @@ describe("outcry", () => {
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
+        auctionHouse: null,
+        houseFeeRecipient: null,
+        proceedsEscrow: null,
+        winnerBidderAccount: null,
+        winnerProfile: getBidderProfilePDA(bidder2.publicKey, program.programId)[0],
+        sellerProfile: getSellerProfilePDA(seller.publicKey, program.programId)[0],
       })
       .remainingAccounts([
-        { pubkey: seller.publicKey, isSigner: false, isWritable: true },
+        {
+          pubkey: getCreatorEarningsPDA(seller.publicKey, program.programId)[0],
+          isSigner: false,
+          isWritable: true,
+        },
       ])
       .signers([seller])
       .rpc();
@@ -287,9 +408,9 @@ describe("outcry", () => {
 
     // Winning bid = 1.2 SOL. Seller == creator == treasury in this test.
     // Protocol fee = 1.2 SOL * 250/10000 = 0.03 SOL → treasury (== seller)
-    // Royalties = 1.2 SOL * 500/10000 = 0.06 SOL → creator (== seller)
+    // Royalties = 1.2 SOL * 500/10000 = 0.06 SOL → creator (== seller) earnings PDA
     // seller_receives = 1.2 - 0.03 - 0.06 = 1.11 SOL
-    // Total to seller = 1.11 + 0.06 + 0.03 = 1.2 SOL (minus tx fee)
+    // Total to seller = 1.11 + 0.03 = 1.14 SOL (minus tx fee and earnings PDA rent)
     const sellerBalAfter = await connection.getBalance(seller.publicKey);
     const sellerGain = sellerBalAfter - sellerBalBefore;
     expect(sellerGain).to.be.greaterThan(1.1 * LAMPORTS_PER_SOL);
@@ -304,6 +425,16 @@ describe("outcry", () => {
     expect(depositAccount.amount.toNumber()).to.equal(1.8 * LAMPORTS_PER_SOL);
   });
 
+  it("keeps the vault ledger solvent after settlement", async () => {
+    const vault = await program.account.auctionVault.fetch(auctionVault);
+    expect(vault.totalDeposited.toNumber()).to.equal(6 * LAMPORTS_PER_SOL);
+    expect(vault.totalPaidOut.toNumber()).to.equal(1.2 * LAMPORTS_PER_SOL);
+    expect(vault.totalRefunded.toNumber()).to.equal(0);
+
+    // Throws VaultInsolvent if lamports < rent + outstanding deposits
+    await program.methods.checkSolvency().accountsStrict({ auctionVault }).rpc();
+  });
+
   it("allows winner to claim remaining deposit", async () => {
     const winnerBalBefore = await connection.getBalance(bidder2.publicKey);
     const [bidder2Deposit] = getDepositPDA(auctionState, bidder2.publicKey, program.programId);
@@ -316,6 +447,7 @@ describe("outcry", () => {
         bidderDeposit: bidder2Deposit,
         auctionVault,
         systemProgram: SystemProgram.programId,
+        bidderProfile: getBidderProfilePDA(bidder2.publicKey, program.programId)[0],
       })
       .signers([bidder2])
       .rpc();
@@ -337,6 +469,7 @@ describe("outcry", () => {
         bidderDeposit: bidder1Deposit,
         auctionVault,
         systemProgram: SystemProgram.programId,
+        bidderProfile: getBidderProfilePDA(bidder1.publicKey, program.programId)[0],
       })
       .signers([bidder1])
       .rpc();
@@ -398,7 +531,29 @@ describe("outcry", () => {
       cancelEscrowNftAta = await getAssociatedTokenAddress(cancelNftMint, cancelAuctionState, true);
 
       await program.methods
-        .createAuction(reservePrice, new anchor.BN(60), extensionSeconds, extensionWindow, minBidIncrement)
+        .createAuction({
+          reservePrice,
+          reserveCommitment: null,
+          durationSeconds: new anchor.BN(60),
+          extensionSeconds,
+          extensionWindow,
+          minBidIncrement,
+          scheduledStart: null,
+          antiSnipePolicy: { fixedAdd: {} },
+          maxExtensionSeconds: 3600,
+          bidIncrement: { flat: {} },
+          allowlistRoot: null,
+          collectionGate: null,
+          banDefaulters: false,
+          minReputation: null,
+          maxDefaults: null,
+          sellerBond: new anchor.BN(0),
+          minBidInterval: 0,
+          blockSelfOutbid: false,
+          escrowTerms: null,
+          payoutSplits: [],
+          buyerPremiumBps: 0,
+        })
         .accountsStrict({
           seller: cancelSeller.publicKey,
           nftMint: cancelNftMint,
@@ -406,9 +561,12 @@ describe("outcry", () => {
           escrowNftTokenAccount: cancelEscrowNftAta,
           auctionState: cancelAuctionState,
           auctionVault: cancelAuctionVault,
+          unclaimedFunds: getUnclaimedFundsPDA(cancelAuctionState, program.programId)[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          curator: null,
         })
         .signers([cancelSeller])
         .rpc();
@@ -423,10 +581,14 @@ describe("outcry", () => {
         .accountsStrict({
           seller: cancelSeller.publicKey,
           auctionState: cancelAuctionState,
+          auctionVault: cancelAuctionVault,
+          sellerProfile: getSellerProfilePDA(cancelSeller.publicKey, program.programId)[0],
+          protocolTreasury: PROTOCOL_TREASURY,
           nftMint: cancelNftMint,
           escrowNftTokenAccount: cancelEscrowNftAta,
           sellerNftTokenAccount: cancelSellerNftAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .signers([cancelSeller])
         .rpc();
@@ -496,12 +658,29 @@ describe("outcry", () => {
       forfeitEscrowNftAta = await getAssociatedTokenAddress(forfeitNftMint, forfeitAuctionState, true);
 
       await program.methods
-        .createAuction(
-          new anchor.BN(1 * LAMPORTS_PER_SOL),
-          new anchor.BN(5),
-          2, 2,
-          new anchor.BN(0.1 * LAMPORTS_PER_SOL)
-        )
+        .createAuction({
+          reservePrice: new anchor.BN(1 * LAMPORTS_PER_SOL),
+          reserveCommitment: null,
+          durationSeconds: new anchor.BN(5),
+          extensionSeconds: 2,
+          extensionWindow: 2,
+          minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          scheduledStart: null,
+          antiSnipePolicy: { fixedAdd: {} },
+          maxExtensionSeconds: 3600,
+          bidIncrement: { flat: {} },
+          allowlistRoot: null,
+          collectionGate: null,
+          banDefaulters: false,
+          minReputation: null,
+          maxDefaults: null,
+          sellerBond: new anchor.BN(0),
+          minBidInterval: 0,
+          blockSelfOutbid: false,
+          escrowTerms: null,
+          payoutSplits: [],
+          buyerPremiumBps: 0,
+        })
         .accountsStrict({
           seller: forfeitSeller.publicKey,
           nftMint: forfeitNftMint,
@@ -509,9 +688,12 @@ describe("outcry", () => {
           escrowNftTokenAccount: forfeitEscrowNftAta,
           auctionState: forfeitAuctionState,
           auctionVault: forfeitAuctionVault,
+          unclaimedFunds: getUnclaimedFundsPDA(forfeitAuctionState, program.programId)[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          curator: null,
         })
         .signers([forfeitSeller])
         .rpc();
@@ -519,13 +701,19 @@ describe("outcry", () => {
       // Griefer deposits only 0.5 SOL (less than the 1 SOL reserve)
       const [grieferDeposit] = getDepositPDA(forfeitAuctionState, griefer.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(0.5 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: griefer.publicKey,
           auctionState: forfeitAuctionState,
           bidderDeposit: grieferDeposit,
           auctionVault: forfeitAuctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(forfeitSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(griefer.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(griefer.publicKey, program.programId)[0],
         })
         .signers([griefer])
         .rpc();
@@ -538,8 +726,18 @@ describe("outcry", () => {
 
       // Griefer bids 1 SOL but only deposited 0.5 SOL
       await program.methods
-        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: griefer.publicKey, auctionState: forfeitAuctionState })
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: griefer.publicKey,
+          auctionState: forfeitAuctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(forfeitSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(griefer.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(griefer.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([griefer])
         .rpc();
 
@@ -577,6 +775,12 @@ describe("outcry", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            houseFeeRecipient: null,
+            proceedsEscrow: null,
+            winnerBidderAccount: null,
+            winnerProfile: getBidderProfilePDA(griefer.publicKey, program.programId)[0],
+            sellerProfile: getSellerProfilePDA(forfeitSeller.publicKey, program.programId)[0],
           })
           .signers([forfeitSeller])
           .rpc();
@@ -597,6 +801,7 @@ describe("outcry", () => {
           auctionState: forfeitAuctionState,
           auctionVault: forfeitAuctionVault,
           winnerDeposit: grieferDeposit,
+          winnerBidderAccount: getBidderAccountPDA(griefer.publicKey, program.programId)[0],
           seller: forfeitSeller.publicKey,
           nftMint: forfeitNftMint,
           escrowNftTokenAccount: forfeitEscrowNftAta,
@@ -604,6 +809,8 @@ describe("outcry", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          winnerDefaultRecord: getDefaultRecordPDA(griefer.publicKey, program.programId)[0],
+          winnerProfile: getBidderProfilePDA(griefer.publicKey, program.programId)[0],
         })
         .signers([forfeitSeller])
         .rpc();
@@ -622,6 +829,47 @@ describe("outcry", () => {
 
       const deposit = await program.account.bidderDeposit.fetch(grieferDeposit);
       expect(deposit.amount.toNumber()).to.equal(0);
+
+      const [recordPda] = getDefaultRecordPDA(griefer.publicKey, program.programId);
+      const record = await program.account.defaultRecord.fetch(recordPda);
+      expect(record.defaults).to.equal(1);
+
+      // forfeit_auction also opened a profile for the defaulter, mirroring the record
+      const [profilePda] = getBidderProfilePDA(griefer.publicKey, program.programId);
+      const profile = await program.account.bidderProfile.fetch(profilePda);
+      expect(profile.defaults).to.equal(1);
+      expect(profile.forfeitedAmount.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+      expect(profile.reputation).to.equal(0);
+    });
+
+    it("refuses the defaulter on auctions that allow no defaults", async () => {
+      const setup = await setupAuction(program, connection, forfeitSeller, {
+        durationSeconds: new anchor.BN(300),
+        maxDefaults: 0,
+      });
+
+      try {
+        await program.methods
+          .deposit(new anchor.BN(0.5 * LAMPORTS_PER_SOL), [])
+          .accountsStrict({
+            bidder: griefer.publicKey,
+            auctionState: setup.auctionState,
+            bidderDeposit: getDepositPDA(setup.auctionState, griefer.publicKey, program.programId)[0],
+            auctionVault: setup.auctionVault,
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+            blocklist: getBlockListPDA(forfeitSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(griefer.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(griefer.publicKey, program.programId)[0],
+          })
+          .signers([griefer])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("TooManyDefaults");
+      }
     });
   });
 
@@ -666,12 +914,29 @@ describe("outcry", () => {
       smEscrow = await getAssociatedTokenAddress(smMint, smAuction, true);
 
       await program.methods
-        .createAuction(
-          winningBid, // 1 SOL reserve
-          new anchor.BN(5),
-          2, 2,
-          new anchor.BN(0.1 * LAMPORTS_PER_SOL)
-        )
+        .createAuction({
+          reservePrice: winningBid,
+          reserveCommitment: null,
+          durationSeconds: new anchor.BN(5),
+          extensionSeconds: 2,
+          extensionWindow: 2,
+          minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          scheduledStart: null,
+          antiSnipePolicy: { fixedAdd: {} },
+          maxExtensionSeconds: 3600,
+          bidIncrement: { flat: {} },
+          allowlistRoot: null,
+          collectionGate: null,
+          banDefaulters: false,
+          minReputation: null,
+          maxDefaults: null,
+          sellerBond: new anchor.BN(0),
+          minBidInterval: 0,
+          blockSelfOutbid: false,
+          escrowTerms: null,
+          payoutSplits: [],
+          buyerPremiumBps: 0,
+        })
         .accountsStrict({
           seller: smSeller.publicKey,
           nftMint: smMint,
@@ -679,29 +944,58 @@ describe("outcry", () => {
           escrowNftTokenAccount: smEscrow,
           auctionState: smAuction,
           auctionVault: smVault,
+          unclaimedFunds: getUnclaimedFundsPDA(smAuction, program.programId)[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          curator: null,
         })
         .signers([smSeller])
         .rpc();
 
       const auction = await program.account.auctionState.fetch(smAuction);
       expect(JSON.stringify(auction.status)).to.equal(JSON.stringify({ created: {} }));
+
+      await program.methods
+        .createSellerProfile()
+        .accountsStrict({
+          seller: smSeller.publicKey,
+          sellerProfile: getSellerProfilePDA(smSeller.publicKey, program.programId)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([smSeller])
+        .rpc();
     });
 
     it("setup: deposits, starts, bids, ends", async () => {
-      // Both bidders deposit 2 SOL
+      // Both bidders open profiles, then deposit 2 SOL
       for (const bidder of [smBidder, smLoser]) {
+        await program.methods
+          .createBidderProfile()
+          .accountsStrict({
+            bidder: bidder.publicKey,
+            bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([bidder])
+          .rpc();
+
         const [depositPda] = getDepositPDA(smAuction, bidder.publicKey, program.programId);
         await program.methods
-          .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL))
+          .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), [])
           .accountsStrict({
             bidder: bidder.publicKey,
             auctionState: smAuction,
             bidderDeposit: depositPda,
             auctionVault: smVault,
             systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+            blocklist: getBlockListPDA(smSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
           })
           .signers([bidder])
           .rpc();
@@ -715,8 +1009,18 @@ describe("outcry", () => {
 
       // Loser bids reserve
       await program.methods
-        .placeBid(winningBid)
-        .accountsStrict({ bidder: smLoser.publicKey, auctionState: smAuction })
+        .placeBid(winningBid, null)
+        .accountsStrict({
+          bidder: smLoser.publicKey,
+          auctionState: smAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(smSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(smLoser.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(smLoser.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([smLoser])
         .rpc();
 
@@ -738,8 +1042,18 @@ describe("outcry", () => {
       // Actually — the test already called placeBid for smLoser. Can't undo.
       // So smBidder now must bid >= 1.1 SOL:
       await program.methods
-        .placeBid(new anchor.BN(1.1 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: smBidder.publicKey, auctionState: smAuction })
+        .placeBid(new anchor.BN(1.1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: smBidder.publicKey,
+          auctionState: smAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(smSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(smBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(smBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([smBidder])
         .rpc();
 
@@ -757,7 +1071,7 @@ describe("outcry", () => {
 
     it("settles with correct three-way split", async () => {
       // winning_bid = 1.1 SOL = 1,100,000,000 lamports
-      // royalties = 1,100,000,000 * 500 / 10000 = 55,000,000 → creator
+      // royalties = 1,100,000,000 * 500 / 10000 = 55,000,000 → creator earnings PDA
       // protocol_fee = 1,100,000,000 * 250 / 10000 = 27,500,000 → treasury
       // seller_receives = 1,100,000,000 - 55,000,000 - 27,500,000 = 1,017,500,000 → seller
       const expectedRoyalties = 55_000_000;
@@ -765,7 +1079,7 @@ describe("outcry", () => {
       const expectedSellerReceives = 1_017_500_000;
 
       const sellerBalBefore = await connection.getBalance(smSeller.publicKey);
-      const creatorBalBefore = await connection.getBalance(smCreator.publicKey);
+      const [creatorEarnings] = getCreatorEarningsPDA(smCreator.publicKey, program.programId);
       const treasuryBalBefore = await connection.getBalance(PROTOCOL_TREASURY);
 
       const [winnerDeposit] = getDepositPDA(smAuction, smBidder.publicKey, program.programId);
@@ -788,17 +1102,23 @@ describe("outcry", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          houseFeeRecipient: null,
+          proceedsEscrow: null,
+          winnerBidderAccount: null,
+          winnerProfile: getBidderProfilePDA(smBidder.publicKey, program.programId)[0],
+          sellerProfile: getSellerProfilePDA(smSeller.publicKey, program.programId)[0],
         })
         .remainingAccounts([
-          { pubkey: smCreator.publicKey, isSigner: false, isWritable: true },
+          { pubkey: creatorEarnings, isSigner: false, isWritable: true },
         ])
         .signers([smSeller])
         .rpc();
 
-      // Verify exact lamport deltas
-      const creatorBalAfter = await connection.getBalance(smCreator.publicKey);
-      const creatorDelta = creatorBalAfter - creatorBalBefore;
-      expect(creatorDelta).to.equal(expectedRoyalties);
+      // Verify exact lamport deltas — royalties are credited, not pushed
+      const earnings = await program.account.creatorEarnings.fetch(creatorEarnings);
+      expect(earnings.creator.toBase58()).to.equal(smCreator.publicKey.toBase58());
+      expect(earnings.balance.toNumber()).to.equal(expectedRoyalties);
 
       const treasuryBalAfter = await connection.getBalance(PROTOCOL_TREASURY);
       const treasuryDelta = treasuryBalAfter - treasuryBalBefore;
@@ -809,14 +1129,43 @@ describe("outcry", () => {
       // Seller also pays tx fee and winner ATA rent, so use approximate check
       const sellerBalAfter = await connection.getBalance(smSeller.publicKey);
       const sellerDelta = sellerBalAfter - sellerBalBefore;
-      // seller_receives = 1,017,500,000, minus tx fee (~5000) and winner ATA rent (~2,039,280)
-      // Net should be roughly 1,015,455,720
+      // seller_receives = 1,017,500,000, minus tx fee (~5000), winner ATA rent (~2,039,280)
+      // and creator earnings PDA rent (~1,183,200)
       expect(sellerDelta).to.be.greaterThan(expectedSellerReceives - 5_000_000);
       expect(sellerDelta).to.be.lessThan(expectedSellerReceives + 1_000_000);
 
       // Verify winner's deposit was deducted: 2 SOL - 1.1 SOL = 0.9 SOL
       const deposit = await program.account.bidderDeposit.fetch(winnerDeposit);
       expect(deposit.amount.toNumber()).to.equal(0.9 * LAMPORTS_PER_SOL);
+
+      const [winnerProfile] = getBidderProfilePDA(smBidder.publicKey, program.programId);
+      const profile = await program.account.bidderProfile.fetch(winnerProfile);
+      expect(profile.auctionsWon).to.equal(1);
+      expect(profile.totalSpent.toNumber()).to.equal(1.1 * LAMPORTS_PER_SOL);
+      expect(profile.reputation).to.equal(10);
+
+      const [sellerProfile] = getSellerProfilePDA(smSeller.publicKey, program.programId);
+      const sellerStats = await program.account.sellerProfile.fetch(sellerProfile);
+      expect(sellerStats.completedSales).to.equal(1);
+      expect(sellerStats.totalVolume.toNumber()).to.equal(1.1 * LAMPORTS_PER_SOL);
+    });
+
+    it("creator withdraws credited royalties", async () => {
+      const [creatorEarnings] = getCreatorEarningsPDA(smCreator.publicKey, program.programId);
+      const creatorBalBefore = await connection.getBalance(smCreator.publicKey);
+
+      await program.methods
+        .withdrawCreatorEarnings()
+        .accountsStrict({ creator: smCreator.publicKey, creatorEarnings })
+        .signers([smCreator])
+        .rpc();
+
+      const creatorBalAfter = await connection.getBalance(smCreator.publicKey);
+      // Creator pays the tx fee
+      expect(creatorBalAfter - creatorBalBefore).to.be.greaterThan(55_000_000 - 10_000);
+      const earnings = await program.account.creatorEarnings.fetch(creatorEarnings);
+      expect(earnings.balance.toNumber()).to.equal(0);
+      expect(earnings.totalEarned.toNumber()).to.equal(55_000_000);
     });
 
     it("loser claims full refund", async () => {
@@ -831,6 +1180,7 @@ describe("outcry", () => {
           bidderDeposit: loserDeposit,
           auctionVault: smVault,
           systemProgram: SystemProgram.programId,
+          bidderProfile: getBidderProfilePDA(smLoser.publicKey, program.programId)[0],
         })
         .signers([smLoser])
         .rpc();
@@ -839,6 +1189,107 @@ describe("outcry", () => {
       const refund = loserBalAfter - loserBalBefore;
       // Full 2 SOL deposit returned (minus tx fee + rent reclaim from closing deposit PDA)
       expect(refund).to.be.greaterThan(1.9 * LAMPORTS_PER_SOL);
+
+      const [loserProfile] = getBidderProfilePDA(smLoser.publicKey, program.programId);
+      const profile = await program.account.bidderProfile.fetch(loserProfile);
+      expect(profile.auctionsParticipated).to.equal(1);
+      // Participation is counted but doesn't score — a refund doesn't prove a bid
+      expect(profile.reputation).to.equal(0);
+    });
+
+    it("caps royalties at what's left after fees", async () => {
+      // 100% royalty metadata: without the cap, fee + royalties exceed the bid
+      const setup = await setupAuction(program, connection, smSeller, {
+        mintAuthority: smCreator,
+        sellerFeeBps: 10_000,
+        creators: [{ address: smCreator.publicKey, verified: true, share: 100 }],
+      });
+      const [depositPda] = getDepositPDA(setup.auctionState, smBidder.publicKey, program.programId);
+      await program.methods
+        .deposit(new anchor.BN(1 * LAMPORTS_PER_SOL), [])
+        .accountsStrict({
+          bidder: smBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: depositPda,
+          auctionVault: setup.auctionVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(smSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(smBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(smBidder.publicKey, program.programId)[0],
+        })
+        .signers([smBidder])
+        .rpc();
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: smSeller.publicKey, auctionState: setup.auctionState })
+        .signers([smSeller])
+        .rpc();
+      await program.methods
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: smBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(smSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(smBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(smBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([smBidder])
+        .rpc();
+
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      const wait = auction.endTime.toNumber() - Math.floor(Date.now() / 1000) + 2;
+      if (wait > 0) await sleep(wait * 1000);
+      await program.methods
+        .endAuction()
+        .accountsStrict({ authority: smSeller.publicKey, auctionState: setup.auctionState })
+        .signers([smSeller])
+        .rpc();
+
+      const [creatorEarnings] = getCreatorEarningsPDA(smCreator.publicKey, program.programId);
+      const earnedBefore = (await program.account.creatorEarnings.fetch(creatorEarnings)).totalEarned;
+
+      await program.methods
+        .settleAuction()
+        .accountsStrict({
+          payer: smSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          winnerDeposit: depositPda,
+          seller: smSeller.publicKey,
+          winner: smBidder.publicKey,
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: setup.nftMint,
+          nftMetadata: setup.nftMetadata,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          winnerNftTokenAccount: await getAssociatedTokenAddress(setup.nftMint, smBidder.publicKey),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          houseFeeRecipient: null,
+          proceedsEscrow: null,
+          winnerBidderAccount: null,
+          winnerProfile: getBidderProfilePDA(smBidder.publicKey, program.programId)[0],
+          sellerProfile: getSellerProfilePDA(smSeller.publicKey, program.programId)[0],
+        })
+        .remainingAccounts([
+          { pubkey: creatorEarnings, isSigner: false, isWritable: true },
+        ])
+        .signers([smSeller])
+        .rpc();
+
+      // Royalties = 1 SOL - 2.5% protocol fee; the seller's remainder is zero
+      const earnings = await program.account.creatorEarnings.fetch(creatorEarnings);
+      expect(earnings.totalEarned.sub(earnedBefore).toNumber()).to.equal(975_000_000);
+      const settled = await program.account.auctionState.fetch(setup.auctionState);
+      expect(JSON.stringify(settled.status)).to.equal(JSON.stringify({ settled: {} }));
     });
   });
 
@@ -869,13 +1320,29 @@ describe("outcry", () => {
       snipeEscrow = await getAssociatedTokenAddress(snipeMint, snipeAuction, true);
 
       await program.methods
-        .createAuction(
-          new anchor.BN(0.5 * LAMPORTS_PER_SOL),
-          new anchor.BN(8), // 8 seconds
-          4, // extension_seconds
-          4, // extension_window
-          new anchor.BN(0.1 * LAMPORTS_PER_SOL)
-        )
+        .createAuction({
+          reservePrice: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+          reserveCommitment: null,
+          durationSeconds: new anchor.BN(8),
+          extensionSeconds: 4,
+          extensionWindow: 4,
+          minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          scheduledStart: null,
+          antiSnipePolicy: { fixedAdd: {} },
+          maxExtensionSeconds: 3600,
+          bidIncrement: { flat: {} },
+          allowlistRoot: null,
+          collectionGate: null,
+          banDefaulters: false,
+          minReputation: null,
+          maxDefaults: null,
+          sellerBond: new anchor.BN(0),
+          minBidInterval: 0,
+          blockSelfOutbid: false,
+          escrowTerms: null,
+          payoutSplits: [],
+          buyerPremiumBps: 0,
+        })
         .accountsStrict({
           seller: snipeSeller.publicKey,
           nftMint: snipeMint,
@@ -883,9 +1350,12 @@ describe("outcry", () => {
           escrowNftTokenAccount: snipeEscrow,
           auctionState: snipeAuction,
           auctionVault: snipeVault,
+          unclaimedFunds: getUnclaimedFundsPDA(snipeAuction, program.programId)[0],
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          curator: null,
         })
         .signers([snipeSeller])
         .rpc();
@@ -893,13 +1363,19 @@ describe("outcry", () => {
       // Bidder deposits
       const [depositPda] = getDepositPDA(snipeAuction, snipeBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(5 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(5 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: snipeBidder.publicKey,
           auctionState: snipeAuction,
           bidderDeposit: depositPda,
           auctionVault: snipeVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(snipeSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(snipeBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(snipeBidder.publicKey, program.programId)[0],
         })
         .signers([snipeBidder])
         .rpc();
@@ -917,8 +1393,18 @@ describe("outcry", () => {
       const endTimeBefore = auctionBefore.endTime.toNumber();
 
       await program.methods
-        .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: snipeBidder.publicKey, auctionState: snipeAuction })
+        .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: snipeBidder.publicKey,
+          auctionState: snipeAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(snipeSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(snipeBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(snipeBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([snipeBidder])
         .rpc();
 
@@ -943,14 +1429,25 @@ describe("outcry", () => {
       const endTimeBefore = (await program.account.auctionState.fetch(snipeAuction)).endTime.toNumber();
 
       await program.methods
-        .placeBid(new anchor.BN(0.7 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: snipeBidder.publicKey, auctionState: snipeAuction })
+        .placeBid(new anchor.BN(0.7 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: snipeBidder.publicKey,
+          auctionState: snipeAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(snipeSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(snipeBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(snipeBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([snipeBidder])
         .rpc();
 
       const auctionAfter = await program.account.auctionState.fetch(snipeAuction);
       // end_time should have been extended by extension_seconds (4)
       expect(auctionAfter.endTime.toNumber()).to.equal(endTimeBefore + 4);
+      expect(auctionAfter.extensionCount).to.equal(1);
     });
 
     it("auction ends correctly after extensions", async () => {
@@ -968,69 +1465,114 @@ describe("outcry", () => {
       const ended = await program.account.auctionState.fetch(snipeAuction);
       expect(JSON.stringify(ended.status)).to.equal(JSON.stringify({ ended: {} }));
     });
+
+    it("rejects MaxExtensions policy with a zero count", async () => {
+      try {
+        await setupAuction(program, connection, snipeSeller, {
+          antiSnipePolicy: { maxExtensions: { maxExtensions: 0 } },
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAntiSnipePolicy");
+      }
+    });
+
+    it("rejects an extension cap above the maximum", async () => {
+      try {
+        await setupAuction(program, connection, snipeSeller, {
+          maxExtensionSeconds: 86_401,
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidExtensionCap");
+      }
+    });
   });
 
   // =========================================================================
-  // Error paths — create_auction
+  // Sale events
   // =========================================================================
 
-  describe("error_paths_create_auction", () => {
-    const errSeller = Keypair.generate();
+  describe("sale_event", () => {
+    const organizer = Keypair.generate();
+    const saleId = new anchor.BN(1);
+    const stagger = 120;
+    let sale: PublicKey;
+    const lots: AuctionSetup[] = [];
 
     before(async () => {
-      const sig = await connection.requestAirdrop(errSeller.publicKey, 10 * LAMPORTS_PER_SOL);
+      const sig = await connection.requestAirdrop(organizer.publicKey, 10 * LAMPORTS_PER_SOL);
       await connection.confirmTransaction(sig);
-    });
+      [sale] = getSalePDA(organizer.publicKey, saleId, program.programId);
 
-    it("rejects reserve_price of 0", async () => {
-      const nft = await createTestNft(connection, errSeller);
-      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
-      const [av] = getVaultPDA(as, program.programId);
-      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
+      const startTime = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      await program.methods
+        .createSaleEvent(saleId, startTime, stagger)
+        .accountsStrict({
+          organizer: organizer.publicKey,
+          saleEvent: sale,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([organizer])
+        .rpc();
 
-      try {
+      for (let i = 0; i < 2; i++) {
+        const lot = await setupAuction(program, connection, organizer, {
+          durationSeconds: new anchor.BN(600),
+        });
         await program.methods
-          .createAuction(new anchor.BN(0), new anchor.BN(60), 2, 2, new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .addSaleLot()
           .accountsStrict({
-            seller: errSeller.publicKey,
-            nftMint: nft.mint,
-            sellerNftTokenAccount: nft.ownerAta,
-            escrowNftTokenAccount: escrow,
-            auctionState: as,
-            auctionVault: av,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
+            organizer: organizer.publicKey,
+            saleEvent: sale,
+            auctionState: lot.auctionState,
           })
-          .signers([errSeller])
+          .signers([organizer])
           .rpc();
-        expect.fail("Should have thrown");
-      } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidReservePrice");
+        lots.push(lot);
       }
     });
 
-    it("rejects duration below minimum (4s)", async () => {
-      const nft = await createTestNft(connection, errSeller);
-      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
-      const [av] = getVaultPDA(as, program.programId);
-      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
+    it("staggers lots behind the shared start time", async () => {
+      const saleEvent = await program.account.saleEvent.fetch(sale);
+      expect(saleEvent.lots.map((l) => l.toBase58())).to.deep.equal(
+        lots.map((l) => l.auctionState.toBase58())
+      );
+
+      const second = await program.account.auctionState.fetch(lots[1].auctionState);
+      expect(second.saleEvent.toBase58()).to.equal(sale.toBase58());
+      expect(second.lotIndex).to.equal(1);
+      expect(second.scheduledStart.toNumber()).to.equal(saleEvent.startTime.toNumber());
+      expect(second.durationSeconds.toNumber()).to.equal(600 + stagger);
+    });
+
+    it("returns the catalog in closing order", async () => {
+      const catalog = await program.methods
+        .getSaleCatalog()
+        .accountsStrict({ saleEvent: sale })
+        .remainingAccounts(
+          lots.map((l) => ({ pubkey: l.auctionState, isSigner: false, isWritable: false }))
+        )
+        .view();
+      expect(catalog.length).to.equal(2);
+      expect(catalog[1].lotIndex).to.equal(1);
+      expect(JSON.stringify(catalog[0].status)).to.equal(JSON.stringify({ created: {} }));
+    });
 
+    it("rejects a lot whose staggered duration exceeds the cap", async () => {
+      // Third lot closes 2 staggers after the first: 604,700 + 240 > 7 days
+      const lot = await setupAuction(program, connection, organizer, {
+        durationSeconds: new anchor.BN(604_700),
+      });
       try {
         await program.methods
-          .createAuction(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(4), 2, 2, new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .addSaleLot()
           .accountsStrict({
-            seller: errSeller.publicKey,
-            nftMint: nft.mint,
-            sellerNftTokenAccount: nft.ownerAta,
-            escrowNftTokenAccount: escrow,
-            auctionState: as,
-            auctionVault: av,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
+            organizer: organizer.publicKey,
+            saleEvent: sale,
+            auctionState: lot.auctionState,
           })
-          .signers([errSeller])
+          .signers([organizer])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
@@ -1038,284 +1580,2694 @@ describe("outcry", () => {
       }
     });
 
-    it("rejects duration above maximum", async () => {
-      const nft = await createTestNft(connection, errSeller);
-      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
-      const [av] = getVaultPDA(as, program.programId);
-      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
-
+    it("rejects a stagger above the maximum", async () => {
+      const badId = new anchor.BN(2);
       try {
         await program.methods
-          .createAuction(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(604801), 2, 2, new anchor.BN(0.1 * LAMPORTS_PER_SOL))
+          .createSaleEvent(badId, new anchor.BN(Math.floor(Date.now() / 1000) + 3600), 3_601)
           .accountsStrict({
-            seller: errSeller.publicKey,
-            nftMint: nft.mint,
-            sellerNftTokenAccount: nft.ownerAta,
-            escrowNftTokenAccount: escrow,
-            auctionState: as,
-            auctionVault: av,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            organizer: organizer.publicKey,
+            saleEvent: getSalePDA(organizer.publicKey, badId, program.programId)[0],
             systemProgram: SystemProgram.programId,
           })
-          .signers([errSeller])
+          .signers([organizer])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidDuration");
+        expect(err.error.errorCode.code).to.equal("InvalidSaleStagger");
       }
     });
 
-    it("rejects min_bid_increment of 0", async () => {
-      const nft = await createTestNft(connection, errSeller);
-      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
-      const [av] = getVaultPDA(as, program.programId);
-      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
+    describe("closing order", () => {
+      const saleBidder = Keypair.generate();
+      const orderId = new anchor.BN(3);
+      const orderStagger = 4;
+      let orderSale: PublicKey;
+      let saleStart: number;
+      const orderLots: AuctionSetup[] = [];
+
+      const bidOnLot = (lot: AuctionSetup, previousLot: PublicKey | null) =>
+        program.methods
+          .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+          .accountsStrict({
+            bidder: saleBidder.publicKey,
+            auctionState: lot.auctionState,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(organizer.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(saleBidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(saleBidder.publicKey, program.programId)[0],
+            previousLot,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([saleBidder])
+          .rpc();
 
-      try {
+      const waitUntil = async (unix: number) => {
+        const wait = unix - Math.floor(Date.now() / 1000);
+        if (wait > 0) await sleep(wait * 1000);
+      };
+
+      before(async () => {
+        const sig = await connection.requestAirdrop(saleBidder.publicKey, 2 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+        [orderSale] = getSalePDA(organizer.publicKey, orderId, program.programId);
+
+        saleStart = Math.floor(Date.now() / 1000) + 20;
         await program.methods
-          .createAuction(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(60), 2, 2, new anchor.BN(0))
+          .createSaleEvent(orderId, new anchor.BN(saleStart), orderStagger)
           .accountsStrict({
-            seller: errSeller.publicKey,
-            nftMint: nft.mint,
-            sellerNftTokenAccount: nft.ownerAta,
-            escrowNftTokenAccount: escrow,
-            auctionState: as,
-            auctionVault: av,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            organizer: organizer.publicKey,
+            saleEvent: orderSale,
             systemProgram: SystemProgram.programId,
           })
-          .signers([errSeller])
+          .signers([organizer])
           .rpc();
-        expect.fail("Should have thrown");
-      } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidBidIncrement");
-      }
-    });
 
-    it("rejects non-NFT mint (decimals > 0)", async () => {
-      // Create a fungible token mint with 6 decimals
-      const fungibleMint = await createMint(connection, errSeller, errSeller.publicKey, null, 6);
-      const fungibleAta = await createAssociatedTokenAccount(connection, errSeller, fungibleMint, errSeller.publicKey);
-      await mintTo(connection, errSeller, fungibleMint, fungibleAta, errSeller, 1);
+        // Lot 0 closes at start + 12 and extends by 10s on bids in its last 10s
+        for (let i = 0; i < 2; i++) {
+          const lot = await setupAuction(program, connection, organizer, {
+            durationSeconds: new anchor.BN(12),
+            extensionSeconds: 10,
+            extensionWindow: 10,
+          });
+          await program.methods
+            .addSaleLot()
+            .accountsStrict({
+              organizer: organizer.publicKey,
+              saleEvent: orderSale,
+              auctionState: lot.auctionState,
+            })
+            .signers([organizer])
+            .rpc();
+          orderLots.push(lot);
+        }
+        await waitUntil(saleStart + 1);
+      });
 
-      const [as] = getAuctionPDA(errSeller.publicKey, fungibleMint, program.programId);
-      const [av] = getVaultPDA(as, program.programId);
-      const escrow = await getAssociatedTokenAddress(fungibleMint, as, true);
+      it("anchors lots to the sale start even when started out of order", async () => {
+        await program.methods
+          .startAuction()
+          .accountsStrict({ seller: organizer.publicKey, auctionState: orderLots[1].auctionState })
+          .signers([organizer])
+          .rpc();
+
+        const second = await program.account.auctionState.fetch(orderLots[1].auctionState);
+        expect(second.startTime.toNumber()).to.equal(saleStart);
+        expect(second.endTime.toNumber()).to.equal(saleStart + 12 + orderStagger);
+      });
+
+      it("won't end a lot whose predecessor never started", async () => {
+        try {
+          await program.methods
+            .advanceSaleLot()
+            .accountsStrict({
+              authority: organizer.publicKey,
+              saleEvent: orderSale,
+              previousLot: orderLots[0].auctionState,
+              bidderAccount: null,
+              outbidBidderAccount: null,
+              auctionState: orderLots[1].auctionState,
+            })
+            .signers([organizer])
+            .rpc();
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error.errorCode.code).to.equal("SaleLotOutOfOrder");
+        }
 
-      try {
         await program.methods
-          .createAuction(new anchor.BN(LAMPORTS_PER_SOL), new anchor.BN(60), 2, 2, new anchor.BN(0.1 * LAMPORTS_PER_SOL))
-          .accountsStrict({
-            seller: errSeller.publicKey,
-            nftMint: fungibleMint,
-            sellerNftTokenAccount: fungibleAta,
-            escrowNftTokenAccount: escrow,
-            auctionState: as,
-            auctionVault: av,
-            tokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([errSeller])
+          .startAuction()
+          .accountsStrict({ seller: organizer.publicKey, auctionState: orderLots[0].auctionState })
+          .signers([organizer])
           .rpc();
-        expect.fail("Should have thrown");
-      } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidNftMint");
-      }
+        const first = await program.account.auctionState.fetch(orderLots[0].auctionState);
+        expect(first.startTime.toNumber()).to.equal(saleStart);
+      });
+
+      it("requires the previous lot to bid on a later lot", async () => {
+        try {
+          await bidOnLot(orderLots[1], null);
+          expect.fail("Should have thrown");
+        } catch (err: any) {
+          expect(err.error.errorCode.code).to.equal("InvalidSaleLot");
+        }
+      });
+
+      it("keeps a later lot open past its own close while the lot ahead is extended", async () => {
+        // Inside lot 0's window: pushes it to start + 22
+        await waitUntil(saleStart + 3);
+        await bidOnLot(orderLots[0], null);
+        const first = await program.account.auctionState.fetch(orderLots[0].auctionState);
+        expect(first.endTime.toNumber()).to.equal(saleStart + 22);
+
+        // Lot 1 was due to close at start + 16 but still takes bids
+        await waitUntil(saleStart + 17);
+        await bidOnLot(orderLots[1], orderLots[0].auctionState);
+
+        const second = await program.account.auctionState.fetch(orderLots[1].auctionState);
+        expect(second.currentBid.toNumber()).to.equal(LAMPORTS_PER_SOL);
+        expect(second.endTime.toNumber()).to.equal(first.endTime.toNumber() + orderStagger);
+      });
     });
   });
 
   // =========================================================================
-  // Error paths — deposit
+  // Hidden reserve
   // =========================================================================
 
-  describe("error_paths_deposit", () => {
-    const depSeller = Keypair.generate();
-    const depBidder = Keypair.generate();
-    let depAuction: PublicKey;
-    let depVault: PublicKey;
+  describe("hidden_reserve", () => {
+    const hrSeller = Keypair.generate();
+    const hrBidder = Keypair.generate();
+    const hiddenReserve = new anchor.BN(2 * LAMPORTS_PER_SOL);
+    const salt = Buffer.alloc(32, 7);
+    let setup: AuctionSetup;
+    let hrDeposit: PublicKey;
 
     before(async () => {
-      for (const kp of [depSeller, depBidder]) {
+      for (const kp of [hrSeller, hrBidder]) {
         const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
         await connection.confirmTransaction(sig);
       }
 
-      const setup = await setupAuction(program, connection, depSeller, {
-        durationSeconds: new anchor.BN(5),
-      });
-      depAuction = setup.auctionState;
-      depVault = setup.auctionVault;
-    });
-
-    it("rejects deposit of 0 lamports", async () => {
-      const [depositPda] = getDepositPDA(depAuction, depBidder.publicKey, program.programId);
-      try {
-        await program.methods
-          .deposit(new anchor.BN(0))
-          .accountsStrict({
-            bidder: depBidder.publicKey,
-            auctionState: depAuction,
-            bidderDeposit: depositPda,
-            auctionVault: depVault,
-            systemProgram: SystemProgram.programId,
-          })
-          .signers([depBidder])
-          .rpc();
-        expect.fail("Should have thrown");
-      } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidDepositAmount");
-      }
-    });
-
-    it("rejects deposit after settlement", async () => {
-      // Run through full lifecycle to settlement
-      const winner = Keypair.generate();
-      const sig = await connection.requestAirdrop(winner.publicKey, 10 * LAMPORTS_PER_SOL);
-      await connection.confirmTransaction(sig);
-
-      // Create a separate auction for settlement test
-      const setup2 = await setupAuction(program, connection, depSeller, {
-        durationSeconds: new anchor.BN(5),
-        sellerFeeBps: 0,
-        creators: [],
+      // Opens at 0.5 SOL, real reserve of 2 SOL stays hidden
+      setup = await setupAuction(program, connection, hrSeller, {
+        reservePrice: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+        reserveCommitment: hashReserve(hiddenReserve, salt),
       });
 
-      // Deposit
-      const [winnerDep] = getDepositPDA(setup2.auctionState, winner.publicKey, program.programId);
+      [hrDeposit] = getDepositPDA(setup.auctionState, hrBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(1 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
-          bidder: winner.publicKey,
-          auctionState: setup2.auctionState,
-          bidderDeposit: winnerDep,
-          auctionVault: setup2.auctionVault,
+          bidder: hrBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: hrDeposit,
+          auctionVault: setup.auctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(hrSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(hrBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(hrBidder.publicKey, program.programId)[0],
         })
-        .signers([winner])
+        .signers([hrBidder])
         .rpc();
 
-      // Start, bid, wait, end, settle
       await program.methods
         .startAuction()
-        .accountsStrict({ seller: depSeller.publicKey, auctionState: setup2.auctionState })
-        .signers([depSeller])
+        .accountsStrict({ seller: hrSeller.publicKey, auctionState: setup.auctionState })
+        .signers([hrSeller])
         .rpc();
+    });
 
+    it("accepts an opening bid below the hidden reserve", async () => {
       await program.methods
-        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: winner.publicKey, auctionState: setup2.auctionState })
-        .signers([winner])
+        .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: hrBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(hrSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(hrBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(hrBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([hrBidder])
         .rpc();
 
-      const auction = await program.account.auctionState.fetch(setup2.auctionState);
-      const now = Math.floor(Date.now() / 1000);
-      const wait = auction.endTime.toNumber() - now + 2;
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      expect(auction.currentBid.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+    });
+
+    it("rejects a reveal that doesn't match the commitment", async () => {
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      const wait = auction.endTime.toNumber() - Math.floor(Date.now() / 1000) + 2;
       if (wait > 0) await sleep(wait * 1000);
 
       await program.methods
         .endAuction()
-        .accountsStrict({ authority: depSeller.publicKey, auctionState: setup2.auctionState })
-        .signers([depSeller])
+        .accountsStrict({ authority: hrSeller.publicKey, auctionState: setup.auctionState })
+        .signers([hrSeller])
         .rpc();
 
-      const winnerNftAta = await getAssociatedTokenAddress(setup2.nftMint, winner.publicKey);
+      try {
+        await program.methods
+          .revealReserve(new anchor.BN(0.4 * LAMPORTS_PER_SOL), Array.from(salt))
+          .accountsStrict({
+            seller: hrSeller.publicKey,
+            auctionState: setup.auctionState,
+            nftMint: setup.nftMint,
+            escrowNftTokenAccount: setup.escrowNftAta,
+            sellerNftTokenAccount: setup.sellerNftAta,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([hrSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidReserveReveal");
+      }
+    });
+
+    it("reveals reserve above top bid — NFT returned, refunds open", async () => {
       await program.methods
-        .settleAuction()
+        .revealReserve(hiddenReserve, Array.from(salt))
         .accountsStrict({
-          payer: depSeller.publicKey,
-          auctionState: setup2.auctionState,
-          auctionVault: setup2.auctionVault,
-          winnerDeposit: winnerDep,
-          seller: depSeller.publicKey,
-          winner: winner.publicKey,
-          protocolTreasury: PROTOCOL_TREASURY,
-          nftMint: setup2.nftMint,
-          nftMetadata: setup2.nftMetadata,
-          escrowNftTokenAccount: setup2.escrowNftAta,
-          winnerNftTokenAccount: winnerNftAta,
+          seller: hrSeller.publicKey,
+          auctionState: setup.auctionState,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          sellerNftTokenAccount: setup.sellerNftAta,
           tokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([hrSeller])
+        .rpc();
+
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      expect(JSON.stringify(auction.status)).to.equal(JSON.stringify({ reserveNotMet: {} }));
+
+      const sellerNft = await getAccount(connection, setup.sellerNftAta);
+      expect(Number(sellerNft.amount)).to.equal(1);
+
+      const balBefore = await connection.getBalance(hrBidder.publicKey);
+      await program.methods
+        .claimRefund()
+        .accountsStrict({
+          bidder: hrBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: hrDeposit,
+          auctionVault: setup.auctionVault,
           systemProgram: SystemProgram.programId,
+          bidderProfile: getBidderProfilePDA(hrBidder.publicKey, program.programId)[0],
         })
-        .signers([depSeller])
+        .signers([hrBidder])
         .rpc();
+      const balAfter = await connection.getBalance(hrBidder.publicKey);
+      expect(balAfter - balBefore).to.be.greaterThan(0.99 * LAMPORTS_PER_SOL);
+    });
+  });
+
+  // =========================================================================
+  // Error paths — create_auction
+  // =========================================================================
+
+  describe("error_paths_create_auction", () => {
+    const errSeller = Keypair.generate();
+
+    before(async () => {
+      const sig = await connection.requestAirdrop(errSeller.publicKey, 10 * LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+    });
+
+    it("rejects reserve_price of 0", async () => {
+      const nft = await createTestNft(connection, errSeller);
+      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
+      const [av] = getVaultPDA(as, program.programId);
+      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
 
-      // Now try to deposit on the settled auction
-      const [newDepPda] = getDepositPDA(setup2.auctionState, depBidder.publicKey, program.programId);
       try {
         await program.methods
-          .deposit(new anchor.BN(LAMPORTS_PER_SOL))
+          .createAuction({
+            reservePrice: new anchor.BN(0),
+            reserveCommitment: null,
+            durationSeconds: new anchor.BN(60),
+            extensionSeconds: 2,
+            extensionWindow: 2,
+            minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            scheduledStart: null,
+            antiSnipePolicy: { fixedAdd: {} },
+            maxExtensionSeconds: 3600,
+            bidIncrement: { flat: {} },
+            allowlistRoot: null,
+            collectionGate: null,
+            banDefaulters: false,
+            minReputation: null,
+            maxDefaults: null,
+            sellerBond: new anchor.BN(0),
+            minBidInterval: 0,
+            blockSelfOutbid: false,
+            escrowTerms: null,
+            payoutSplits: [],
+            buyerPremiumBps: 0,
+          })
           .accountsStrict({
-            bidder: depBidder.publicKey,
-            auctionState: setup2.auctionState,
-            bidderDeposit: newDepPda,
-            auctionVault: setup2.auctionVault,
+            seller: errSeller.publicKey,
+            nftMint: nft.mint,
+            sellerNftTokenAccount: nft.ownerAta,
+            escrowNftTokenAccount: escrow,
+            auctionState: as,
+            auctionVault: av,
+            unclaimedFunds: getUnclaimedFundsPDA(as, program.programId)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            curator: null,
           })
-          .signers([depBidder])
+          .signers([errSeller])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
+        expect(err.error.errorCode.code).to.equal("InvalidReservePrice");
       }
     });
-  });
 
-  // =========================================================================
-  // Error paths — start_auction
-  // =========================================================================
+    it("rejects duration below minimum (4s)", async () => {
+      const nft = await createTestNft(connection, errSeller);
+      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
+      const [av] = getVaultPDA(as, program.programId);
+      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
 
-  describe("error_paths_start_auction", () => {
-    const startSeller = Keypair.generate();
-    const imposter = Keypair.generate();
-    let startAuction: PublicKey;
+      try {
+        await program.methods
+          .createAuction({
+            reservePrice: new anchor.BN(LAMPORTS_PER_SOL),
+            reserveCommitment: null,
+            durationSeconds: new anchor.BN(4),
+            extensionSeconds: 2,
+            extensionWindow: 2,
+            minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            scheduledStart: null,
+            antiSnipePolicy: { fixedAdd: {} },
+            maxExtensionSeconds: 3600,
+            bidIncrement: { flat: {} },
+            allowlistRoot: null,
+            collectionGate: null,
+            banDefaulters: false,
+            minReputation: null,
+            maxDefaults: null,
+            sellerBond: new anchor.BN(0),
+            minBidInterval: 0,
+            blockSelfOutbid: false,
+            escrowTerms: null,
+            payoutSplits: [],
+            buyerPremiumBps: 0,
+          })
+          .accountsStrict({
+            seller: errSeller.publicKey,
+            nftMint: nft.mint,
+            sellerNftTokenAccount: nft.ownerAta,
+            escrowNftTokenAccount: escrow,
+            auctionState: as,
+            auctionVault: av,
+            unclaimedFunds: getUnclaimedFundsPDA(as, program.programId)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            curator: null,
+          })
+          .signers([errSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidDuration");
+      }
+    });
 
-    before(async () => {
-      for (const kp of [startSeller, imposter]) {
-        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
-        await connection.confirmTransaction(sig);
+    it("rejects duration above maximum", async () => {
+      const nft = await createTestNft(connection, errSeller);
+      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
+      const [av] = getVaultPDA(as, program.programId);
+      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
+
+      try {
+        await program.methods
+          .createAuction({
+            reservePrice: new anchor.BN(LAMPORTS_PER_SOL),
+            reserveCommitment: null,
+            durationSeconds: new anchor.BN(604801),
+            extensionSeconds: 2,
+            extensionWindow: 2,
+            minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            scheduledStart: null,
+            antiSnipePolicy: { fixedAdd: {} },
+            maxExtensionSeconds: 3600,
+            bidIncrement: { flat: {} },
+            allowlistRoot: null,
+            collectionGate: null,
+            banDefaulters: false,
+            minReputation: null,
+            maxDefaults: null,
+            sellerBond: new anchor.BN(0),
+            minBidInterval: 0,
+            blockSelfOutbid: false,
+            escrowTerms: null,
+            payoutSplits: [],
+            buyerPremiumBps: 0,
+          })
+          .accountsStrict({
+            seller: errSeller.publicKey,
+            nftMint: nft.mint,
+            sellerNftTokenAccount: nft.ownerAta,
+            escrowNftTokenAccount: escrow,
+            auctionState: as,
+            auctionVault: av,
+            unclaimedFunds: getUnclaimedFundsPDA(as, program.programId)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            curator: null,
+          })
+          .signers([errSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidDuration");
       }
+    });
+
+    it("rejects min_bid_increment of 0", async () => {
+      const nft = await createTestNft(connection, errSeller);
+      const [as] = getAuctionPDA(errSeller.publicKey, nft.mint, program.programId);
+      const [av] = getVaultPDA(as, program.programId);
+      const escrow = await getAssociatedTokenAddress(nft.mint, as, true);
+
+      try {
+        await program.methods
+          .createAuction({
+            reservePrice: new anchor.BN(LAMPORTS_PER_SOL),
+            reserveCommitment: null,
+            durationSeconds: new anchor.BN(60),
+            extensionSeconds: 2,
+            extensionWindow: 2,
+            minBidIncrement: new anchor.BN(0),
+            scheduledStart: null,
+            antiSnipePolicy: { fixedAdd: {} },
+            maxExtensionSeconds: 3600,
+            bidIncrement: { flat: {} },
+            allowlistRoot: null,
+            collectionGate: null,
+            banDefaulters: false,
+            minReputation: null,
+            maxDefaults: null,
+            sellerBond: new anchor.BN(0),
+            minBidInterval: 0,
+            blockSelfOutbid: false,
+            escrowTerms: null,
+            payoutSplits: [],
+            buyerPremiumBps: 0,
+          })
+          .accountsStrict({
+            seller: errSeller.publicKey,
+            nftMint: nft.mint,
+            sellerNftTokenAccount: nft.ownerAta,
+            escrowNftTokenAccount: escrow,
+            auctionState: as,
+            auctionVault: av,
+            unclaimedFunds: getUnclaimedFundsPDA(as, program.programId)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            curator: null,
+          })
+          .signers([errSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBidIncrement");
+      }
+    });
+
+    it("rejects buyer's premium above the maximum", async () => {
+      try {
+        await setupAuction(program, connection, errSeller, { buyerPremiumBps: 2501 });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBuyerPremium");
+      }
+    });
+
+    it("rejects payout splits that do not sum to 100%", async () => {
+      try {
+        await setupAuction(program, connection, errSeller, {
+          payoutSplits: [
+            { recipient: errSeller.publicKey, bps: 7000 },
+            { recipient: Keypair.generate().publicKey, bps: 2000 },
+          ],
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidPayoutSplit");
+      }
+    });
+
+    it("rejects escrowed payout without a dispute window", async () => {
+      try {
+        await setupAuction(program, connection, errSeller, {
+          escrowTerms: { arbiter: Keypair.generate().publicKey, disputeWindowSeconds: 0 },
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidEscrowTerms");
+      }
+    });
+
+    it("rejects auction house fee above the maximum", async () => {
+      const [house] = getHousePDA(errSeller.publicKey, program.programId);
+      try {
+        await program.methods
+          .createAuctionHouse({
+            feeBps: 2001,
+            feeRecipient: errSeller.publicKey,
+            curator: null,
+            buyerPremiumBps: 0,
+          })
+          .accountsStrict({
+            authority: errSeller.publicKey,
+            auctionHouse: house,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([errSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidHouseFee");
+      }
+    });
+
+    it("rejects listing under a curated house without curator signature", async () => {
+      const houseAuthority = Keypair.generate();
+      const curator = Keypair.generate();
+      const sig = await connection.requestAirdrop(houseAuthority.publicKey, LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+      const [house] = getHousePDA(houseAuthority.publicKey, program.programId);
+
+      await program.methods
+        .createAuctionHouse({
+          feeBps: 500,
+          feeRecipient: houseAuthority.publicKey,
+          curator: curator.publicKey,
+          buyerPremiumBps: 1000,
+        })
+        .accountsStrict({
+          authority: houseAuthority.publicKey,
+          auctionHouse: house,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([houseAuthority])
+        .rpc();
+
+      try {
+        await setupAuction(program, connection, errSeller, { auctionHouse: house });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("CuratorApprovalRequired");
+      }
+
+      // With the curator co-signing, the house terms are snapshotted
+      const listed = await setupAuction(program, connection, errSeller, {
+        auctionHouse: house,
+        curator,
+      });
+      const state = await program.account.auctionState.fetch(listed.auctionState);
+      expect(state.house.toBase58()).to.equal(house.toBase58());
+      expect(state.houseFeeBps).to.equal(500);
+      expect(state.buyerPremiumBps).to.equal(1000);
+    });
+
+    it("rejects tiered increments that are not ascending", async () => {
+      const sol = (n: number) => new anchor.BN(n * LAMPORTS_PER_SOL);
+      try {
+        await setupAuction(program, connection, errSeller, {
+          bidIncrement: {
+            tiered: {
+              tiers: [
+                { below: sol(10), increment: sol(0.25) },
+                { below: sol(1), increment: sol(0.05) },
+                { below: new anchor.BN(0), increment: new anchor.BN(0) },
+                { below: new anchor.BN(0), increment: new anchor.BN(0) },
+              ],
+            },
+          },
+        });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBidIncrement");
+      }
+    });
+
+    it("rejects non-NFT mint (decimals > 0)", async () => {
+      // Create a fungible token mint with 6 decimals
+      const fungibleMint = await createMint(connection, errSeller, errSeller.publicKey, null, 6);
+      const fungibleAta = await createAssociatedTokenAccount(connection, errSeller, fungibleMint, errSeller.publicKey);
+      await mintTo(connection, errSeller, fungibleMint, fungibleAta, errSeller, 1);
+
+      const [as] = getAuctionPDA(errSeller.publicKey, fungibleMint, program.programId);
+      const [av] = getVaultPDA(as, program.programId);
+      const escrow = await getAssociatedTokenAddress(fungibleMint, as, true);
+
+      try {
+        await program.methods
+          .createAuction({
+            reservePrice: new anchor.BN(LAMPORTS_PER_SOL),
+            reserveCommitment: null,
+            durationSeconds: new anchor.BN(60),
+            extensionSeconds: 2,
+            extensionWindow: 2,
+            minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+            scheduledStart: null,
+            antiSnipePolicy: { fixedAdd: {} },
+            maxExtensionSeconds: 3600,
+            bidIncrement: { flat: {} },
+            allowlistRoot: null,
+            collectionGate: null,
+            banDefaulters: false,
+            minReputation: null,
+            maxDefaults: null,
+            sellerBond: new anchor.BN(0),
+            minBidInterval: 0,
+            blockSelfOutbid: false,
+            escrowTerms: null,
+            payoutSplits: [],
+            buyerPremiumBps: 0,
+          })
+          .accountsStrict({
+            seller: errSeller.publicKey,
+            nftMint: fungibleMint,
+            sellerNftTokenAccount: fungibleAta,
+            escrowNftTokenAccount: escrow,
+            auctionState: as,
+            auctionVault: av,
+            unclaimedFunds: getUnclaimedFundsPDA(as, program.programId)[0],
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            curator: null,
+          })
+          .signers([errSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidNftMint");
+      }
+    });
+  });
+
+  // =========================================================================
+  // Error paths — deposit
+  // =========================================================================
+
+  describe("error_paths_deposit", () => {
+    const depSeller = Keypair.generate();
+    const depBidder = Keypair.generate();
+    let depAuction: PublicKey;
+    let depVault: PublicKey;
+
+    before(async () => {
+      for (const kp of [depSeller, depBidder]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      const setup = await setupAuction(program, connection, depSeller, {
+        durationSeconds: new anchor.BN(5),
+      });
+      depAuction = setup.auctionState;
+      depVault = setup.auctionVault;
+    });
+
+    it("rejects deposit of 0 lamports", async () => {
+      const [depositPda] = getDepositPDA(depAuction, depBidder.publicKey, program.programId);
+      try {
+        await program.methods
+          .deposit(new anchor.BN(0), [])
+          .accountsStrict({
+            bidder: depBidder.publicKey,
+            auctionState: depAuction,
+            bidderDeposit: depositPda,
+            auctionVault: depVault,
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+            blocklist: getBlockListPDA(depSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(depBidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(depBidder.publicKey, program.programId)[0],
+          })
+          .signers([depBidder])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidDepositAmount");
+      }
+    });
+
+    it("rejects deposit after settlement", async () => {
+      // Run through full lifecycle to settlement
+      const winner = Keypair.generate();
+      const sig = await connection.requestAirdrop(winner.publicKey, 10 * LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+
+      // Create a separate auction for settlement test
+      const setup2 = await setupAuction(program, connection, depSeller, {
+        durationSeconds: new anchor.BN(5),
+        sellerFeeBps: 0,
+        creators: [],
+      });
+
+      // Deposit
+      const [winnerDep] = getDepositPDA(setup2.auctionState, winner.publicKey, program.programId);
+      await program.methods
+        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), [])
+        .accountsStrict({
+          bidder: winner.publicKey,
+          auctionState: setup2.auctionState,
+          bidderDeposit: winnerDep,
+          auctionVault: setup2.auctionVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(depSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(winner.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(winner.publicKey, program.programId)[0],
+        })
+        .signers([winner])
+        .rpc();
+
+      // Start, bid, wait, end, settle
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: depSeller.publicKey, auctionState: setup2.auctionState })
+        .signers([depSeller])
+        .rpc();
+
+      await program.methods
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: winner.publicKey,
+          auctionState: setup2.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(depSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(winner.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(winner.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([winner])
+        .rpc();
+
+      const auction = await program.account.auctionState.fetch(setup2.auctionState);
+      const now = Math.floor(Date.now() / 1000);
+      const wait = auction.endTime.toNumber() - now + 2;
+      if (wait > 0) await sleep(wait * 1000);
+
+      await program.methods
+        .endAuction()
+        .accountsStrict({ authority: depSeller.publicKey, auctionState: setup2.auctionState })
+        .signers([depSeller])
+        .rpc();
+
+      const winnerNftAta = await getAssociatedTokenAddress(setup2.nftMint, winner.publicKey);
+      await program.methods
+        .settleAuction()
+        .accountsStrict({
+          payer: depSeller.publicKey,
+          auctionState: setup2.auctionState,
+          auctionVault: setup2.auctionVault,
+          winnerDeposit: winnerDep,
+          seller: depSeller.publicKey,
+          winner: winner.publicKey,
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: setup2.nftMint,
+          nftMetadata: setup2.nftMetadata,
+          escrowNftTokenAccount: setup2.escrowNftAta,
+          winnerNftTokenAccount: winnerNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          houseFeeRecipient: null,
+          proceedsEscrow: null,
+          winnerBidderAccount: null,
+          winnerProfile: getBidderProfilePDA(winner.publicKey, program.programId)[0],
+          sellerProfile: getSellerProfilePDA(depSeller.publicKey, program.programId)[0],
+        })
+        .signers([depSeller])
+        .rpc();
+
+      // Now try to deposit on the settled auction
+      const [newDepPda] = getDepositPDA(setup2.auctionState, depBidder.publicKey, program.programId);
+      try {
+        await program.methods
+          .deposit(new anchor.BN(LAMPORTS_PER_SOL), [])
+          .accountsStrict({
+            bidder: depBidder.publicKey,
+            auctionState: setup2.auctionState,
+            bidderDeposit: newDepPda,
+            auctionVault: setup2.auctionVault,
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+            blocklist: getBlockListPDA(depSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(depBidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(depBidder.publicKey, program.programId)[0],
+          })
+          .signers([depBidder])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
+      }
+    });
+  });
+
+  // =========================================================================
+  // withdraw_deposit — pull excess deposit while the auction is live
+  // =========================================================================
+
+  describe("withdraw_deposit", () => {
+    const wdSeller = Keypair.generate();
+    const leader = Keypair.generate();
+    const outbid = Keypair.generate();
+    let wdAuction: PublicKey;
+    let wdVault: PublicKey;
+
+    const withdraw = (bidder: Keypair, amount: number) => {
+      const [depositPda] = getDepositPDA(wdAuction, bidder.publicKey, program.programId);
+      return program.methods
+        .withdrawDeposit(new anchor.BN(amount))
+        .accountsStrict({
+          bidder: bidder.publicKey,
+          auctionState: wdAuction,
+          bidderDeposit: depositPda,
+          auctionVault: wdVault,
+        })
+        .signers([bidder])
+        .rpc();
+    };
+
+    before(async () => {
+      for (const kp of [wdSeller, leader, outbid]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      const setup = await setupAuction(program, connection, wdSeller, {
+        durationSeconds: new anchor.BN(300),
+      });
+      wdAuction = setup.auctionState;
+      wdVault = setup.auctionVault;
+
+      for (const kp of [leader, outbid]) {
+        const [depositPda] = getDepositPDA(wdAuction, kp.publicKey, program.programId);
+        await program.methods
+          .deposit(new anchor.BN(3 * LAMPORTS_PER_SOL), [])
+          .accountsStrict({
+            bidder: kp.publicKey,
+            auctionState: wdAuction,
+            bidderDeposit: depositPda,
+            auctionVault: wdVault,
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+            blocklist: getBlockListPDA(wdSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(kp.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(kp.publicKey, program.programId)[0],
+          })
+          .signers([kp])
+          .rpc();
+      }
+    });
+
+    it("withdraws excess deposit before the auction starts", async () => {
+      await withdraw(outbid, 0.5 * LAMPORTS_PER_SOL);
+
+      const [depositPda] = getDepositPDA(wdAuction, outbid.publicKey, program.programId);
+      const dep = await program.account.bidderDeposit.fetch(depositPda);
+      expect(dep.amount.toNumber()).to.equal(2.5 * LAMPORTS_PER_SOL);
+    });
+
+    it("keeps the leader's bid locked while Active", async () => {
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: wdSeller.publicKey, auctionState: wdAuction })
+        .signers([wdSeller])
+        .rpc();
+      await program.methods
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: outbid.publicKey,
+          auctionState: wdAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(wdSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(outbid.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(outbid.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([outbid])
+        .rpc();
+      await program.methods
+        .placeBid(new anchor.BN(2 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: leader.publicKey,
+          auctionState: wdAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(wdSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(leader.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(leader.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([leader])
+        .rpc();
+
+      try {
+        await withdraw(leader, 1.5 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("WithdrawExceedsExcess");
+      }
+
+      // Exactly the excess above the 2 SOL bid is allowed
+      await withdraw(leader, 1 * LAMPORTS_PER_SOL);
+      const [depositPda] = getDepositPDA(wdAuction, leader.publicKey, program.programId);
+      const dep = await program.account.bidderDeposit.fetch(depositPda);
+      expect(dep.amount.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+    });
+
+    it("lets an outbid bidder withdraw their whole deposit", async () => {
+      await withdraw(outbid, 2.5 * LAMPORTS_PER_SOL);
+
+      const [depositPda] = getDepositPDA(wdAuction, outbid.publicKey, program.programId);
+      const dep = await program.account.bidderDeposit.fetch(depositPda);
+      expect(dep.amount.toNumber()).to.equal(0);
+
+      const vault = await program.account.auctionVault.fetch(wdVault);
+      expect(vault.totalRefunded.toNumber()).to.equal(4 * LAMPORTS_PER_SOL);
+    });
+
+    it("rejects withdrawing 0 lamports", async () => {
+      try {
+        await withdraw(leader, 0);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidDepositAmount");
+      }
+    });
+  });
+
+  // =========================================================================
+  // bidder_account — one balance locked into auctions by bids
+  // =========================================================================
+
+  describe("bidder_account", () => {
+    const baSeller = Keypair.generate();
+    const collector = Keypair.generate();
+    const rival = Keypair.generate();
+    let won: AuctionSetup;
+    let lost: AuctionSetup;
+    let bidderAccount: PublicKey;
+    let rivalAccount: PublicKey;
+
+    const bid = (
+      bidder: Keypair,
+      auction: AuctionSetup,
+      amount: number,
+      funding: { bidderAccount?: PublicKey; outbidBidderAccount?: PublicKey } = {}
+    ) =>
+      program.methods
+        .placeBid(new anchor.BN(amount), null)
+        .accountsStrict({
+          bidder: bidder.publicKey,
+          auctionState: auction.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(baSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: funding.bidderAccount ?? null,
+          outbidBidderAccount: funding.outbidBidderAccount ?? null,
+        })
+        .signers([bidder])
+        .rpc();
+
+    const unlock = (auction: AuctionSetup) =>
+      program.methods
+        .unlockBidFunds()
+        .accountsStrict({
+          bidder: collector.publicKey,
+          auctionState: auction.auctionState,
+          bidderAccount,
+        })
+        .rpc();
+
+    const lockOn = (account: any, auction: AuctionSetup) =>
+      account.locks.find((l: any) => l.auction.equals(auction.auctionState))?.amount.toNumber() ?? 0;
+
+    before(async () => {
+      for (const kp of [baSeller, collector, rival]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 20 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+      [bidderAccount] = getBidderAccountPDA(collector.publicKey, program.programId);
+      [rivalAccount] = getBidderAccountPDA(rival.publicKey, program.programId);
+
+      const opts = { sellerFeeBps: 0, creators: [], durationSeconds: new anchor.BN(20) };
+      won = await setupAuction(program, connection, baSeller, opts);
+      lost = await setupAuction(program, connection, baSeller, opts);
+
+      for (const [owner, account] of [[collector, bidderAccount], [rival, rivalAccount]] as const) {
+        await program.methods
+          .fundBidderAccount(new anchor.BN(5 * LAMPORTS_PER_SOL))
+          .accountsStrict({
+            owner: owner.publicKey,
+            bidderAccount: account,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([owner])
+          .rpc();
+      }
+      for (const auction of [won, lost]) {
+        await program.methods
+          .startAuction()
+          .accountsStrict({ seller: baSeller.publicKey, auctionState: auction.auctionState })
+          .signers([baSeller])
+          .rpc();
+      }
+    });
+
+    it("locks the amount due on each bid from one balance", async () => {
+      await bid(collector, won, 1 * LAMPORTS_PER_SOL, { bidderAccount });
+      await bid(collector, lost, 1 * LAMPORTS_PER_SOL, { bidderAccount });
+
+      const account = await program.account.bidderAccount.fetch(bidderAccount);
+      expect(account.balance.toNumber()).to.equal(3 * LAMPORTS_PER_SOL);
+      expect(account.locked.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+      expect(lockOn(account, won)).to.equal(1 * LAMPORTS_PER_SOL);
+      expect(lockOn(account, lost)).to.equal(1 * LAMPORTS_PER_SOL);
+    });
+
+    it("keeps the leader's lock and releases it once they are outbid", async () => {
+      try {
+        await unlock(won);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("BidLockActive");
+      }
+
+      // Outbid without carrying the collector's account — unlock releases it
+      // while the auction is still live
+      await bid(rival, lost, 2 * LAMPORTS_PER_SOL);
+      await unlock(lost);
+
+      const account = await program.account.bidderAccount.fetch(bidderAccount);
+      expect(account.balance.toNumber()).to.equal(4 * LAMPORTS_PER_SOL);
+      expect(account.locked.toNumber()).to.equal(1 * LAMPORTS_PER_SOL);
+      expect(lockOn(account, lost)).to.equal(0);
+    });
+
+    it("releases the outbid leader's lock inside the outbidding bid", async () => {
+      await bid(collector, lost, 3 * LAMPORTS_PER_SOL, { bidderAccount });
+      let account = await program.account.bidderAccount.fetch(bidderAccount);
+      expect(account.balance.toNumber()).to.equal(1 * LAMPORTS_PER_SOL);
+
+      await bid(rival, lost, 4 * LAMPORTS_PER_SOL, {
+        bidderAccount: rivalAccount,
+        outbidBidderAccount: bidderAccount,
+      });
+
+      account = await program.account.bidderAccount.fetch(bidderAccount);
+      expect(account.balance.toNumber()).to.equal(4 * LAMPORTS_PER_SOL);
+      expect(account.locked.toNumber()).to.equal(1 * LAMPORTS_PER_SOL);
+      const rivalState = await program.account.bidderAccount.fetch(rivalAccount);
+      expect(lockOn(rivalState, lost)).to.equal(4 * LAMPORTS_PER_SOL);
+    });
+
+    it("rejects a bid the free balance can't cover", async () => {
+      try {
+        await bid(collector, lost, 5 * LAMPORTS_PER_SOL, { bidderAccount });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InsufficientBalance");
+      }
+    });
+
+    it("settles from the winner's lock without a BidderDeposit", async () => {
+      const auction = await program.account.auctionState.fetch(won.auctionState);
+      const wait = auction.endTime.toNumber() - Math.floor(Date.now() / 1000) + 2;
+      if (wait > 0) await sleep(wait * 1000);
+      await program.methods
+        .endAuction()
+        .accountsStrict({ authority: baSeller.publicKey, auctionState: won.auctionState })
+        .signers([baSeller])
+        .rpc();
+
+      const winnerNftAta = await getAssociatedTokenAddress(won.nftMint, collector.publicKey);
+      await program.methods
+        .settleAuction()
+        .accountsStrict({
+          payer: baSeller.publicKey,
+          auctionState: won.auctionState,
+          auctionVault: won.auctionVault,
+          winnerDeposit: null,
+          winnerBidderAccount: bidderAccount,
+          seller: baSeller.publicKey,
+          winner: collector.publicKey,
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: won.nftMint,
+          nftMetadata: won.nftMetadata,
+          escrowNftTokenAccount: won.escrowNftAta,
+          winnerNftTokenAccount: winnerNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          houseFeeRecipient: null,
+          proceedsEscrow: null,
+          winnerProfile: getBidderProfilePDA(collector.publicKey, program.programId)[0],
+          sellerProfile: getSellerProfilePDA(baSeller.publicKey, program.programId)[0],
+        })
+        .signers([baSeller])
+        .rpc();
+
+      // The 1 SOL lock was debited; the balance is untouched
+      const account = await program.account.bidderAccount.fetch(bidderAccount);
+      expect(account.balance.toNumber()).to.equal(4 * LAMPORTS_PER_SOL);
+      expect(account.locked.toNumber()).to.equal(0);
+      expect(lockOn(account, won)).to.equal(0);
+
+      const nft = await getAccount(connection, winnerNftAta);
+      expect(Number(nft.amount)).to.equal(1);
+    });
+
+    it("refuses to close the account while a lock is open", async () => {
+      const open = await setupAuction(program, connection, baSeller, {
+        sellerFeeBps: 0,
+        creators: [],
+        durationSeconds: new anchor.BN(20),
+      });
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: baSeller.publicKey, auctionState: open.auctionState })
+        .signers([baSeller])
+        .rpc();
+      await bid(collector, open, 1 * LAMPORTS_PER_SOL, { bidderAccount });
+
+      const close = () =>
+        program.methods
+          .closeBidderAccount()
+          .accountsStrict({ owner: collector.publicKey, bidderAccount })
+          .signers([collector])
+          .rpc();
+
+      try {
+        await close();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("BidLocksOutstanding");
+      }
+
+      await bid(rival, open, 2 * LAMPORTS_PER_SOL, { outbidBidderAccount: bidderAccount });
+      const before = await connection.getBalance(collector.publicKey);
+      const rent = await connection.getBalance(bidderAccount);
+      await close();
+
+      // Free balance and rent both come back
+      expect(rent).to.be.greaterThan(4 * LAMPORTS_PER_SOL);
+      expect(await connection.getBalance(collector.publicKey)).to.be.greaterThan(before + rent - 10_000);
+      expect(await connection.getAccountInfo(bidderAccount)).to.be.null;
+    });
+  });
+
+  // =========================================================================
+  // session_lifecycle — expiry, spend cap, revoke and close
+  // =========================================================================
+
+  describe("session_lifecycle", () => {
+    const sessSeller = Keypair.generate();
+    const sessBidder = Keypair.generate();
+    const sessionKey = Keypair.generate();
+    let sessAuction: PublicKey;
+    let sessionToken: PublicKey;
+
+    const bidWithSession = (amount: number) =>
+      program.methods
+        .placeBidSession(new anchor.BN(amount), null)
+        .accountsStrict({
+          sessionSigner: sessionKey.publicKey,
+          sessionToken,
+          auctionState: sessAuction,
+          globalSession: null,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(sessSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(sessBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(sessBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([sessionKey])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [sessSeller, sessBidder]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      const setup = await setupAuction(program, connection, sessSeller, {
+        durationSeconds: new anchor.BN(300),
+      });
+      sessAuction = setup.auctionState;
+      [sessionToken] = getSessionPDA(sessAuction, sessBidder.publicKey, program.programId);
+
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: sessSeller.publicKey, auctionState: sessAuction })
+        .signers([sessSeller])
+        .rpc();
+    });
+
+    it("rejects a session expiring in the past", async () => {
+      try {
+        await program.methods
+          .createSession(
+            sessionKey.publicKey,
+            new anchor.BN(Math.floor(Date.now() / 1000) - 60),
+            new anchor.BN(2 * LAMPORTS_PER_SOL),
+            []
+          )
+          .accountsStrict({
+            bidder: sessBidder.publicKey,
+            auctionState: sessAuction,
+            sessionToken,
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+          })
+          .signers([sessBidder])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidSessionExpiry");
+      }
+    });
+
+    it("bids within the session's spend cap", async () => {
+      await program.methods
+        .createSession(
+          sessionKey.publicKey,
+          new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+          new anchor.BN(2 * LAMPORTS_PER_SOL),
+          []
+        )
+        .accountsStrict({
+          bidder: sessBidder.publicKey,
+          auctionState: sessAuction,
+          sessionToken,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+        })
+        .signers([sessBidder])
+        .rpc();
+
+      await bidWithSession(1 * LAMPORTS_PER_SOL);
+      const auction = await program.account.auctionState.fetch(sessAuction);
+      expect(auction.highestBidder.toBase58()).to.equal(sessBidder.publicKey.toBase58());
+
+      try {
+        await bidWithSession(3 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SessionCapExceeded");
+      }
+    });
+
+    it("rejects bids after revoke_session", async () => {
+      await program.methods
+        .revokeSession()
+        .accountsStrict({ bidder: sessBidder.publicKey, sessionToken })
+        .signers([sessBidder])
+        .rpc();
+
+      try {
+        await bidWithSession(1.5 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SessionSignerMismatch");
+      }
+    });
+
+    it("closes the session token and returns rent", async () => {
+      const balBefore = await connection.getBalance(sessBidder.publicKey);
+      await program.methods
+        .closeSession()
+        .accountsStrict({ bidder: sessBidder.publicKey, sessionToken })
+        .signers([sessBidder])
+        .rpc();
+
+      expect(await connection.getAccountInfo(sessionToken)).to.be.null;
+      expect(await connection.getBalance(sessBidder.publicKey)).to.be.greaterThan(balBefore);
+    });
+  });
+
+  // =========================================================================
+  // allowlist — Merkle-gated deposits, sessions and bids
+  // =========================================================================
+
+  describe("allowlist", () => {
+    const alSeller = Keypair.generate();
+    const listed = Keypair.generate();
+    const other = Keypair.generate();
+    const outsider = Keypair.generate();
+    const allowlist = buildAllowlist([listed.publicKey, other.publicKey]);
+    let alAuction: PublicKey;
+    let alVault: PublicKey;
+
+    const depositFor = (bidder: Keypair, proof: number[][]) =>
+      program.methods
+        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), proof)
+        .accountsStrict({
+          bidder: bidder.publicKey,
+          auctionState: alAuction,
+          bidderDeposit: getDepositPDA(alAuction, bidder.publicKey, program.programId)[0],
+          auctionVault: alVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(alSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+        })
+        .signers([bidder])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [alSeller, listed, other, outsider]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      // Created with a placeholder root; the seller swaps in the real one
+      const setup = await setupAuction(program, connection, alSeller, {
+        durationSeconds: new anchor.BN(300),
+        allowlistRoot: Array(32).fill(7),
+      });
+      alAuction = setup.auctionState;
+      alVault = setup.auctionVault;
+    });
+
+    it("lets the seller update the root before start", async () => {
+      await program.methods
+        .updateAllowlistRoot(allowlist.root)
+        .accountsStrict({ seller: alSeller.publicKey, auctionState: alAuction })
+        .signers([alSeller])
+        .rpc();
+
+      const auction = await program.account.auctionState.fetch(alAuction);
+      expect(auction.allowlistRoot).to.deep.equal(allowlist.root);
+    });
+
+    it("rejects a deposit without a valid proof", async () => {
+      try {
+        await depositFor(outsider, allowlist.proof(listed.publicKey));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAllowlistProof");
+      }
+    });
+
+    it("marks a deposit made with a valid proof", async () => {
+      await depositFor(listed, allowlist.proof(listed.publicKey));
+      const [depositPda] = getDepositPDA(alAuction, listed.publicKey, program.programId);
+      const deposit = await program.account.bidderDeposit.fetch(depositPda);
+      expect(deposit.verifiedRoot).to.deep.equal(allowlist.root);
+
+      // Top-ups reuse the marker — no proof needed
+      await depositFor(listed, []);
+    });
+
+    it("rejects a session from a wallet not on the list", async () => {
+      try {
+        await program.methods
+          .createSession(
+            Keypair.generate().publicKey,
+            new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+            new anchor.BN(2 * LAMPORTS_PER_SOL),
+            []
+          )
+          .accountsStrict({
+            bidder: outsider.publicKey,
+            auctionState: alAuction,
+            sessionToken: getSessionPDA(alAuction, outsider.publicKey, program.programId)[0],
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+          })
+          .signers([outsider])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAllowlistProof");
+      }
+    });
+
+    it("requires the deposit marker to bid", async () => {
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: alSeller.publicKey, auctionState: alAuction })
+        .signers([alSeller])
+        .rpc();
+
+      try {
+        await program.methods
+          .placeBid(new anchor.BN(LAMPORTS_PER_SOL), null)
+          .accountsStrict({
+            bidder: listed.publicKey,
+            auctionState: alAuction,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(alSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(listed.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(listed.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([listed])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("NotAllowlisted");
+      }
+
+      await program.methods
+        .placeBid(new anchor.BN(LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: listed.publicKey,
+          auctionState: alAuction,
+          bidderDeposit: getDepositPDA(alAuction, listed.publicKey, program.programId)[0],
+          blocklist: getBlockListPDA(alSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(listed.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(listed.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([listed])
+        .rpc();
+
+      const auction = await program.account.auctionState.fetch(alAuction);
+      expect(auction.highestBidder.toBase58()).to.equal(listed.publicKey.toBase58());
+    });
+
+    it("rejects root updates after start", async () => {
+      try {
+        await program.methods
+          .updateAllowlistRoot(null)
+          .accountsStrict({ seller: alSeller.publicKey, auctionState: alAuction })
+          .signers([alSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
+      }
+    });
+  });
+
+  // =========================================================================
+  // collection_gate — only holders of a verified collection may bid
+  // =========================================================================
+
+  describe("collection_gate", () => {
+    const cgSeller = Keypair.generate();
+    const holder = Keypair.generate();
+    const friend = Keypair.generate();
+    let collection: PublicKey;
+    let cgAuction: PublicKey;
+    let cgVault: PublicKey;
+    let unverifiedNft: { mint: PublicKey; ownerAta: PublicKey; metadata: PublicKey };
+    let gateNft: { mint: PublicKey; ownerAta: PublicKey; metadata: PublicKey };
+
+    const depositWithGate = (
+      bidder: Keypair,
+      gateTokenAccount: PublicKey | null,
+      gateMetadata: PublicKey | null,
+      gateClaim: PublicKey | null
+    ) =>
+      program.methods
+        .deposit(new anchor.BN(LAMPORTS_PER_SOL), [])
+        .accountsStrict({
+          bidder: bidder.publicKey,
+          auctionState: cgAuction,
+          bidderDeposit: getDepositPDA(cgAuction, bidder.publicKey, program.programId)[0],
+          auctionVault: cgVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount,
+          gateMetadata,
+          gateClaim,
+          blocklist: getBlockListPDA(cgSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+        })
+        .signers([bidder])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [cgSeller, holder, friend]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      const collectionNft = await createCollectionNft(connection, cgSeller);
+      collection = collectionNft.mint;
+
+      const setup = await setupAuction(program, connection, cgSeller, {
+        durationSeconds: new anchor.BN(300),
+        collectionGate: collection,
+      });
+      cgAuction = setup.auctionState;
+      cgVault = setup.auctionVault;
+
+      // Claims the collection, but nobody verified it
+      unverifiedNft = await createTestNft(connection, holder, { collection });
+
+      gateNft = await createTestNft(connection, holder, { collection });
+      await verifyCollectionItem(connection, cgSeller, gateNft.metadata, collectionNft);
+    });
+
+    const gateClaimFor = (mint: PublicKey) =>
+      getGateClaimPDA(cgAuction, mint, program.programId)[0];
+
+    it("stores the gate at creation", async () => {
+      const auction = await program.account.auctionState.fetch(cgAuction);
+      expect(auction.collectionGate.toBase58()).to.equal(collection.toBase58());
+    });
+
+    it("rejects a deposit without a holdership proof", async () => {
+      try {
+        await depositWithGate(holder, null, null, null);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("NotCollectionHolder");
+      }
+    });
+
+    it("rejects a token whose collection is not verified", async () => {
+      try {
+        await depositWithGate(
+          holder,
+          unverifiedNft.ownerAta,
+          unverifiedNft.metadata,
+          gateClaimFor(unverifiedNft.mint)
+        );
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("NotCollectionHolder");
+      }
+    });
+
+    it("rejects proving with someone else's token", async () => {
+      const stranger = Keypair.generate();
+      const sig = await connection.requestAirdrop(stranger.publicKey, 10 * LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+
+      try {
+        await depositWithGate(
+          stranger,
+          gateNft.ownerAta,
+          gateNft.metadata,
+          gateClaimFor(gateNft.mint)
+        );
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("NotCollectionHolder");
+      }
+    });
+
+    it("claims the gate token for the first bidder to prove it", async () => {
+      await depositWithGate(
+        holder,
+        gateNft.ownerAta,
+        gateNft.metadata,
+        gateClaimFor(gateNft.mint)
+      );
+
+      const deposit = await program.account.bidderDeposit.fetch(
+        getDepositPDA(cgAuction, holder.publicKey, program.programId)[0]
+      );
+      expect(deposit.verifiedCollection.toBase58()).to.equal(collection.toBase58());
+      // GateClaim { auction, mint, bidder, bump } after the discriminator
+      const claim = await connection.getAccountInfo(gateClaimFor(gateNft.mint));
+      expect(claim.owner.toBase58()).to.equal(program.programId.toBase58());
+      expect(new PublicKey(claim.data.subarray(72, 104)).toBase58()).to.equal(
+        holder.publicKey.toBase58()
+      );
+    });
+
+    it("keeps a session's holdership marker when it is re-created without a proof", async () => {
+      const [sessionToken] = getSessionPDA(cgAuction, holder.publicKey, program.programId);
+      const createHolderSession = (gate: boolean) =>
+        program.methods
+          .createSession(
+            Keypair.generate().publicKey,
+            new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+            new anchor.BN(LAMPORTS_PER_SOL),
+            []
+          )
+          .accountsStrict({
+            bidder: holder.publicKey,
+            auctionState: cgAuction,
+            sessionToken,
+            gateTokenAccount: gate ? gateNft.ownerAta : null,
+            gateMetadata: gate ? gateNft.metadata : null,
+            gateClaim: gate ? gateClaimFor(gateNft.mint) : null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([holder])
+          .rpc();
+
+      await createHolderSession(true);
+      // e.g. a page refresh generating a new ephemeral key
+      await createHolderSession(false);
+
+      const session = await program.account.sessionToken.fetch(sessionToken);
+      expect(session.verifiedCollection.toBase58()).to.equal(collection.toBase58());
+    });
+
+    it("rejects the same token qualifying a second bidder", async () => {
+      const friendAta = await createAssociatedTokenAccount(
+        connection,
+        friend,
+        gateNft.mint,
+        friend.publicKey
+      );
+      await transfer(connection, holder, gateNft.ownerAta, friendAta, holder, 1);
+
+      try {
+        await depositWithGate(
+          friend,
+          friendAta,
+          gateNft.metadata,
+          gateClaimFor(gateNft.mint)
+        );
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("GateTokenClaimed");
+      }
+    });
+
+    it("rejects a session holdership proof without a gate claim", async () => {
+      try {
+        await program.methods
+          .createSession(
+            Keypair.generate().publicKey,
+            new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+            new anchor.BN(LAMPORTS_PER_SOL),
+            []
+          )
+          .accountsStrict({
+            bidder: friend.publicKey,
+            auctionState: cgAuction,
+            sessionToken: getSessionPDA(cgAuction, friend.publicKey, program.programId)[0],
+            gateTokenAccount: unverifiedNft.ownerAta,
+            gateMetadata: unverifiedNft.metadata,
+            gateClaim: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([friend])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidGateClaim");
+      }
+    });
+
+    it("rejects bids without an eligibility marker", async () => {
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: cgSeller.publicKey, auctionState: cgAuction })
+        .signers([cgSeller])
+        .rpc();
+
+      try {
+        await program.methods
+          .placeBid(new anchor.BN(LAMPORTS_PER_SOL), null)
+          .accountsStrict({
+            bidder: holder.publicKey,
+            auctionState: cgAuction,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(cgSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(holder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(holder.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([holder])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("NotCollectionHolder");
+      }
+    });
+  });
+
+  // =========================================================================
+  // blocklist — seller-blocked wallets can't deposit or bid
+  // =========================================================================
+
+  describe("blocklist", () => {
+    const blSeller = Keypair.generate();
+    const blocked = Keypair.generate();
+    let blAuction: PublicKey;
+    let blVault: PublicKey;
+    const [blocklist] = getBlockListPDA(blSeller.publicKey, program.programId);
+    const [defaultRecord] = getDefaultRecordPDA(blocked.publicKey, program.programId);
+    const [bidderProfile] = getBidderProfilePDA(blocked.publicKey, program.programId);
+
+    const depositBlocked = () =>
+      program.methods
+        .deposit(new anchor.BN(LAMPORTS_PER_SOL), [])
+        .accountsStrict({
+          bidder: blocked.publicKey,
+          auctionState: blAuction,
+          bidderDeposit: getDepositPDA(blAuction, blocked.publicKey, program.programId)[0],
+          auctionVault: blVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist,
+          defaultRecord,
+          bidderProfile,
+        })
+        .signers([blocked])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [blSeller, blocked]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      const setup = await setupAuction(program, connection, blSeller, {
+        durationSeconds: new anchor.BN(300),
+      });
+      blAuction = setup.auctionState;
+      blVault = setup.auctionVault;
+
+      await program.methods
+        .blockBidder(blocked.publicKey)
+        .accountsStrict({
+          seller: blSeller.publicKey,
+          blocklist,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([blSeller])
+        .rpc();
+    });
+
+    it("refuses deposits from a blocked wallet", async () => {
+      const list = await program.account.blockList.fetch(blocklist);
+      expect(list.blocked.map((w) => w.toBase58())).to.include(blocked.publicKey.toBase58());
+
+      try {
+        await depositBlocked();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("WalletBlocked");
+      }
+    });
+
+    it("refuses bids from a blocked wallet", async () => {
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: blSeller.publicKey, auctionState: blAuction })
+        .signers([blSeller])
+        .rpc();
+
+      try {
+        await program.methods
+          .placeBid(new anchor.BN(LAMPORTS_PER_SOL), null)
+          .accountsStrict({
+            bidder: blocked.publicKey,
+            auctionState: blAuction,
+            bidderDeposit: null,
+            blocklist,
+            defaultRecord,
+            bidderProfile,
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([blocked])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("WalletBlocked");
+      }
+    });
+
+    it("accepts the wallet again after unblock", async () => {
+      await program.methods
+        .unblockBidder(blocked.publicKey)
+        .accountsStrict({ seller: blSeller.publicKey, blocklist })
+        .signers([blSeller])
+        .rpc();
+
+      await depositBlocked();
+      const [depositPda] = getDepositPDA(blAuction, blocked.publicKey, program.programId);
+      const deposit = await program.account.bidderDeposit.fetch(depositPda);
+      expect(deposit.amount.toNumber()).to.equal(LAMPORTS_PER_SOL);
+    });
+  });
+
+  // =========================================================================
+  // bidder_profile — reputation and default limits set per auction
+  // =========================================================================
+
+  describe("bidder_profile", () => {
+    const bpSeller = Keypair.generate();
+    const newcomer = Keypair.generate();
+
+    const depositInto = (auctionState: PublicKey, auctionVault: PublicKey) =>
+      program.methods
+        .deposit(new anchor.BN(LAMPORTS_PER_SOL), [])
+        .accountsStrict({
+          bidder: newcomer.publicKey,
+          auctionState,
+          bidderDeposit: getDepositPDA(auctionState, newcomer.publicKey, program.programId)[0],
+          auctionVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(bpSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(newcomer.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(newcomer.publicKey, program.programId)[0],
+        })
+        .signers([newcomer])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [bpSeller, newcomer]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      await program.methods
+        .createBidderProfile()
+        .accountsStrict({
+          bidder: newcomer.publicKey,
+          bidderProfile: getBidderProfilePDA(newcomer.publicKey, program.programId)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([newcomer])
+        .rpc();
+    });
+
+    it("opens a versioned, empty profile", async () => {
+      const [profilePda] = getBidderProfilePDA(newcomer.publicKey, program.programId);
+      const profile = await program.account.bidderProfile.fetch(profilePda);
+      expect(profile.version).to.equal(1);
+      expect(profile.bidder.toBase58()).to.equal(newcomer.publicKey.toBase58());
+      expect(profile.reputation).to.equal(0);
+    });
+
+    it("refuses bidders below the auction's minimum reputation", async () => {
+      const setup = await setupAuction(program, connection, bpSeller, {
+        durationSeconds: new anchor.BN(300),
+        minReputation: 5,
+      });
+
+      try {
+        await depositInto(setup.auctionState, setup.auctionVault);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("ReputationTooLow");
+      }
+    });
+
+    it("accepts a clean history under max_defaults", async () => {
+      const setup = await setupAuction(program, connection, bpSeller, {
+        durationSeconds: new anchor.BN(300),
+        maxDefaults: 0,
+      });
+
+      await depositInto(setup.auctionState, setup.auctionVault);
+      const [depositPda] = getDepositPDA(setup.auctionState, newcomer.publicKey, program.programId);
+      const deposit = await program.account.bidderDeposit.fetch(depositPda);
+      expect(deposit.amount.toNumber()).to.equal(LAMPORTS_PER_SOL);
+    });
+  });
+
+  // =========================================================================
+  // seller_bond — listing bond held in the vault, returned or slashed
+  // =========================================================================
+
+  describe("seller_bond", () => {
+    const sbSeller = Keypair.generate();
+    const sbBidder = Keypair.generate();
+    const bond = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+    const [sellerProfile] = getSellerProfilePDA(sbSeller.publicKey, program.programId);
+
+    const cancel = (setup: AuctionSetup) =>
+      program.methods
+        .cancelAuction()
+        .accountsStrict({
+          seller: sbSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          sellerProfile,
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          sellerNftTokenAccount: setup.sellerNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([sbSeller])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [sbSeller, sbBidder]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+    });
+
+    it("holds the bond in the vault and returns it at close", async () => {
+      const setup = await setupAuction(program, connection, sbSeller, { sellerBond: bond });
+      const vault = await program.account.auctionVault.fetch(setup.auctionVault);
+      expect(vault.sellerBond.toNumber()).to.equal(bond.toNumber());
+
+      // No deposits yet — cancelling costs nothing but a mark on the profile
+      await cancel(setup);
+      const profile = await program.account.sellerProfile.fetch(sellerProfile);
+      expect(profile.cancellations).to.equal(1);
+      expect(profile.bondsSlashed).to.equal(0);
+
+      const sellerBalBefore = await connection.getBalance(sbSeller.publicKey);
+      await program.methods
+        .closeAuction()
+        .accountsStrict({
+          seller: sbSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([sbSeller])
+        .rpc();
+      const sellerBalAfter = await connection.getBalance(sbSeller.publicKey);
+      expect(sellerBalAfter - sellerBalBefore).to.be.greaterThan(bond.toNumber());
+    });
+
+    it("slashes the bond to the treasury when cancelling after deposits", async () => {
+      const setup = await setupAuction(program, connection, sbSeller, { sellerBond: bond });
+      await program.methods
+        .deposit(new anchor.BN(LAMPORTS_PER_SOL), [])
+        .accountsStrict({
+          bidder: sbBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: getDepositPDA(setup.auctionState, sbBidder.publicKey, program.programId)[0],
+          auctionVault: setup.auctionVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(sbSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(sbBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(sbBidder.publicKey, program.programId)[0],
+        })
+        .signers([sbBidder])
+        .rpc();
+
+      const treasuryBalBefore = await connection.getBalance(PROTOCOL_TREASURY);
+      await cancel(setup);
+      const treasuryBalAfter = await connection.getBalance(PROTOCOL_TREASURY);
+      expect(treasuryBalAfter - treasuryBalBefore).to.equal(bond.toNumber());
+
+      const vault = await program.account.auctionVault.fetch(setup.auctionVault);
+      expect(vault.sellerBond.toNumber()).to.equal(0);
+      const profile = await program.account.sellerProfile.fetch(sellerProfile);
+      expect(profile.cancellations).to.equal(2);
+      expect(profile.bondsSlashed).to.equal(1);
+      expect(profile.slashedAmount.toNumber()).to.equal(bond.toNumber());
+    });
+  });
+
+  // =========================================================================
+  // bid_rate_limit — per-bidder interval and self-outbid rules
+  // =========================================================================
+
+  describe("bid_rate_limit", () => {
+    const rlSeller = Keypair.generate();
+    const rlAlice = Keypair.generate();
+    const rlBob = Keypair.generate();
+
+    const bid = (auctionState: PublicKey, bidder: Keypair, sol: number) =>
+      program.methods
+        .placeBid(new anchor.BN(sol * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: bidder.publicKey,
+          auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(rlSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([bidder])
+        .rpc();
+
+    const liveAuction = async (opts: { minBidInterval?: number; blockSelfOutbid?: boolean }) => {
+      const setup = await setupAuction(program, connection, rlSeller, {
+        durationSeconds: new anchor.BN(300),
+        ...opts,
+      });
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: rlSeller.publicKey, auctionState: setup.auctionState })
+        .signers([rlSeller])
+        .rpc();
+      return setup.auctionState;
+    };
+
+    before(async () => {
+      for (const kp of [rlSeller, rlAlice, rlBob]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+    });
+
+    it("rejects a bidder raising their own top bid", async () => {
+      const auctionState = await liveAuction({ blockSelfOutbid: true });
+      await bid(auctionState, rlAlice, 1);
+
+      try {
+        await bid(auctionState, rlAlice, 1.1);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SelfOutbid");
+      }
+
+      await bid(auctionState, rlBob, 1.1);
+      await bid(auctionState, rlAlice, 1.2);
+      const auction = await program.account.auctionState.fetch(auctionState);
+      expect(auction.highestBidder.toBase58()).to.equal(rlAlice.publicKey.toBase58());
+    });
+
+    it("enforces the minimum interval per bidder, not per auction", async () => {
+      const auctionState = await liveAuction({ minBidInterval: 60 });
+      await bid(auctionState, rlAlice, 1);
+      // A different wallet isn't held back by Alice's cooldown
+      await bid(auctionState, rlBob, 1.1);
+
+      try {
+        await bid(auctionState, rlAlice, 1.2);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("BidRateLimited");
+      }
+
+      const auction = await program.account.auctionState.fetch(auctionState);
+      const recent = auction.recentBidders.filter((r: any) => r.at.toNumber() > 0);
+      expect(recent.map((r: any) => r.bidder.toBase58())).to.have.members([
+        rlAlice.publicKey.toBase58(),
+        rlBob.publicKey.toBase58(),
+      ]);
+    });
+
+    it("rejects a bid interval above the maximum", async () => {
+      try {
+        await setupAuction(program, connection, rlSeller, { minBidInterval: 3_601 });
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBidInterval");
+      }
+    });
+  });
+
+  // =========================================================================
+  // global_session — one session key across auctions with a shared bid-volume cap
+  // =========================================================================
+
+  describe("global_session", () => {
+    const gsSeller = Keypair.generate();
+    const gsBidder = Keypair.generate();
+    const sessionKey = Keypair.generate();
+    const gsRival = Keypair.generate();
+    let allowed: PublicKey;
+    let other: PublicKey;
+    let globalSession: PublicKey;
+
+    const bidWithGlobal = (auctionState: PublicKey, amount: number) =>
+      program.methods
+        .placeBidSession(new anchor.BN(amount), null)
+        .accountsStrict({
+          sessionSigner: sessionKey.publicKey,
+          sessionToken: null,
+          globalSession,
+          auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(gsSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(gsBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(gsBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([sessionKey])
+        .rpc();
+
+    before(async () => {
+      for (const kp of [gsSeller, gsBidder, gsRival]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+      [globalSession] = getGlobalSessionPDA(gsBidder.publicKey, program.programId);
+
+      const opts = { durationSeconds: new anchor.BN(300) };
+      allowed = (await setupAuction(program, connection, gsSeller, opts)).auctionState;
+      other = (await setupAuction(program, connection, gsSeller, opts)).auctionState;
+      for (const auctionState of [allowed, other]) {
+        await program.methods
+          .startAuction()
+          .accountsStrict({ seller: gsSeller.publicKey, auctionState })
+          .signers([gsSeller])
+          .rpc();
+      }
+
+      await program.methods
+        .createGlobalSession({
+          sessionSigner: sessionKey.publicKey,
+          expiresAt: new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+          maxBidAmount: new anchor.BN(5 * LAMPORTS_PER_SOL),
+          volumeCap: new anchor.BN(2.5 * LAMPORTS_PER_SOL),
+          allowedTargets: [allowed],
+        })
+        .accountsStrict({
+          bidder: gsBidder.publicKey,
+          globalSession,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([gsBidder])
+        .rpc();
+    });
+
+    it("bids as the real wallet and draws down the volume cap", async () => {
+      await bidWithGlobal(allowed, 1 * LAMPORTS_PER_SOL);
+
+      const auction = await program.account.auctionState.fetch(allowed);
+      expect(auction.highestBidder.toBase58()).to.equal(gsBidder.publicKey.toBase58());
+      const session = await program.account.globalSession.fetch(globalSession);
+      expect(session.volumeRemaining.toNumber()).to.equal(1.5 * LAMPORTS_PER_SOL);
+    });
+
+    it("charges only the increase when raising its own lead", async () => {
+      await bidWithGlobal(allowed, 2 * LAMPORTS_PER_SOL);
+
+      const session = await program.account.globalSession.fetch(globalSession);
+      expect(session.volumeRemaining.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+    });
+
+    it("does not credit volume back when outbid", async () => {
+      await program.methods
+        .placeBid(new anchor.BN(2.2 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: gsRival.publicKey,
+          auctionState: allowed,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(gsSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(gsRival.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(gsRival.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([gsRival])
+        .rpc();
+
+      const session = await program.account.globalSession.fetch(globalSession);
+      expect(session.volumeRemaining.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+    });
+
+    it("rejects bids beyond the remaining volume", async () => {
+      try {
+        await bidWithGlobal(allowed, 2.6 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SessionVolumeCapExceeded");
+      }
+    });
+
+    it("rejects auctions outside the allowlist", async () => {
+      try {
+        await bidWithGlobal(other, 1 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SessionTargetNotAllowed");
+      }
+    });
+
+    it("revokes and closes the global session", async () => {
+      await program.methods
+        .revokeGlobalSession()
+        .accountsStrict({ bidder: gsBidder.publicKey, globalSession })
+        .signers([gsBidder])
+        .rpc();
+      try {
+        await bidWithGlobal(allowed, 2.1 * LAMPORTS_PER_SOL);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SessionSignerMismatch");
+      }
+
+      await program.methods
+        .closeGlobalSession()
+        .accountsStrict({ bidder: gsBidder.publicKey, globalSession })
+        .signers([gsBidder])
+        .rpc();
+      expect(await connection.getAccountInfo(globalSession)).to.be.null;
+    });
+  });
+
+  // =========================================================================
+  // place_signed_bid — relayed bids signed off-chain by the real bidder
+  // =========================================================================
+
+  describe("place_signed_bid", () => {
+    const sbSeller = Keypair.generate();
+    const signer = Keypair.generate();
+    let sbAuction: PublicKey;
+    let bidNonce: PublicKey;
+    const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 600);
+
+    const signedBidMessage = (amount: anchor.BN, nonce: anchor.BN) =>
+      Buffer.concat([
+        program.programId.toBuffer(),
+        sbAuction.toBuffer(),
+        amount.toArrayLike(Buffer, "le", 8),
+        nonce.toArrayLike(Buffer, "le", 8),
+        expiresAt.toArrayLike(Buffer, "le", 8),
+      ]);
+
+    const relay = (amount: anchor.BN, nonce: anchor.BN, signedAmount = amount) =>
+      program.methods
+        .placeSignedBid(signer.publicKey, amount, nonce, expiresAt)
+        .accountsStrict({
+          relayer: provider.wallet.publicKey,
+          bidNonce,
+          auctionState: sbAuction,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(sbSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(signer.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(signer.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: signer.secretKey,
+            message: signedBidMessage(signedAmount, nonce),
+          }),
+        ])
+        .rpc();
+
+    before(async () => {
+      const sig = await connection.requestAirdrop(sbSeller.publicKey, 10 * LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+
+      const setup = await setupAuction(program, connection, sbSeller, {
+        durationSeconds: new anchor.BN(300),
+      });
+      sbAuction = setup.auctionState;
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: sbSeller.publicKey, auctionState: sbAuction })
+        .signers([sbSeller])
+        .rpc();
+
+      [bidNonce] = getBidNoncePDA(signer.publicKey, program.programId);
+      await program.methods
+        .createBidNonce(signer.publicKey)
+        .accountsStrict({
+          payer: provider.wallet.publicKey,
+          bidNonce,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("records the signer, not the relayer, as highest bidder", async () => {
+      await relay(new anchor.BN(1 * LAMPORTS_PER_SOL), new anchor.BN(1));
+
+      const auction = await program.account.auctionState.fetch(sbAuction);
+      expect(auction.highestBidder.toBase58()).to.equal(signer.publicKey.toBase58());
+      const nonce = await program.account.bidNonce.fetch(bidNonce);
+      expect(nonce.lastNonce.toNumber()).to.equal(1);
+    });
+
+    it("rejects a replayed nonce", async () => {
+      try {
+        await relay(new anchor.BN(2 * LAMPORTS_PER_SOL), new anchor.BN(1));
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("SignedBidReplayed");
+      }
+    });
+
+    it("rejects an amount the bidder didn't sign", async () => {
+      try {
+        await relay(
+          new anchor.BN(3 * LAMPORTS_PER_SOL),
+          new anchor.BN(2),
+          new anchor.BN(2 * LAMPORTS_PER_SOL)
+        );
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBidSignature");
+      }
+    });
+  });
+
+  // =========================================================================
+  // Error paths — start_auction
+  // =========================================================================
+
+  describe("error_paths_start_auction", () => {
+    const startSeller = Keypair.generate();
+    const imposter = Keypair.generate();
+    let startAuction: PublicKey;
+
+    before(async () => {
+      for (const kp of [startSeller, imposter]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+
+      const setup = await setupAuction(program, connection, startSeller);
+      startAuction = setup.auctionState;
+    });
+
+    it("rejects non-seller starting auction", async () => {
+      try {
+        await program.methods
+          .startAuction()
+          .accountsStrict({ seller: imposter.publicKey, auctionState: startAuction })
+          .signers([imposter])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        // Seeds constraint fails before has_one: seller PDA doesn't match
+        expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
+      }
+    });
+
+    it("rejects starting Active auction", async () => {
+      // Start it first
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: startSeller.publicKey, auctionState: startAuction })
+        .signers([startSeller])
+        .rpc();
+
+      // Try starting again
+      try {
+        await program.methods
+          .startAuction()
+          .accountsStrict({ seller: startSeller.publicKey, auctionState: startAuction })
+          .signers([startSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
+      }
+    });
+
+    it("rejects starting a scheduled auction before scheduled_start", async () => {
+      const scheduledStart = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const setup = await setupAuction(program, connection, startSeller, { scheduledStart });
+
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      expect(auction.scheduledStart.toNumber()).to.equal(scheduledStart.toNumber());
+
+      try {
+        await program.methods
+          .startAuction()
+          .accountsStrict({ seller: startSeller.publicKey, auctionState: setup.auctionState })
+          .signers([startSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("AuctionNotStarted");
+      }
+    });
+
+    // Anyone can crank a scheduled auction live; it activates and delegates
+    // in one transaction.
+    const crankScheduledStart = (setup: AuctionSetup) =>
+      program.methods
+        .startScheduledAuction(startSeller.publicKey, setup.nftMint)
+        .accountsStrict({
+          payer: provider.wallet.publicKey,
+          bufferAuctionState: getDelegationBufferPDA(setup.auctionState, program.programId)[0],
+          delegationRecordAuctionState: getDelegationRecordPDA(setup.auctionState)[0],
+          delegationMetadataAuctionState: getDelegationMetadataPDA(setup.auctionState)[0],
+          auctionState: setup.auctionState,
+          ownerProgram: program.programId,
+          delegationProgram: DELEGATION_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it("rejects cranking a scheduled auction before scheduled_start", async () => {
+      const scheduledStart = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+      const setup = await setupAuction(program, connection, startSeller, { scheduledStart });
+
+      try {
+        await crankScheduledStart(setup);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("AuctionNotStarted");
+      }
+
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      expect(auction.status).to.deep.equal({ created: {} });
+    });
+
+    let scheduledSetup: AuctionSetup;
+
+    it("cranks a scheduled auction live once scheduled_start passes", async () => {
+      const scheduledStart = new anchor.BN(Math.floor(Date.now() / 1000) + 5);
+      const setup = await setupAuction(program, connection, startSeller, { scheduledStart });
+      scheduledSetup = setup;
+
+      // Wait until the validator clock is past scheduled_start
+      while ((await connection.getBlockTime(await connection.getSlot())) < scheduledStart.toNumber()) {
+        await sleep(1000);
+      }
+
+      await crankScheduledStart(setup);
+
+      // Delegated: owned by the delegation program, data still readable on L1
+      const info = await connection.getAccountInfo(setup.auctionState);
+      expect(info.owner.toBase58()).to.equal(DELEGATION_PROGRAM_ID.toBase58());
+      const auction = program.coder.accounts.decode("auctionState", info.data);
+      expect(auction.status).to.deep.equal({ active: {} });
+      expect(auction.startTime.toNumber()).to.be.at.least(scheduledStart.toNumber());
+      expect(auction.endTime.sub(auction.startTime).toNumber()).to.equal(
+        auction.durationSeconds.toNumber()
+      );
+    });
+
+    it("rejects cranking a scheduled auction that is already active", async () => {
+      try {
+        await crankScheduledStart(scheduledSetup);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
+      }
+    });
+  });
+
+  // =========================================================================
+  // bid_guards — expected price, max amount and deadline checks
+  // =========================================================================
+
+  describe("bid_guards", () => {
+    const bgSeller = Keypair.generate();
+    const first = Keypair.generate();
+    const second = Keypair.generate();
+    let bgAuction: PublicKey;
+    const sol = (n: number) => new anchor.BN(n * LAMPORTS_PER_SOL);
+    const guards = (g: { expectedCurrentBid?: anchor.BN; maxAmount?: anchor.BN; validUntil?: anchor.BN }) => ({
+      expectedCurrentBid: g.expectedCurrentBid ?? null,
+      maxAmount: g.maxAmount ?? null,
+      validUntil: g.validUntil ?? null,
+    });
+
+    const expectError = async (tx: Promise<string>, code: string) => {
+      try {
+        await tx;
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal(code);
+      }
+    };
+
+    before(async () => {
+      for (const kp of [bgSeller, first, second]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+      const setup = await setupAuction(program, connection, bgSeller, {
+        durationSeconds: new anchor.BN(300),
+      });
+      bgAuction = setup.auctionState;
+      await program.methods
+        .startAuction()
+        .accountsStrict({ seller: bgSeller.publicKey, auctionState: bgAuction })
+        .signers([bgSeller])
+        .rpc();
+
+      await program.methods
+        .placeBid(sol(1), guards({ expectedCurrentBid: new anchor.BN(0) }))
+        .accountsStrict({
+          bidder: first.publicKey,
+          auctionState: bgAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(bgSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(first.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(first.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([first])
+        .rpc();
+    });
+
+    it("rejects a bid built against a stale current bid", async () => {
+      await expectError(
+        program.methods
+          .placeBid(sol(1.1), guards({ expectedCurrentBid: new anchor.BN(0) }))
+          .accountsStrict({
+            bidder: second.publicKey,
+            auctionState: bgAuction,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bgSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(second.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(second.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([second])
+          .rpc(),
+        "BidStateChanged"
+      );
+    });
+
+    it("rejects a transaction past valid_until", async () => {
+      await expectError(
+        program.methods
+          .placeBid(sol(1.1), guards({ validUntil: new anchor.BN(Math.floor(Date.now() / 1000) - 60) }))
+          .accountsStrict({
+            bidder: second.publicKey,
+            auctionState: bgAuction,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bgSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(second.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(second.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([second])
+          .rpc(),
+        "BidIntentExpired"
+      );
+    });
 
-      const setup = await setupAuction(program, connection, startSeller);
-      startAuction = setup.auctionState;
+    it("requires max_amount for next-increment bids", async () => {
+      await expectError(
+        program.methods
+          .placeBidNextIncrement(guards({}))
+          .accountsStrict({
+            bidder: second.publicKey,
+            auctionState: bgAuction,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bgSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(second.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(second.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([second])
+          .rpc(),
+        "BidGuardRequired"
+      );
     });
 
-    it("rejects non-seller starting auction", async () => {
-      try {
-        await program.methods
-          .startAuction()
-          .accountsStrict({ seller: imposter.publicKey, auctionState: startAuction })
-          .signers([imposter])
-          .rpc();
-        expect.fail("Should have thrown");
-      } catch (err: any) {
-        // Seeds constraint fails before has_one: seller PDA doesn't match
-        expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
-      }
+    it("rejects a next increment above max_amount", async () => {
+      await expectError(
+        program.methods
+          .placeBidNextIncrement(guards({ maxAmount: sol(1.05) }))
+          .accountsStrict({
+            bidder: second.publicKey,
+            auctionState: bgAuction,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bgSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(second.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(second.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
+          .signers([second])
+          .rpc(),
+        "BidAboveMax"
+      );
     });
 
-    it("rejects starting Active auction", async () => {
-      // Start it first
+    it("bids exactly the next increment on-chain", async () => {
       await program.methods
-        .startAuction()
-        .accountsStrict({ seller: startSeller.publicKey, auctionState: startAuction })
-        .signers([startSeller])
+        .placeBidNextIncrement(guards({ maxAmount: sol(1.2), expectedCurrentBid: sol(1) }))
+        .accountsStrict({
+          bidder: second.publicKey,
+          auctionState: bgAuction,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(bgSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(second.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(second.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
+        .signers([second])
         .rpc();
 
-      // Try starting again
-      try {
-        await program.methods
-          .startAuction()
-          .accountsStrict({ seller: startSeller.publicKey, auctionState: startAuction })
-          .signers([startSeller])
-          .rpc();
-        expect.fail("Should have thrown");
-      } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
-      }
+      const auction = await program.account.auctionState.fetch(bgAuction);
+      expect(auction.currentBid.toNumber()).to.equal(1.1 * LAMPORTS_PER_SOL);
+      expect(auction.highestBidder.toBase58()).to.equal(second.publicKey.toBase58());
     });
   });
 
@@ -1360,8 +4312,18 @@ describe("outcry", () => {
     it("rejects first bid below reserve", async () => {
       try {
         await program.methods
-          .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL)) // below 1 SOL reserve
-          .accountsStrict({ bidder: bidBidder.publicKey, auctionState: bidAuctionActive })
+          .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL), null) // below 1 SOL reserve
+          .accountsStrict({
+            bidder: bidBidder.publicKey,
+            auctionState: bidAuctionActive,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bidSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(bidBidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(bidBidder.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
           .signers([bidBidder])
           .rpc();
         expect.fail("Should have thrown");
@@ -1373,13 +4335,23 @@ describe("outcry", () => {
     it("rejects bid on Created auction", async () => {
       try {
         await program.methods
-          .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-          .accountsStrict({ bidder: bidBidder.publicKey, auctionState: bidAuctionCreated })
+          .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+          .accountsStrict({
+            bidder: bidBidder.publicKey,
+            auctionState: bidAuctionCreated,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bidSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(bidBidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(bidBidder.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
           .signers([bidBidder])
           .rpc();
         expect.fail("Should have thrown");
       } catch (err: any) {
-        expect(err.error.errorCode.code).to.equal("InvalidAuctionStatus");
+        expect(err.error.errorCode.code).to.equal("AuctionNotStarted");
       }
     });
 
@@ -1391,8 +4363,18 @@ describe("outcry", () => {
 
       try {
         await program.methods
-          .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-          .accountsStrict({ bidder: bidBidder.publicKey, auctionState: bidAuctionActive })
+          .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+          .accountsStrict({
+            bidder: bidBidder.publicKey,
+            auctionState: bidAuctionActive,
+            bidderDeposit: null,
+            blocklist: getBlockListPDA(bidSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(bidBidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(bidBidder.publicKey, program.programId)[0],
+            previousLot: null,
+            bidderAccount: null,
+            outbidBidderAccount: null,
+          })
           .signers([bidBidder])
           .rpc();
         expect.fail("Should have thrown");
@@ -1504,13 +4486,19 @@ describe("outcry", () => {
       // Bidder deposits so the winnerDeposit PDA exists
       const [winnerDep] = getDepositPDA(settleSetup1.auctionState, settleBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: settleBidder.publicKey,
           auctionState: settleSetup1.auctionState,
           bidderDeposit: winnerDep,
           auctionVault: settleSetup1.auctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(settleSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(settleBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(settleBidder.publicKey, program.programId)[0],
         })
         .signers([settleBidder])
         .rpc();
@@ -1541,6 +4529,12 @@ describe("outcry", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            houseFeeRecipient: null,
+            proceedsEscrow: null,
+            winnerBidderAccount: null,
+            winnerProfile: getBidderProfilePDA(settleBidder.publicKey, program.programId)[0],
+            sellerProfile: getSellerProfilePDA(settleSeller.publicKey, program.programId)[0],
           })
           .signers([settleSeller])
           .rpc();
@@ -1585,6 +4579,12 @@ describe("outcry", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            houseFeeRecipient: null,
+            proceedsEscrow: null,
+            winnerBidderAccount: null,
+            winnerProfile: getBidderProfilePDA(settleBidder.publicKey, program.programId)[0],
+            sellerProfile: getSellerProfilePDA(settleSeller.publicKey, program.programId)[0],
           })
           .signers([settleSeller])
           .rpc();
@@ -1598,13 +4598,19 @@ describe("outcry", () => {
       // Setup auction 2: deposit, start, bid, wait, end
       const [bidderDep] = getDepositPDA(settleSetup2.auctionState, settleBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: settleBidder.publicKey,
           auctionState: settleSetup2.auctionState,
           bidderDeposit: bidderDep,
           auctionVault: settleSetup2.auctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(settleSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(settleBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(settleBidder.publicKey, program.programId)[0],
         })
         .signers([settleBidder])
         .rpc();
@@ -1616,8 +4622,18 @@ describe("outcry", () => {
         .rpc();
 
       await program.methods
-        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: settleBidder.publicKey, auctionState: settleSetup2.auctionState })
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: settleBidder.publicKey,
+          auctionState: settleSetup2.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(settleSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(settleBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(settleBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([settleBidder])
         .rpc();
 
@@ -1653,6 +4669,12 @@ describe("outcry", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            auctionHouse: null,
+            houseFeeRecipient: null,
+            proceedsEscrow: null,
+            winnerBidderAccount: null,
+            winnerProfile: getBidderProfilePDA(settleBidder.publicKey, program.programId)[0],
+            sellerProfile: getSellerProfilePDA(settleSeller.publicKey, program.programId)[0],
           })
           .signers([settleSeller])
           .rpc();
@@ -1688,10 +4710,14 @@ describe("outcry", () => {
           .accountsStrict({
             seller: imposter2.publicKey,
             auctionState: setup.auctionState,
+            auctionVault: setup.auctionVault,
+            sellerProfile: getSellerProfilePDA(imposter2.publicKey, program.programId)[0],
+            protocolTreasury: PROTOCOL_TREASURY,
             nftMint: setup.nftMint,
             escrowNftTokenAccount: setup.escrowNftAta,
             sellerNftTokenAccount: setup.sellerNftAta,
             tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
           })
           .signers([imposter2])
           .rpc();
@@ -1716,8 +4742,18 @@ describe("outcry", () => {
 
       // Place a bid
       await program.methods
-        .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: cancelBidder.publicKey, auctionState: setup.auctionState })
+        .placeBid(new anchor.BN(0.5 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: cancelBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(cancelSeller2.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(cancelBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(cancelBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([cancelBidder])
         .rpc();
 
@@ -1727,10 +4763,14 @@ describe("outcry", () => {
           .accountsStrict({
             seller: cancelSeller2.publicKey,
             auctionState: setup.auctionState,
+            auctionVault: setup.auctionVault,
+            sellerProfile: getSellerProfilePDA(cancelSeller2.publicKey, program.programId)[0],
+            protocolTreasury: PROTOCOL_TREASURY,
             nftMint: setup.nftMint,
             escrowNftTokenAccount: setup.escrowNftAta,
             sellerNftTokenAccount: setup.sellerNftAta,
             tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
           })
           .signers([cancelSeller2])
           .rpc();
@@ -1763,13 +4803,19 @@ describe("outcry", () => {
 
       const [depositPda] = getDepositPDA(setup.auctionState, refundBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(1 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(1 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: refundBidder.publicKey,
           auctionState: setup.auctionState,
           bidderDeposit: depositPda,
           auctionVault: setup.auctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(refundSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(refundBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
         })
         .signers([refundBidder])
         .rpc();
@@ -1789,6 +4835,7 @@ describe("outcry", () => {
             bidderDeposit: depositPda,
             auctionVault: setup.auctionVault,
             systemProgram: SystemProgram.programId,
+            bidderProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
           })
           .signers([refundBidder])
           .rpc();
@@ -1808,13 +4855,19 @@ describe("outcry", () => {
 
       const [depositPda] = getDepositPDA(setup.auctionState, refundBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: refundBidder.publicKey,
           auctionState: setup.auctionState,
           bidderDeposit: depositPda,
           auctionVault: setup.auctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(refundSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(refundBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
         })
         .signers([refundBidder])
         .rpc();
@@ -1826,8 +4879,18 @@ describe("outcry", () => {
         .rpc();
 
       await program.methods
-        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: refundBidder.publicKey, auctionState: setup.auctionState })
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: refundBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(refundSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(refundBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([refundBidder])
         .rpc();
 
@@ -1860,6 +4923,12 @@ describe("outcry", () => {
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          houseFeeRecipient: null,
+          proceedsEscrow: null,
+          winnerBidderAccount: null,
+          winnerProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
+          sellerProfile: getSellerProfilePDA(refundSeller.publicKey, program.programId)[0],
         })
         .signers([refundSeller])
         .rpc();
@@ -1873,6 +4942,7 @@ describe("outcry", () => {
           bidderDeposit: depositPda,
           auctionVault: setup.auctionVault,
           systemProgram: SystemProgram.programId,
+          bidderProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
         })
         .signers([refundBidder])
         .rpc();
@@ -1887,6 +4957,7 @@ describe("outcry", () => {
             bidderDeposit: depositPda,
             auctionVault: setup.auctionVault,
             systemProgram: SystemProgram.programId,
+            bidderProfile: getBidderProfilePDA(refundBidder.publicKey, program.programId)[0],
           })
           .signers([refundBidder])
           .rpc();
@@ -1922,10 +4993,14 @@ describe("outcry", () => {
         .accountsStrict({
           seller: closeSeller.publicKey,
           auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          sellerProfile: getSellerProfilePDA(closeSeller.publicKey, program.programId)[0],
+          protocolTreasury: PROTOCOL_TREASURY,
           nftMint: setup.nftMint,
           escrowNftTokenAccount: setup.escrowNftAta,
           sellerNftTokenAccount: setup.sellerNftAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .signers([closeSeller])
         .rpc();
@@ -2005,13 +5080,19 @@ describe("outcry", () => {
       // Bidder deposits MORE than enough
       const [depositPda] = getDepositPDA(setup.auctionState, forfBidder.publicKey, program.programId);
       await program.methods
-        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL))
+        .deposit(new anchor.BN(2 * LAMPORTS_PER_SOL), [])
         .accountsStrict({
           bidder: forfBidder.publicKey,
           auctionState: setup.auctionState,
           bidderDeposit: depositPda,
           auctionVault: setup.auctionVault,
           systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(forfSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(forfBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(forfBidder.publicKey, program.programId)[0],
         })
         .signers([forfBidder])
         .rpc();
@@ -2023,8 +5104,18 @@ describe("outcry", () => {
         .rpc();
 
       await program.methods
-        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL))
-        .accountsStrict({ bidder: forfBidder.publicKey, auctionState: setup.auctionState })
+        .placeBid(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+        .accountsStrict({
+          bidder: forfBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: null,
+          blocklist: getBlockListPDA(forfSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(forfBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(forfBidder.publicKey, program.programId)[0],
+          previousLot: null,
+          bidderAccount: null,
+          outbidBidderAccount: null,
+        })
         .signers([forfBidder])
         .rpc();
 
@@ -2048,6 +5139,7 @@ describe("outcry", () => {
             auctionState: setup.auctionState,
             auctionVault: setup.auctionVault,
             winnerDeposit: depositPda,
+            winnerBidderAccount: getBidderAccountPDA(forfBidder.publicKey, program.programId)[0],
             seller: forfSeller.publicKey,
             nftMint: setup.nftMint,
             escrowNftTokenAccount: setup.escrowNftAta,
@@ -2055,6 +5147,8 @@ describe("outcry", () => {
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
+            winnerDefaultRecord: getDefaultRecordPDA(forfBidder.publicKey, program.programId)[0],
+            winnerProfile: getBidderProfilePDA(forfBidder.publicKey, program.programId)[0],
           })
           .signers([forfSeller])
           .rpc();
@@ -2064,4 +5158,362 @@ describe("outcry", () => {
       }
     });
   });
+
+  // =========================================================================
+  // Force close — unclaimed deposits stay redeemable
+  // =========================================================================
+
+  describe("force_close_unclaimed_funds", () => {
+    const fcSeller = Keypair.generate();
+    const fcBidder = Keypair.generate();
+    const depositAmount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+    let setup: AuctionSetup;
+    let fcDeposit: PublicKey;
+    let unclaimedFunds: PublicKey;
+
+    before(async () => {
+      for (const kp of [fcSeller, fcBidder]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+      setup = await setupAuction(program, connection, fcSeller);
+      [fcDeposit] = getDepositPDA(setup.auctionState, fcBidder.publicKey, program.programId);
+      [unclaimedFunds] = getUnclaimedFundsPDA(setup.auctionState, program.programId);
+
+      // Deposit while Created, then cancel before start — no grace period applies
+      await program.methods
+        .deposit(depositAmount, [])
+        .accountsStrict({
+          bidder: fcBidder.publicKey,
+          auctionState: setup.auctionState,
+          bidderDeposit: fcDeposit,
+          auctionVault: setup.auctionVault,
+          systemProgram: SystemProgram.programId,
+          gateTokenAccount: null,
+          gateMetadata: null,
+          gateClaim: null,
+          blocklist: getBlockListPDA(fcSeller.publicKey, program.programId)[0],
+          defaultRecord: getDefaultRecordPDA(fcBidder.publicKey, program.programId)[0],
+          bidderProfile: getBidderProfilePDA(fcBidder.publicKey, program.programId)[0],
+        })
+        .signers([fcBidder])
+        .rpc();
+
+      await program.methods
+        .cancelAuction()
+        .accountsStrict({
+          seller: fcSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          sellerProfile: getSellerProfilePDA(fcSeller.publicKey, program.programId)[0],
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          sellerNftTokenAccount: setup.sellerNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([fcSeller])
+        .rpc();
+    });
+
+    const relist = () =>
+      program.methods
+        .createAuction({
+          reservePrice: new anchor.BN(1 * LAMPORTS_PER_SOL),
+          reserveCommitment: null,
+          durationSeconds: new anchor.BN(300),
+          extensionSeconds: 2,
+          extensionWindow: 2,
+          minBidIncrement: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          scheduledStart: null,
+          antiSnipePolicy: { fixedAdd: {} },
+          maxExtensionSeconds: 3600,
+          bidIncrement: { flat: {} },
+          allowlistRoot: null,
+          collectionGate: null,
+          banDefaulters: false,
+          minReputation: null,
+          maxDefaults: null,
+          sellerBond: new anchor.BN(0),
+          minBidInterval: 0,
+          blockSelfOutbid: false,
+          escrowTerms: null,
+          payoutSplits: [],
+          buyerPremiumBps: 0,
+        })
+        .accountsStrict({
+          seller: fcSeller.publicKey,
+          nftMint: setup.nftMint,
+          sellerNftTokenAccount: setup.sellerNftAta,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          unclaimedFunds,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          auctionHouse: null,
+          curator: null,
+        })
+        .signers([fcSeller])
+        .rpc();
+
+    it("moves unclaimed deposits into UnclaimedFunds instead of the seller", async () => {
+      // Lamports sent straight to the vault aren't owed to anyone
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: fcSeller.publicKey,
+            toPubkey: setup.auctionVault,
+            lamports: 0.1 * LAMPORTS_PER_SOL,
+          })
+        ),
+        [fcSeller]
+      );
+
+      await program.methods
+        .forceCloseAuction()
+        .accountsStrict({
+          seller: fcSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          unclaimedFunds,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([fcSeller])
+        .rpc();
+
+      const unclaimed = await program.account.unclaimedFunds.fetch(unclaimedFunds);
+      // Only the ledger's liabilities move — the stray lamports went back to the seller
+      expect(unclaimed.remaining.toNumber()).to.equal(depositAmount.toNumber());
+      expect(await connection.getAccountInfo(setup.auctionState)).to.be.null;
+    });
+
+    it("refuses to relist the mint while deposits are unclaimed", async () => {
+      try {
+        await relist();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("UnclaimedFundsOutstanding");
+      }
+    });
+
+    it("bidder redeems the deposit and the fund closes", async () => {
+      const bidderBalBefore = await connection.getBalance(fcBidder.publicKey);
+
+      await program.methods
+        .claimUnclaimedRefund()
+        .accountsStrict({
+          bidder: fcBidder.publicKey,
+          unclaimedFunds,
+          bidderDeposit: fcDeposit,
+          seller: fcSeller.publicKey,
+        })
+        .signers([fcBidder])
+        .rpc();
+
+      const bidderBalAfter = await connection.getBalance(fcBidder.publicKey);
+      // Refund plus the deposit PDA's rent, minus the tx fee
+      expect(bidderBalAfter - bidderBalBefore).to.be.greaterThan(depositAmount.toNumber());
+      expect(await connection.getAccountInfo(unclaimedFunds)).to.be.null;
+    });
+
+    it("relists the mint once every deposit is redeemed", async () => {
+      await relist();
+      const auction = await program.account.auctionState.fetch(setup.auctionState);
+      expect(JSON.stringify(auction.status)).to.equal(JSON.stringify({ created: {} }));
+    });
+
+    it("refuses an UnclaimedFunds account when nothing is owed", async () => {
+      await program.methods
+        .cancelAuction()
+        .accountsStrict({
+          seller: fcSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          sellerProfile: getSellerProfilePDA(fcSeller.publicKey, program.programId)[0],
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          sellerNftTokenAccount: setup.sellerNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([fcSeller])
+        .rpc();
+
+      const forceClose = (withUnclaimed: boolean) =>
+        program.methods
+          .forceCloseAuction()
+          .accountsStrict({
+            seller: fcSeller.publicKey,
+            auctionState: setup.auctionState,
+            auctionVault: setup.auctionVault,
+            nftMint: setup.nftMint,
+            escrowNftTokenAccount: setup.escrowNftAta,
+            unclaimedFunds: withUnclaimed ? unclaimedFunds : null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([fcSeller])
+          .rpc();
+
+      try {
+        await forceClose(true);
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("UnclaimedFundsNotNeeded");
+      }
+
+      await forceClose(false);
+      expect(await connection.getAccountInfo(setup.auctionState)).to.be.null;
+      expect(await connection.getAccountInfo(unclaimedFunds)).to.be.null;
+    });
+  });
+
+  // =========================================================================
+  // Batch refunds
+  // =========================================================================
+
+  describe("claim_refunds_batch", () => {
+    const brSeller = Keypair.generate();
+    const brBidders = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    const profiles = brBidders.map(
+      (b) => getBidderProfilePDA(b.publicKey, program.programId)[0]
+    );
+    let setup: AuctionSetup;
+
+    before(async () => {
+      for (const kp of [brSeller, ...brBidders]) {
+        const sig = await connection.requestAirdrop(kp.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig);
+      }
+      setup = await setupAuction(program, connection, brSeller);
+
+      await program.methods
+        .createBidderProfile()
+        .accountsStrict({
+          bidder: brBidders[0].publicKey,
+          bidderProfile: profiles[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([brBidders[0]])
+        .rpc();
+
+      for (const bidder of brBidders) {
+        const [deposit] = getDepositPDA(setup.auctionState, bidder.publicKey, program.programId);
+        await program.methods
+          .deposit(new anchor.BN(0.2 * LAMPORTS_PER_SOL), [])
+          .accountsStrict({
+            bidder: bidder.publicKey,
+            auctionState: setup.auctionState,
+            bidderDeposit: deposit,
+            auctionVault: setup.auctionVault,
+            systemProgram: SystemProgram.programId,
+            gateTokenAccount: null,
+            gateMetadata: null,
+            gateClaim: null,
+            blocklist: getBlockListPDA(brSeller.publicKey, program.programId)[0],
+            defaultRecord: getDefaultRecordPDA(bidder.publicKey, program.programId)[0],
+            bidderProfile: getBidderProfilePDA(bidder.publicKey, program.programId)[0],
+          })
+          .signers([bidder])
+          .rpc();
+      }
+
+      await program.methods
+        .cancelAuction()
+        .accountsStrict({
+          seller: brSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+          sellerProfile: getSellerProfilePDA(brSeller.publicKey, program.programId)[0],
+          protocolTreasury: PROTOCOL_TREASURY,
+          nftMint: setup.nftMint,
+          escrowNftTokenAccount: setup.escrowNftAta,
+          sellerNftTokenAccount: setup.sellerNftAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([brSeller])
+        .rpc();
+    });
+
+    it("rejects remaining accounts that aren't whole triples", async () => {
+      try {
+        await program.methods
+          .claimRefundsBatch()
+          .accountsStrict({
+            payer: brSeller.publicKey,
+            auctionState: setup.auctionState,
+            auctionVault: setup.auctionVault,
+          })
+          .remainingAccounts([
+            { pubkey: brBidders[0].publicKey, isSigner: false, isWritable: true },
+          ])
+          .signers([brSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBatchAccounts");
+      }
+    });
+
+    it("rejects a profile that isn't the bidder's", async () => {
+      const [deposit] = getDepositPDA(setup.auctionState, brBidders[0].publicKey, program.programId);
+      try {
+        await program.methods
+          .claimRefundsBatch()
+          .accountsStrict({
+            payer: brSeller.publicKey,
+            auctionState: setup.auctionState,
+            auctionVault: setup.auctionVault,
+          })
+          .remainingAccounts([
+            { pubkey: brBidders[0].publicKey, isSigner: false, isWritable: true },
+            { pubkey: deposit, isSigner: false, isWritable: true },
+            { pubkey: profiles[1], isSigner: false, isWritable: true },
+          ])
+          .signers([brSeller])
+          .rpc();
+        expect.fail("Should have thrown");
+      } catch (err: any) {
+        expect(err.error.errorCode.code).to.equal("InvalidBidderProfile");
+      }
+    });
+
+    it("refunds and closes every deposit in one transaction", async () => {
+      const deposits = brBidders.map(
+        (b) => getDepositPDA(setup.auctionState, b.publicKey, program.programId)[0]
+      );
+
+      await program.methods
+        .claimRefundsBatch()
+        .accountsStrict({
+          payer: brSeller.publicKey,
+          auctionState: setup.auctionState,
+          auctionVault: setup.auctionVault,
+        })
+        .remainingAccounts(
+          brBidders.flatMap((b, i) => [
+            { pubkey: b.publicKey, isSigner: false, isWritable: true },
+            { pubkey: deposits[i], isSigner: false, isWritable: true },
+            { pubkey: profiles[i], isSigner: false, isWritable: true },
+          ])
+        )
+        .signers([brSeller])
+        .rpc();
+
+      for (const deposit of deposits) {
+        expect(await connection.getAccountInfo(deposit)).to.be.null;
+      }
+      // Cancelled auctions don't count as participation
+      const profile = await program.account.bidderProfile.fetch(profiles[0]);
+      expect(profile.auctionsParticipated).to.equal(0);
+    });
+  });
 });